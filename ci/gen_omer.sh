@@ -0,0 +1,33 @@
+#!/bin/sh
+# Prints src/prelude/get_omer.rs. Run it from the top of the repository:
+#   ci/gen_omer.sh > src/prelude/get_omer.rs
+
+set -e
+
+{
+    cat <<'EOF'
+use crate::args::types::{DayVal, MinorDays, Name};
+use chrono::prelude::*;
+use chrono::Duration;
+use heca_lib::prelude::HebrewMonth;
+use heca_lib::HebrewYear;
+use std::num::NonZeroI8;
+
+//generated from https://play.golang.com/p/fCtYz6kNCBw
+pub fn get_omer(year: &HebrewYear) -> [DayVal; 49] {
+    let first_day_of_pesach: DateTime<Utc> = year
+        .get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(15).unwrap())
+        .unwrap()
+        .into();
+
+    //generated by ci/gen_omer.sh
+    [
+EOF
+    i=1
+    while [ $i -le 49 ]; do
+        echo "DayVal::new(first_day_of_pesach + Duration::days($i), Name::MinorDays(MinorDays::Omer$i)),"
+        i=$((i + 1))
+    done
+    echo "]"
+    echo "}"
+} | rustfmt --edition 2018 --emit stdout
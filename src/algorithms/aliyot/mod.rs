@@ -0,0 +1,1180 @@
+use crate::args::types::{Language, Minhag};
+use chrono::Weekday;
+use heca_lib::prelude::{Chol, Location, Parsha, SpecialParsha, TorahReading, YomTov};
+use serde::ser::*;
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Book {
+    Bereishis,
+    Shemos,
+    Vayikra,
+    Bamidbar,
+    Devarim,
+}
+
+use Book::*;
+
+/// A range of verses, from `start` to `end` inclusive. Each is a (chapter, verse) pair.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Aliyah {
+    pub book: Book,
+    pub start: (u8, u8),
+    pub end: (u8, u8),
+}
+
+const fn a(
+    book: Book,
+    start_chapter: u8,
+    start_verse: u8,
+    end_chapter: u8,
+    end_verse: u8,
+) -> Aliyah {
+    Aliyah {
+        book,
+        start: (start_chapter, start_verse),
+        end: (end_chapter, end_verse),
+    }
+}
+
+impl Serialize for Aliyah {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Aliyah", 3)?;
+        state.serialize_field("book", &self.book)?;
+        state.serialize_field("start", &format!("{}:{}", self.start.0, self.start.1))?;
+        state.serialize_field("end", &format!("{}:{}", self.end.0, self.end.1))?;
+        state.end()
+    }
+}
+
+impl Aliyah {
+    pub fn pretty(&self, language: Language) -> String {
+        let (start, end) = (self.start, self.end);
+        let book = book_name(self.book, language);
+        if start.0 == end.0 {
+            format!("{} {}:{}-{}", book, start.0, start.1, end.1)
+        } else {
+            format!("{} {}:{}-{}:{}", book, start.0, start.1, end.0, end.1)
+        }
+    }
+}
+
+fn book_name(book: Book, language: Language) -> &'static str {
+    language.tr(match book {
+        Bereishis => "Bereishis",
+        Shemos => "Shemos",
+        Vayikra => "Vayikra",
+        Bamidbar => "Bamidbar",
+        Devarim => "Devarim",
+    })
+}
+
+/// The Torah reading of a day: the aliyos in the order they're read, and the Maftir (if there is
+/// one).
+#[derive(Debug, Clone)]
+pub struct Reading {
+    pub aliyot: Vec<Aliyah>,
+    pub maftir: Option<Aliyah>,
+}
+
+impl Serialize for Reading {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Reading", 3)?;
+        state.serialize_field("range", &self.range())?;
+        state.serialize_field("aliyot", &self.aliyot)?;
+        if let Some(maftir) = &self.maftir {
+            state.serialize_field("maftir", maftir)?;
+        }
+        state.end()
+    }
+}
+
+impl Reading {
+    /// The whole reading, from the first verse of the first aliyah until the last verse read in
+    /// that book.
+    pub fn range(&self) -> Aliyah {
+        let first = self.aliyot[0];
+        let end = self
+            .aliyot
+            .iter()
+            .filter(|aliyah| aliyah.book == first.book)
+            .map(|aliyah| aliyah.end)
+            .max()
+            .unwrap();
+        Aliyah { end, ..first }
+    }
+
+    pub fn pretty(&self, language: Language) -> String {
+        let mut ret = self
+            .aliyot
+            .iter()
+            .map(|aliyah| aliyah.pretty(language))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if let Some(maftir) = self.maftir {
+            ret.push_str("; ");
+            ret.push_str(language.tr("Maftir"));
+            ret.push_str(": ");
+            ret.push_str(&maftir.pretty(language));
+        }
+        ret
+    }
+}
+
+/// The third of a parsha read in one year of the triennial cycle.
+#[derive(Debug, Clone, Copy)]
+pub struct Triennial {
+    pub year: u8,
+    pub portion: Aliyah,
+}
+
+impl Serialize for Triennial {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Triennial", 2)?;
+        state.serialize_field("year", &self.year)?;
+        state.serialize_field("portion", &self.portion)?;
+        state.end()
+    }
+}
+
+impl Triennial {
+    pub fn pretty(&self, language: Language) -> String {
+        format!(
+            "{} {}: {}",
+            language.tr("Triennial year"),
+            self.year,
+            self.portion.pretty(language)
+        )
+    }
+}
+
+/// The portion of `parsha` read in the Hebrew year `year` by the congregations that read the
+/// Torah over three years. The cycle started over with Bereishis 5783. Each year reads a third
+/// of the parsha, split where the aliyos of the annual cycle are: the first three aliyos, then
+/// the fourth and fifth, then the sixth and seventh.
+pub fn triennial(parsha: Parsha, year: u64) -> Triennial {
+    let aliyot = shabbos(parsha);
+    // Vayelech and Haazinu can be read in Tishrei, before Bereishis starts the next year.
+    let year = match parsha {
+        Parsha::Vayelech | Parsha::Haazinu => year - 1,
+        _ => year,
+    };
+    let year = ((year + 1) % 3 + 1) as u8;
+    let (first, last) = match year {
+        1 => (aliyot[0], aliyot[2]),
+        2 => (aliyot[3], aliyot[4]),
+        _ => (aliyot[5], aliyot[6]),
+    };
+    Triennial {
+        year,
+        portion: Aliyah {
+            end: last.end,
+            ..first
+        },
+    }
+}
+
+fn reading(aliyot: &[Aliyah], maftir: Option<Aliyah>) -> Reading {
+    Reading {
+        aliyot: aliyot.to_vec(),
+        maftir,
+    }
+}
+
+/// `weekday` is the day on which the reading is read (not the evening on which the day starts).
+pub fn get(
+    torah_reading: TorahReading,
+    location: Location,
+    weekday: Weekday,
+    minhag: Minhag,
+) -> Reading {
+    match torah_reading {
+        TorahReading::Shabbos(parsha) => {
+            let aliyot = shabbos(parsha);
+            reading(&aliyot[..7], Some(aliyot[7]))
+        }
+        TorahReading::YomTov(yom_tov) => yom_tov_reading(yom_tov, location, weekday),
+        TorahReading::Chol(chol) => chol_reading(chol, minhag),
+        TorahReading::SpecialParsha(special_parsha) => {
+            let maftir = match special_parsha {
+                SpecialParsha::Shekalim => a(Shemos, 30, 11, 30, 16),
+                SpecialParsha::Zachor => a(Devarim, 25, 17, 25, 19),
+                SpecialParsha::Parah => a(Bamidbar, 19, 1, 19, 22),
+                SpecialParsha::HaChodesh => a(Shemos, 12, 1, 12, 20),
+            };
+            reading(&[maftir], None)
+        }
+    }
+}
+
+fn shabbos(parsha: Parsha) -> [Aliyah; 8] {
+    match parsha {
+        Parsha::Bereishis => [
+            a(Bereishis, 1, 1, 2, 3),
+            a(Bereishis, 2, 4, 2, 19),
+            a(Bereishis, 2, 20, 3, 21),
+            a(Bereishis, 3, 22, 4, 18),
+            a(Bereishis, 4, 19, 4, 22),
+            a(Bereishis, 4, 23, 5, 24),
+            a(Bereishis, 5, 25, 6, 8),
+            a(Bereishis, 6, 5, 6, 8),
+        ],
+        Parsha::Noach => [
+            a(Bereishis, 6, 9, 6, 22),
+            a(Bereishis, 7, 1, 7, 16),
+            a(Bereishis, 7, 17, 8, 14),
+            a(Bereishis, 8, 15, 9, 7),
+            a(Bereishis, 9, 8, 9, 17),
+            a(Bereishis, 9, 18, 10, 32),
+            a(Bereishis, 11, 1, 11, 32),
+            a(Bereishis, 11, 29, 11, 32),
+        ],
+        Parsha::LechLecha => [
+            a(Bereishis, 12, 1, 12, 13),
+            a(Bereishis, 12, 14, 13, 4),
+            a(Bereishis, 13, 5, 13, 18),
+            a(Bereishis, 14, 1, 14, 20),
+            a(Bereishis, 14, 21, 15, 6),
+            a(Bereishis, 15, 7, 17, 6),
+            a(Bereishis, 17, 7, 17, 27),
+            a(Bereishis, 17, 24, 17, 27),
+        ],
+        Parsha::Vayeira => [
+            a(Bereishis, 18, 1, 18, 14),
+            a(Bereishis, 18, 15, 18, 33),
+            a(Bereishis, 19, 1, 19, 20),
+            a(Bereishis, 19, 21, 21, 4),
+            a(Bereishis, 21, 5, 21, 21),
+            a(Bereishis, 21, 22, 21, 34),
+            a(Bereishis, 22, 1, 22, 24),
+            a(Bereishis, 22, 20, 22, 24),
+        ],
+        Parsha::ChayeiSara => [
+            a(Bereishis, 23, 1, 23, 16),
+            a(Bereishis, 23, 17, 24, 9),
+            a(Bereishis, 24, 10, 24, 26),
+            a(Bereishis, 24, 27, 24, 52),
+            a(Bereishis, 24, 53, 24, 67),
+            a(Bereishis, 25, 1, 25, 11),
+            a(Bereishis, 25, 12, 25, 18),
+            a(Bereishis, 25, 16, 25, 18),
+        ],
+        Parsha::Toldos => [
+            a(Bereishis, 25, 19, 26, 5),
+            a(Bereishis, 26, 6, 26, 12),
+            a(Bereishis, 26, 13, 26, 22),
+            a(Bereishis, 26, 23, 26, 29),
+            a(Bereishis, 26, 30, 27, 27),
+            a(Bereishis, 27, 28, 28, 4),
+            a(Bereishis, 28, 5, 28, 9),
+            a(Bereishis, 28, 7, 28, 9),
+        ],
+        Parsha::Vayetzei => [
+            a(Bereishis, 28, 10, 28, 22),
+            a(Bereishis, 29, 1, 29, 17),
+            a(Bereishis, 29, 18, 30, 13),
+            a(Bereishis, 30, 14, 30, 27),
+            a(Bereishis, 30, 28, 31, 16),
+            a(Bereishis, 31, 17, 31, 42),
+            a(Bereishis, 31, 43, 32, 3),
+            a(Bereishis, 32, 1, 32, 3),
+        ],
+        Parsha::Vayishlach => [
+            a(Bereishis, 32, 4, 32, 13),
+            a(Bereishis, 32, 14, 32, 30),
+            a(Bereishis, 32, 31, 33, 5),
+            a(Bereishis, 33, 6, 33, 20),
+            a(Bereishis, 34, 1, 35, 11),
+            a(Bereishis, 35, 12, 36, 19),
+            a(Bereishis, 36, 20, 36, 43),
+            a(Bereishis, 36, 40, 36, 43),
+        ],
+        Parsha::Vayeshev => [
+            a(Bereishis, 37, 1, 37, 11),
+            a(Bereishis, 37, 12, 37, 22),
+            a(Bereishis, 37, 23, 37, 36),
+            a(Bereishis, 38, 1, 38, 30),
+            a(Bereishis, 39, 1, 39, 6),
+            a(Bereishis, 39, 7, 39, 23),
+            a(Bereishis, 40, 1, 40, 23),
+            a(Bereishis, 40, 20, 40, 23),
+        ],
+        Parsha::Miketz => [
+            a(Bereishis, 41, 1, 41, 14),
+            a(Bereishis, 41, 15, 41, 38),
+            a(Bereishis, 41, 39, 41, 52),
+            a(Bereishis, 41, 53, 42, 18),
+            a(Bereishis, 42, 19, 43, 15),
+            a(Bereishis, 43, 16, 43, 29),
+            a(Bereishis, 43, 30, 44, 17),
+            a(Bereishis, 44, 14, 44, 17),
+        ],
+        Parsha::Vayigash => [
+            a(Bereishis, 44, 18, 44, 30),
+            a(Bereishis, 44, 31, 45, 7),
+            a(Bereishis, 45, 8, 45, 18),
+            a(Bereishis, 45, 19, 45, 27),
+            a(Bereishis, 45, 28, 46, 27),
+            a(Bereishis, 46, 28, 47, 10),
+            a(Bereishis, 47, 11, 47, 27),
+            a(Bereishis, 47, 25, 47, 27),
+        ],
+        Parsha::Vayechi => [
+            a(Bereishis, 47, 28, 48, 9),
+            a(Bereishis, 48, 10, 48, 16),
+            a(Bereishis, 48, 17, 48, 22),
+            a(Bereishis, 49, 1, 49, 18),
+            a(Bereishis, 49, 19, 49, 26),
+            a(Bereishis, 49, 27, 50, 20),
+            a(Bereishis, 50, 21, 50, 26),
+            a(Bereishis, 50, 23, 50, 26),
+        ],
+        Parsha::Shemos => [
+            a(Shemos, 1, 1, 1, 17),
+            a(Shemos, 1, 18, 2, 10),
+            a(Shemos, 2, 11, 2, 25),
+            a(Shemos, 3, 1, 3, 15),
+            a(Shemos, 3, 16, 4, 17),
+            a(Shemos, 4, 18, 4, 31),
+            a(Shemos, 5, 1, 6, 1),
+            a(Shemos, 5, 22, 6, 1),
+        ],
+        Parsha::Vaeira => [
+            a(Shemos, 6, 2, 6, 13),
+            a(Shemos, 6, 14, 6, 28),
+            a(Shemos, 6, 29, 7, 7),
+            a(Shemos, 7, 8, 8, 6),
+            a(Shemos, 8, 7, 8, 18),
+            a(Shemos, 8, 19, 9, 16),
+            a(Shemos, 9, 17, 9, 35),
+            a(Shemos, 9, 33, 9, 35),
+        ],
+        Parsha::Bo => [
+            a(Shemos, 10, 1, 10, 11),
+            a(Shemos, 10, 12, 10, 23),
+            a(Shemos, 10, 24, 11, 3),
+            a(Shemos, 11, 4, 12, 20),
+            a(Shemos, 12, 21, 12, 28),
+            a(Shemos, 12, 29, 12, 51),
+            a(Shemos, 13, 1, 13, 16),
+            a(Shemos, 13, 14, 13, 16),
+        ],
+        Parsha::Beshalach => [
+            a(Shemos, 13, 17, 14, 8),
+            a(Shemos, 14, 9, 14, 14),
+            a(Shemos, 14, 15, 14, 25),
+            a(Shemos, 14, 26, 15, 26),
+            a(Shemos, 15, 27, 16, 10),
+            a(Shemos, 16, 11, 16, 36),
+            a(Shemos, 17, 1, 17, 16),
+            a(Shemos, 17, 14, 17, 16),
+        ],
+        Parsha::Yisro => [
+            a(Shemos, 18, 1, 18, 12),
+            a(Shemos, 18, 13, 18, 23),
+            a(Shemos, 18, 24, 18, 27),
+            a(Shemos, 19, 1, 19, 6),
+            a(Shemos, 19, 7, 19, 19),
+            a(Shemos, 19, 20, 20, 14),
+            a(Shemos, 20, 15, 20, 23),
+            a(Shemos, 20, 19, 20, 23),
+        ],
+        Parsha::Mishpatim => [
+            a(Shemos, 21, 1, 21, 19),
+            a(Shemos, 21, 20, 22, 3),
+            a(Shemos, 22, 4, 22, 26),
+            a(Shemos, 22, 27, 23, 5),
+            a(Shemos, 23, 6, 23, 19),
+            a(Shemos, 23, 20, 23, 25),
+            a(Shemos, 23, 26, 24, 18),
+            a(Shemos, 24, 15, 24, 18),
+        ],
+        Parsha::Terumah => [
+            a(Shemos, 25, 1, 25, 16),
+            a(Shemos, 25, 17, 25, 30),
+            a(Shemos, 25, 31, 26, 14),
+            a(Shemos, 26, 15, 26, 30),
+            a(Shemos, 26, 31, 26, 37),
+            a(Shemos, 27, 1, 27, 8),
+            a(Shemos, 27, 9, 27, 19),
+            a(Shemos, 27, 17, 27, 19),
+        ],
+        Parsha::Tetzaveh => [
+            a(Shemos, 27, 20, 28, 12),
+            a(Shemos, 28, 13, 28, 30),
+            a(Shemos, 28, 31, 28, 43),
+            a(Shemos, 29, 1, 29, 18),
+            a(Shemos, 29, 19, 29, 37),
+            a(Shemos, 29, 38, 29, 46),
+            a(Shemos, 30, 1, 30, 10),
+            a(Shemos, 30, 8, 30, 10),
+        ],
+        Parsha::KiSisa => [
+            a(Shemos, 30, 11, 31, 17),
+            a(Shemos, 31, 18, 33, 11),
+            a(Shemos, 33, 12, 33, 16),
+            a(Shemos, 33, 17, 33, 23),
+            a(Shemos, 34, 1, 34, 9),
+            a(Shemos, 34, 10, 34, 26),
+            a(Shemos, 34, 27, 34, 35),
+            a(Shemos, 34, 33, 34, 35),
+        ],
+        Parsha::VayakhelPikudei => [
+            a(Shemos, 35, 1, 35, 20),
+            a(Shemos, 35, 21, 35, 29),
+            a(Shemos, 35, 30, 37, 16),
+            a(Shemos, 37, 17, 37, 29),
+            a(Shemos, 38, 1, 39, 1),
+            a(Shemos, 39, 2, 39, 21),
+            a(Shemos, 39, 22, 40, 38),
+            a(Shemos, 40, 34, 40, 38),
+        ],
+        Parsha::Vayakhel => [
+            a(Shemos, 35, 1, 35, 20),
+            a(Shemos, 35, 21, 35, 29),
+            a(Shemos, 35, 30, 36, 7),
+            a(Shemos, 36, 8, 36, 19),
+            a(Shemos, 36, 20, 37, 16),
+            a(Shemos, 37, 17, 37, 29),
+            a(Shemos, 38, 1, 38, 20),
+            a(Shemos, 38, 18, 38, 20),
+        ],
+        Parsha::Pikudei => [
+            a(Shemos, 38, 21, 39, 1),
+            a(Shemos, 39, 2, 39, 21),
+            a(Shemos, 39, 22, 39, 32),
+            a(Shemos, 39, 33, 39, 43),
+            a(Shemos, 40, 1, 40, 16),
+            a(Shemos, 40, 17, 40, 27),
+            a(Shemos, 40, 28, 40, 38),
+            a(Shemos, 40, 34, 40, 38),
+        ],
+        Parsha::Vayikra => [
+            a(Vayikra, 1, 1, 1, 13),
+            a(Vayikra, 1, 14, 2, 6),
+            a(Vayikra, 2, 7, 2, 16),
+            a(Vayikra, 3, 1, 3, 17),
+            a(Vayikra, 4, 1, 4, 26),
+            a(Vayikra, 4, 27, 5, 10),
+            a(Vayikra, 5, 11, 5, 26),
+            a(Vayikra, 5, 24, 5, 26),
+        ],
+        Parsha::Tzav => [
+            a(Vayikra, 6, 1, 6, 11),
+            a(Vayikra, 6, 12, 7, 10),
+            a(Vayikra, 7, 11, 7, 38),
+            a(Vayikra, 8, 1, 8, 13),
+            a(Vayikra, 8, 14, 8, 21),
+            a(Vayikra, 8, 22, 8, 29),
+            a(Vayikra, 8, 30, 8, 36),
+            a(Vayikra, 8, 33, 8, 36),
+        ],
+        Parsha::Shemini => [
+            a(Vayikra, 9, 1, 9, 16),
+            a(Vayikra, 9, 17, 9, 23),
+            a(Vayikra, 9, 24, 10, 11),
+            a(Vayikra, 10, 12, 10, 15),
+            a(Vayikra, 10, 16, 10, 20),
+            a(Vayikra, 11, 1, 11, 32),
+            a(Vayikra, 11, 33, 11, 47),
+            a(Vayikra, 11, 45, 11, 47),
+        ],
+        Parsha::TazriyaMetzorah => [
+            a(Vayikra, 12, 1, 13, 23),
+            a(Vayikra, 13, 24, 13, 39),
+            a(Vayikra, 13, 40, 13, 54),
+            a(Vayikra, 13, 55, 14, 20),
+            a(Vayikra, 14, 21, 14, 32),
+            a(Vayikra, 14, 33, 15, 15),
+            a(Vayikra, 15, 16, 15, 33),
+            a(Vayikra, 15, 31, 15, 33),
+        ],
+        Parsha::Tazriya => [
+            a(Vayikra, 12, 1, 13, 5),
+            a(Vayikra, 13, 6, 13, 17),
+            a(Vayikra, 13, 18, 13, 23),
+            a(Vayikra, 13, 24, 13, 28),
+            a(Vayikra, 13, 29, 13, 39),
+            a(Vayikra, 13, 40, 13, 54),
+            a(Vayikra, 13, 55, 13, 59),
+            a(Vayikra, 13, 56, 13, 59),
+        ],
+        Parsha::Metzorah => [
+            a(Vayikra, 14, 1, 14, 12),
+            a(Vayikra, 14, 13, 14, 20),
+            a(Vayikra, 14, 21, 14, 32),
+            a(Vayikra, 14, 33, 14, 53),
+            a(Vayikra, 14, 54, 15, 15),
+            a(Vayikra, 15, 16, 15, 28),
+            a(Vayikra, 15, 29, 15, 33),
+            a(Vayikra, 15, 31, 15, 33),
+        ],
+        Parsha::AchareiMosKedoshim => [
+            a(Vayikra, 16, 1, 16, 24),
+            a(Vayikra, 16, 25, 17, 7),
+            a(Vayikra, 17, 8, 18, 5),
+            a(Vayikra, 18, 6, 18, 21),
+            a(Vayikra, 18, 22, 19, 14),
+            a(Vayikra, 19, 15, 19, 32),
+            a(Vayikra, 19, 33, 20, 27),
+            a(Vayikra, 20, 25, 20, 27),
+        ],
+        Parsha::AchareiMos => [
+            a(Vayikra, 16, 1, 16, 17),
+            a(Vayikra, 16, 18, 16, 24),
+            a(Vayikra, 16, 25, 16, 34),
+            a(Vayikra, 17, 1, 17, 7),
+            a(Vayikra, 17, 8, 18, 5),
+            a(Vayikra, 18, 6, 18, 21),
+            a(Vayikra, 18, 22, 18, 30),
+            a(Vayikra, 18, 28, 18, 30),
+        ],
+        Parsha::Kedoshim => [
+            a(Vayikra, 19, 1, 19, 14),
+            a(Vayikra, 19, 15, 19, 22),
+            a(Vayikra, 19, 23, 19, 32),
+            a(Vayikra, 19, 33, 19, 37),
+            a(Vayikra, 20, 1, 20, 7),
+            a(Vayikra, 20, 8, 20, 22),
+            a(Vayikra, 20, 23, 20, 27),
+            a(Vayikra, 20, 25, 20, 27),
+        ],
+        Parsha::Emor => [
+            a(Vayikra, 21, 1, 21, 15),
+            a(Vayikra, 21, 16, 22, 16),
+            a(Vayikra, 22, 17, 22, 33),
+            a(Vayikra, 23, 1, 23, 22),
+            a(Vayikra, 23, 23, 23, 32),
+            a(Vayikra, 23, 33, 23, 44),
+            a(Vayikra, 24, 1, 24, 23),
+            a(Vayikra, 24, 21, 24, 23),
+        ],
+        Parsha::BeharBechukosai => [
+            a(Vayikra, 25, 1, 25, 18),
+            a(Vayikra, 25, 19, 25, 28),
+            a(Vayikra, 25, 29, 25, 38),
+            a(Vayikra, 25, 39, 25, 46),
+            a(Vayikra, 25, 47, 26, 9),
+            a(Vayikra, 26, 10, 26, 46),
+            a(Vayikra, 27, 1, 27, 34),
+            a(Vayikra, 27, 32, 27, 34),
+        ],
+        Parsha::Behar => [
+            a(Vayikra, 25, 1, 25, 13),
+            a(Vayikra, 25, 14, 25, 18),
+            a(Vayikra, 25, 19, 25, 24),
+            a(Vayikra, 25, 25, 25, 28),
+            a(Vayikra, 25, 29, 25, 38),
+            a(Vayikra, 25, 39, 25, 46),
+            a(Vayikra, 25, 47, 26, 2),
+            a(Vayikra, 25, 55, 26, 2),
+        ],
+        Parsha::Bechukosai => [
+            a(Vayikra, 26, 3, 26, 5),
+            a(Vayikra, 26, 6, 26, 9),
+            a(Vayikra, 26, 10, 26, 46),
+            a(Vayikra, 27, 1, 27, 15),
+            a(Vayikra, 27, 16, 27, 21),
+            a(Vayikra, 27, 22, 27, 28),
+            a(Vayikra, 27, 29, 27, 34),
+            a(Vayikra, 27, 32, 27, 34),
+        ],
+        Parsha::Bamidbar => [
+            a(Bamidbar, 1, 1, 1, 19),
+            a(Bamidbar, 1, 20, 1, 54),
+            a(Bamidbar, 2, 1, 2, 34),
+            a(Bamidbar, 3, 1, 3, 13),
+            a(Bamidbar, 3, 14, 3, 39),
+            a(Bamidbar, 3, 40, 3, 51),
+            a(Bamidbar, 4, 1, 4, 20),
+            a(Bamidbar, 4, 17, 4, 20),
+        ],
+        Parsha::Naso => [
+            a(Bamidbar, 4, 21, 4, 37),
+            a(Bamidbar, 4, 38, 4, 49),
+            a(Bamidbar, 5, 1, 5, 10),
+            a(Bamidbar, 5, 11, 6, 27),
+            a(Bamidbar, 7, 1, 7, 41),
+            a(Bamidbar, 7, 42, 7, 71),
+            a(Bamidbar, 7, 72, 7, 89),
+            a(Bamidbar, 7, 87, 7, 89),
+        ],
+        Parsha::Behaaloscha => [
+            a(Bamidbar, 8, 1, 8, 14),
+            a(Bamidbar, 8, 15, 8, 26),
+            a(Bamidbar, 9, 1, 9, 14),
+            a(Bamidbar, 9, 15, 10, 10),
+            a(Bamidbar, 10, 11, 10, 34),
+            a(Bamidbar, 10, 35, 11, 29),
+            a(Bamidbar, 11, 30, 12, 16),
+            a(Bamidbar, 12, 14, 12, 16),
+        ],
+        Parsha::Shlach => [
+            a(Bamidbar, 13, 1, 13, 20),
+            a(Bamidbar, 13, 21, 14, 7),
+            a(Bamidbar, 14, 8, 14, 25),
+            a(Bamidbar, 14, 26, 15, 7),
+            a(Bamidbar, 15, 8, 15, 16),
+            a(Bamidbar, 15, 17, 15, 26),
+            a(Bamidbar, 15, 27, 15, 41),
+            a(Bamidbar, 15, 37, 15, 41),
+        ],
+        Parsha::Korach => [
+            a(Bamidbar, 16, 1, 16, 13),
+            a(Bamidbar, 16, 14, 16, 19),
+            a(Bamidbar, 16, 20, 17, 8),
+            a(Bamidbar, 17, 9, 17, 15),
+            a(Bamidbar, 17, 16, 17, 24),
+            a(Bamidbar, 17, 25, 18, 20),
+            a(Bamidbar, 18, 21, 18, 32),
+            a(Bamidbar, 18, 30, 18, 32),
+        ],
+        Parsha::ChukasBalak => [
+            a(Bamidbar, 19, 1, 19, 17),
+            a(Bamidbar, 19, 18, 20, 6),
+            a(Bamidbar, 20, 7, 20, 13),
+            a(Bamidbar, 20, 14, 21, 20),
+            a(Bamidbar, 21, 21, 22, 12),
+            a(Bamidbar, 22, 13, 22, 38),
+            a(Bamidbar, 22, 39, 25, 9),
+            a(Bamidbar, 25, 7, 25, 9),
+        ],
+        Parsha::Chukas => [
+            a(Bamidbar, 19, 1, 19, 17),
+            a(Bamidbar, 19, 18, 20, 6),
+            a(Bamidbar, 20, 7, 20, 13),
+            a(Bamidbar, 20, 14, 20, 21),
+            a(Bamidbar, 20, 22, 21, 9),
+            a(Bamidbar, 21, 10, 21, 20),
+            a(Bamidbar, 21, 21, 22, 1),
+            a(Bamidbar, 21, 34, 22, 1),
+        ],
+        Parsha::Balak => [
+            a(Bamidbar, 22, 2, 22, 12),
+            a(Bamidbar, 22, 13, 22, 20),
+            a(Bamidbar, 22, 21, 22, 38),
+            a(Bamidbar, 22, 39, 23, 12),
+            a(Bamidbar, 23, 13, 23, 26),
+            a(Bamidbar, 23, 27, 24, 13),
+            a(Bamidbar, 24, 14, 25, 9),
+            a(Bamidbar, 25, 7, 25, 9),
+        ],
+        Parsha::Pinchas => [
+            a(Bamidbar, 25, 10, 26, 4),
+            a(Bamidbar, 26, 5, 26, 51),
+            a(Bamidbar, 26, 52, 27, 5),
+            a(Bamidbar, 27, 6, 27, 23),
+            a(Bamidbar, 28, 1, 28, 15),
+            a(Bamidbar, 28, 16, 29, 11),
+            a(Bamidbar, 29, 12, 30, 1),
+            a(Bamidbar, 29, 35, 30, 1),
+        ],
+        Parsha::MatosMaasei => [
+            a(Bamidbar, 30, 2, 31, 12),
+            a(Bamidbar, 31, 13, 31, 41),
+            a(Bamidbar, 31, 42, 32, 19),
+            a(Bamidbar, 32, 20, 33, 49),
+            a(Bamidbar, 33, 50, 34, 15),
+            a(Bamidbar, 34, 16, 35, 8),
+            a(Bamidbar, 35, 9, 36, 13),
+            a(Bamidbar, 36, 11, 36, 13),
+        ],
+        Parsha::Matos => [
+            a(Bamidbar, 30, 2, 30, 17),
+            a(Bamidbar, 31, 1, 31, 12),
+            a(Bamidbar, 31, 13, 31, 24),
+            a(Bamidbar, 31, 25, 31, 41),
+            a(Bamidbar, 31, 42, 31, 54),
+            a(Bamidbar, 32, 1, 32, 19),
+            a(Bamidbar, 32, 20, 32, 42),
+            a(Bamidbar, 32, 39, 32, 42),
+        ],
+        Parsha::Maasei => [
+            a(Bamidbar, 33, 1, 33, 10),
+            a(Bamidbar, 33, 11, 33, 49),
+            a(Bamidbar, 33, 50, 34, 15),
+            a(Bamidbar, 34, 16, 34, 29),
+            a(Bamidbar, 35, 1, 35, 8),
+            a(Bamidbar, 35, 9, 35, 34),
+            a(Bamidbar, 36, 1, 36, 13),
+            a(Bamidbar, 36, 11, 36, 13),
+        ],
+        Parsha::Devarim => [
+            a(Devarim, 1, 1, 1, 10),
+            a(Devarim, 1, 11, 1, 21),
+            a(Devarim, 1, 22, 1, 38),
+            a(Devarim, 1, 39, 2, 1),
+            a(Devarim, 2, 2, 2, 30),
+            a(Devarim, 2, 31, 3, 14),
+            a(Devarim, 3, 15, 3, 22),
+            a(Devarim, 3, 20, 3, 22),
+        ],
+        Parsha::Vaeschanan => [
+            a(Devarim, 3, 23, 4, 4),
+            a(Devarim, 4, 5, 4, 40),
+            a(Devarim, 4, 41, 4, 49),
+            a(Devarim, 5, 1, 5, 18),
+            a(Devarim, 5, 19, 6, 3),
+            a(Devarim, 6, 4, 6, 25),
+            a(Devarim, 7, 1, 7, 11),
+            a(Devarim, 7, 9, 7, 11),
+        ],
+        Parsha::Eikev => [
+            a(Devarim, 7, 12, 8, 10),
+            a(Devarim, 8, 11, 9, 3),
+            a(Devarim, 9, 4, 9, 29),
+            a(Devarim, 10, 1, 10, 11),
+            a(Devarim, 10, 12, 11, 9),
+            a(Devarim, 11, 10, 11, 21),
+            a(Devarim, 11, 22, 11, 25),
+            a(Devarim, 11, 22, 11, 25),
+        ],
+        Parsha::Reeh => [
+            a(Devarim, 11, 26, 12, 10),
+            a(Devarim, 12, 11, 12, 28),
+            a(Devarim, 12, 29, 13, 19),
+            a(Devarim, 14, 1, 14, 21),
+            a(Devarim, 14, 22, 14, 29),
+            a(Devarim, 15, 1, 15, 18),
+            a(Devarim, 15, 19, 16, 17),
+            a(Devarim, 16, 13, 16, 17),
+        ],
+        Parsha::Shoftim => [
+            a(Devarim, 16, 18, 17, 13),
+            a(Devarim, 17, 14, 17, 20),
+            a(Devarim, 18, 1, 18, 5),
+            a(Devarim, 18, 6, 18, 13),
+            a(Devarim, 18, 14, 19, 13),
+            a(Devarim, 19, 14, 20, 9),
+            a(Devarim, 20, 10, 21, 9),
+            a(Devarim, 21, 7, 21, 9),
+        ],
+        Parsha::KiSeitzei => [
+            a(Devarim, 21, 10, 21, 21),
+            a(Devarim, 21, 22, 22, 7),
+            a(Devarim, 22, 8, 23, 7),
+            a(Devarim, 23, 8, 23, 24),
+            a(Devarim, 23, 25, 24, 4),
+            a(Devarim, 24, 5, 24, 13),
+            a(Devarim, 24, 14, 25, 19),
+            a(Devarim, 25, 17, 25, 19),
+        ],
+        Parsha::KiSavoh => [
+            a(Devarim, 26, 1, 26, 11),
+            a(Devarim, 26, 12, 26, 15),
+            a(Devarim, 26, 16, 26, 19),
+            a(Devarim, 27, 1, 27, 10),
+            a(Devarim, 27, 11, 28, 6),
+            a(Devarim, 28, 7, 28, 69),
+            a(Devarim, 29, 1, 29, 8),
+            a(Devarim, 29, 6, 29, 8),
+        ],
+        Parsha::NitzavimVayelech => [
+            a(Devarim, 29, 9, 29, 28),
+            a(Devarim, 30, 1, 30, 6),
+            a(Devarim, 30, 7, 30, 14),
+            a(Devarim, 30, 15, 31, 6),
+            a(Devarim, 31, 7, 31, 13),
+            a(Devarim, 31, 14, 31, 19),
+            a(Devarim, 31, 20, 31, 30),
+            a(Devarim, 31, 28, 31, 30),
+        ],
+        Parsha::Nitzavim => [
+            a(Devarim, 29, 9, 29, 11),
+            a(Devarim, 29, 12, 29, 14),
+            a(Devarim, 29, 15, 29, 28),
+            a(Devarim, 30, 1, 30, 6),
+            a(Devarim, 30, 7, 30, 10),
+            a(Devarim, 30, 11, 30, 14),
+            a(Devarim, 30, 15, 30, 20),
+            a(Devarim, 30, 15, 30, 20),
+        ],
+        Parsha::Vayelech => [
+            a(Devarim, 31, 1, 31, 3),
+            a(Devarim, 31, 4, 31, 6),
+            a(Devarim, 31, 7, 31, 9),
+            a(Devarim, 31, 10, 31, 13),
+            a(Devarim, 31, 14, 31, 19),
+            a(Devarim, 31, 20, 31, 24),
+            a(Devarim, 31, 25, 31, 30),
+            a(Devarim, 31, 28, 31, 30),
+        ],
+        Parsha::Haazinu => [
+            a(Devarim, 32, 1, 32, 6),
+            a(Devarim, 32, 7, 32, 12),
+            a(Devarim, 32, 13, 32, 18),
+            a(Devarim, 32, 19, 32, 28),
+            a(Devarim, 32, 29, 32, 39),
+            a(Devarim, 32, 40, 32, 43),
+            a(Devarim, 32, 44, 32, 52),
+            a(Devarim, 32, 48, 32, 52),
+        ],
+    }
+}
+
+/// The reading of Shabbos Chol HaMoed.
+const SHABBOS_CHOL_HAMOED: [Aliyah; 7] = [
+    a(Shemos, 33, 12, 33, 16),
+    a(Shemos, 33, 17, 33, 19),
+    a(Shemos, 33, 20, 33, 23),
+    a(Shemos, 34, 1, 34, 3),
+    a(Shemos, 34, 4, 34, 10),
+    a(Shemos, 34, 11, 34, 17),
+    a(Shemos, 34, 18, 34, 26),
+];
+
+/// The sacrifices of each day of Sukkos, starting from the second day.
+const SUKKOS_KORBANOS: [Aliyah; 6] = [
+    a(Bamidbar, 29, 17, 29, 19),
+    a(Bamidbar, 29, 20, 29, 22),
+    a(Bamidbar, 29, 23, 29, 25),
+    a(Bamidbar, 29, 26, 29, 28),
+    a(Bamidbar, 29, 29, 29, 31),
+    a(Bamidbar, 29, 32, 29, 34),
+];
+
+const PESACH_MAFTIR: Aliyah = a(Bamidbar, 28, 19, 28, 25);
+const SHAVUOS_MAFTIR: Aliyah = a(Bamidbar, 28, 26, 28, 31);
+const SHMINI_ATZERES_MAFTIR: Aliyah = a(Bamidbar, 29, 35, 30, 1);
+
+/// "Aser Te'aser" - read on Shmini Atzeres in Chutz La'aretz, and on the last day of Pesach and
+/// Shavuos when it's Shabbos.
+const ASER_TEASER: [Aliyah; 7] = [
+    a(Devarim, 14, 22, 14, 29),
+    a(Devarim, 15, 1, 15, 18),
+    a(Devarim, 15, 19, 15, 23),
+    a(Devarim, 16, 1, 16, 3),
+    a(Devarim, 16, 4, 16, 8),
+    a(Devarim, 16, 9, 16, 12),
+    a(Devarim, 16, 13, 16, 17),
+];
+
+/// "Kol HaBechor" - read on the last day of Pesach and Shavuos on a weekday.
+const KOL_HABECHOR: [Aliyah; 5] = [
+    a(Devarim, 15, 19, 15, 23),
+    a(Devarim, 16, 1, 16, 3),
+    a(Devarim, 16, 4, 16, 8),
+    a(Devarim, 16, 9, 16, 12),
+    a(Devarim, 16, 13, 16, 17),
+];
+
+const SIMCHAS_TORAH: [Aliyah; 8] = [
+    a(Devarim, 33, 1, 33, 7),
+    a(Devarim, 33, 8, 33, 12),
+    a(Devarim, 33, 13, 33, 17),
+    a(Devarim, 33, 18, 33, 21),
+    a(Devarim, 33, 22, 33, 26),
+    a(Devarim, 33, 27, 33, 29),
+    a(Devarim, 33, 27, 34, 12),
+    a(Bereishis, 1, 1, 2, 3),
+];
+
+const EMOR_MOADIM: [Aliyah; 5] = [
+    a(Vayikra, 22, 26, 23, 3),
+    a(Vayikra, 23, 4, 23, 14),
+    a(Vayikra, 23, 15, 23, 22),
+    a(Vayikra, 23, 23, 23, 32),
+    a(Vayikra, 23, 33, 23, 44),
+];
+
+/// Yom Tov readings are given as they're split on a weekday, unless the reading itself is
+/// different on Shabbos.
+fn yom_tov_reading(yom_tov: YomTov, location: Location, weekday: Weekday) -> Reading {
+    let shabbos = weekday == Weekday::Sat;
+    let chul = location == Location::Chul;
+    match yom_tov {
+        YomTov::RoshHashanah1 => reading(
+            &[
+                a(Bereishis, 21, 1, 21, 4),
+                a(Bereishis, 21, 5, 21, 12),
+                a(Bereishis, 21, 13, 21, 21),
+                a(Bereishis, 21, 22, 21, 27),
+                a(Bereishis, 21, 28, 21, 34),
+            ],
+            Some(a(Bamidbar, 29, 1, 29, 6)),
+        ),
+        YomTov::RoshHashanah2 => reading(
+            &[
+                a(Bereishis, 22, 1, 22, 3),
+                a(Bereishis, 22, 4, 22, 8),
+                a(Bereishis, 22, 9, 22, 14),
+                a(Bereishis, 22, 15, 22, 19),
+                a(Bereishis, 22, 20, 22, 24),
+            ],
+            Some(a(Bamidbar, 29, 1, 29, 6)),
+        ),
+        YomTov::YomKippur => reading(
+            &[
+                a(Vayikra, 16, 1, 16, 6),
+                a(Vayikra, 16, 7, 16, 11),
+                a(Vayikra, 16, 12, 16, 17),
+                a(Vayikra, 16, 18, 16, 24),
+                a(Vayikra, 16, 25, 16, 30),
+                a(Vayikra, 16, 31, 16, 34),
+            ],
+            Some(a(Bamidbar, 29, 7, 29, 11)),
+        ),
+        YomTov::Sukkos1 => reading(&EMOR_MOADIM, Some(a(Bamidbar, 29, 12, 29, 16))),
+        YomTov::Sukkos2 if chul => reading(&EMOR_MOADIM, Some(a(Bamidbar, 29, 12, 29, 16))),
+        YomTov::Sukkos2
+        | YomTov::Sukkos3
+        | YomTov::Sukkos4
+        | YomTov::Sukkos5
+        | YomTov::Sukkos6
+        | YomTov::Sukkos7 => {
+            let day = match yom_tov {
+                YomTov::Sukkos2 => 0,
+                YomTov::Sukkos3 => 1,
+                YomTov::Sukkos4 => 2,
+                YomTov::Sukkos5 => 3,
+                YomTov::Sukkos6 => 4,
+                _ => 5,
+            };
+            if shabbos && chul {
+                // Maftir reads the sacrifices of both days it could be.
+                let yesterday = SUKKOS_KORBANOS[day - 1];
+                let today = SUKKOS_KORBANOS[day];
+                reading(
+                    &SHABBOS_CHOL_HAMOED,
+                    Some(Aliyah {
+                        end: today.end,
+                        ..yesterday
+                    }),
+                )
+            } else if shabbos {
+                reading(&SHABBOS_CHOL_HAMOED, Some(SUKKOS_KORBANOS[day]))
+            } else if chul {
+                // Since we're not sure which day it is, we read yesterday's sacrifices, today's
+                // and tomorrow's, and then repeat yesterday's and today's. On Hoshana Rabbah
+                // there's no tomorrow, so we start from the day before yesterday.
+                let first = day.min(4) - 1;
+                let yesterday = SUKKOS_KORBANOS[day - 1];
+                let today = SUKKOS_KORBANOS[day];
+                reading(
+                    &[
+                        SUKKOS_KORBANOS[first],
+                        SUKKOS_KORBANOS[first + 1],
+                        SUKKOS_KORBANOS[first + 2],
+                        Aliyah {
+                            end: today.end,
+                            ..yesterday
+                        },
+                    ],
+                    None,
+                )
+            } else {
+                let today = SUKKOS_KORBANOS[day];
+                reading(&[today, today, today, today], None)
+            }
+        }
+        YomTov::ShminiAtzeres if !chul => reading(&SIMCHAS_TORAH, Some(SHMINI_ATZERES_MAFTIR)),
+        YomTov::ShminiAtzeres => reading(&ASER_TEASER, Some(SHMINI_ATZERES_MAFTIR)),
+        YomTov::SimchasTorah => reading(&SIMCHAS_TORAH, Some(SHMINI_ATZERES_MAFTIR)),
+        YomTov::Pesach1 => reading(
+            &[
+                a(Shemos, 12, 21, 12, 24),
+                a(Shemos, 12, 25, 12, 28),
+                a(Shemos, 12, 29, 12, 36),
+                a(Shemos, 12, 37, 12, 42),
+                a(Shemos, 12, 43, 12, 51),
+            ],
+            Some(a(Bamidbar, 28, 16, 28, 25)),
+        ),
+        YomTov::Pesach2 if chul => reading(&EMOR_MOADIM, Some(a(Bamidbar, 28, 16, 28, 25))),
+        YomTov::Pesach2 | YomTov::Pesach3 | YomTov::Pesach4 | YomTov::Pesach5 | YomTov::Pesach6 => {
+            if shabbos {
+                reading(&SHABBOS_CHOL_HAMOED, Some(PESACH_MAFTIR))
+            } else {
+                let mut aliyot = match yom_tov {
+                    YomTov::Pesach2 => vec![
+                        a(Vayikra, 22, 26, 23, 3),
+                        a(Vayikra, 23, 4, 23, 14),
+                        a(Vayikra, 23, 15, 23, 44),
+                    ],
+                    YomTov::Pesach3 => vec![
+                        a(Shemos, 13, 1, 13, 4),
+                        a(Shemos, 13, 5, 13, 10),
+                        a(Shemos, 13, 11, 13, 16),
+                    ],
+                    YomTov::Pesach4 => vec![
+                        a(Shemos, 22, 24, 22, 26),
+                        a(Shemos, 22, 27, 23, 5),
+                        a(Shemos, 23, 6, 23, 19),
+                    ],
+                    YomTov::Pesach5 => vec![
+                        a(Shemos, 34, 1, 34, 3),
+                        a(Shemos, 34, 4, 34, 17),
+                        a(Shemos, 34, 18, 34, 26),
+                    ],
+                    _ => vec![
+                        a(Bamidbar, 9, 1, 9, 5),
+                        a(Bamidbar, 9, 6, 9, 8),
+                        a(Bamidbar, 9, 9, 9, 14),
+                    ],
+                };
+                aliyot.push(PESACH_MAFTIR);
+                Reading {
+                    aliyot,
+                    maftir: None,
+                }
+            }
+        }
+        YomTov::Pesach7 => reading(
+            &[
+                a(Shemos, 13, 17, 13, 22),
+                a(Shemos, 14, 1, 14, 8),
+                a(Shemos, 14, 9, 14, 14),
+                a(Shemos, 14, 15, 14, 25),
+                a(Shemos, 14, 26, 15, 26),
+            ],
+            Some(PESACH_MAFTIR),
+        ),
+        YomTov::Pesach8 if shabbos => reading(&ASER_TEASER, Some(PESACH_MAFTIR)),
+        YomTov::Pesach8 => reading(&KOL_HABECHOR, Some(PESACH_MAFTIR)),
+        YomTov::Shavuos1 => reading(
+            &[
+                a(Shemos, 19, 1, 19, 6),
+                a(Shemos, 19, 7, 19, 13),
+                a(Shemos, 19, 14, 19, 19),
+                a(Shemos, 19, 20, 20, 14),
+                a(Shemos, 20, 15, 20, 23),
+            ],
+            Some(SHAVUOS_MAFTIR),
+        ),
+        YomTov::Shavuos2 if shabbos => reading(&ASER_TEASER, Some(SHAVUOS_MAFTIR)),
+        YomTov::Shavuos2 => reading(&KOL_HABECHOR, Some(SHAVUOS_MAFTIR)),
+    }
+}
+
+const FAST_DAY: [Aliyah; 3] = [
+    a(Shemos, 32, 11, 32, 14),
+    a(Shemos, 34, 1, 34, 3),
+    a(Shemos, 34, 4, 34, 10),
+];
+
+const ROSH_CHODESH: [Aliyah; 4] = [
+    a(Bamidbar, 28, 1, 28, 3),
+    a(Bamidbar, 28, 3, 28, 5),
+    a(Bamidbar, 28, 6, 28, 10),
+    a(Bamidbar, 28, 11, 28, 15),
+];
+
+/// The first three aliyos of the Rosh Chodesh reading when it's also Chanukah. The fourth is
+/// from Chanukah, and depends on the day.
+const ROSH_CHODESH_CHANUKAH: [Aliyah; 3] = [
+    a(Bamidbar, 28, 1, 28, 5),
+    a(Bamidbar, 28, 6, 28, 10),
+    a(Bamidbar, 28, 11, 28, 15),
+];
+
+/// Rosh Chodesh Teves is the sixth day of Chanukah, and when it's two days, also the seventh.
+fn rosh_chodesh_chanukah(day: u8) -> Reading {
+    let start = 6 * day + 6;
+    let mut aliyot = ROSH_CHODESH_CHANUKAH.to_vec();
+    aliyot.push(a(Bamidbar, 7, start, 7, start + 5));
+    reading(&aliyot, None)
+}
+
+fn chanukah(day: u8, minhag: Minhag) -> Reading {
+    if day == 1 {
+        reading(
+            &[
+                a(Bamidbar, 7, 1, 7, 11),
+                a(Bamidbar, 7, 12, 7, 14),
+                a(Bamidbar, 7, 15, 7, 17),
+            ],
+            None,
+        )
+    } else if day == 8 {
+        reading(
+            &[
+                a(Bamidbar, 7, 54, 7, 56),
+                a(Bamidbar, 7, 57, 7, 59),
+                a(Bamidbar, 7, 60, 8, 4),
+            ],
+            None,
+        )
+    } else {
+        // Each day, we read about the Nasi who brought his offering on that day. Sephardim repeat
+        // it for the third aliyah, and Ashkenazim read about the next day's Nasi.
+        let start = 6 * day + 6;
+        let third = match minhag {
+            Minhag::EdotHaMizrach | Minhag::Yemenite => a(Bamidbar, 7, start, 7, start + 5),
+            Minhag::Ashkenaz | Minhag::Sefard | Minhag::Chabad => {
+                a(Bamidbar, 7, start + 6, 7, start + 11)
+            }
+        };
+        reading(
+            &[
+                a(Bamidbar, 7, start, 7, start + 2),
+                a(Bamidbar, 7, start + 3, 7, start + 5),
+                third,
+            ],
+            None,
+        )
+    }
+}
+
+fn chol_reading(chol: Chol, minhag: Minhag) -> Reading {
+    match chol {
+        Chol::TzomGedalia | Chol::TenTeves | Chol::TaanisEsther | Chol::SeventeenTammuz => {
+            reading(&FAST_DAY, None)
+        }
+        Chol::NineAv => reading(
+            &[
+                a(Devarim, 4, 25, 4, 29),
+                a(Devarim, 4, 30, 4, 35),
+                a(Devarim, 4, 36, 4, 40),
+            ],
+            None,
+        ),
+        Chol::Purim | Chol::ShushanPurim => reading(
+            &[
+                a(Shemos, 17, 8, 17, 10),
+                a(Shemos, 17, 11, 17, 13),
+                a(Shemos, 17, 14, 17, 16),
+            ],
+            None,
+        ),
+        Chol::Chanukah1 => chanukah(1, minhag),
+        Chol::Chanukah2 => chanukah(2, minhag),
+        Chol::Chanukah3 => chanukah(3, minhag),
+        Chol::Chanukah4 => chanukah(4, minhag),
+        Chol::Chanukah5 => chanukah(5, minhag),
+        Chol::Chanukah6 => chanukah(6, minhag),
+        Chol::Chanukah7 => chanukah(7, minhag),
+        Chol::Chanukah8 => chanukah(8, minhag),
+        Chol::RoshChodeshTeves | Chol::RoshChodeshTeves1 => rosh_chodesh_chanukah(6),
+        Chol::RoshChodeshTeves2 => rosh_chodesh_chanukah(7),
+        Chol::RoshChodeshCheshvan1
+        | Chol::RoshChodeshCheshvan2
+        | Chol::RoshChodeshKislev
+        | Chol::RoshChodeshKislev1
+        | Chol::RoshChodeshKislev2
+        | Chol::RoshChodeshShvat
+        | Chol::RoshChodeshAdar1
+        | Chol::RoshChodeshAdar2
+        | Chol::RoshChodeshAdarRishon1
+        | Chol::RoshChodeshAdarRishon2
+        | Chol::RoshChodeshAdarSheni1
+        | Chol::RoshChodeshAdarSheni2
+        | Chol::RoshChodeshNissan
+        | Chol::RoshChodeshIyar1
+        | Chol::RoshChodeshIyar2
+        | Chol::RoshChodeshSivan
+        | Chol::RoshChodeshTammuz1
+        | Chol::RoshChodeshTammuz2
+        | Chol::RoshChodeshAv
+        | Chol::RoshChodeshElul1
+        | Chol::RoshChodeshElul2 => reading(&ROSH_CHODESH, None),
+    }
+}
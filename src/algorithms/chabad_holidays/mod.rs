@@ -1,131 +1,129 @@
 use crate::args::types::{DayVal, Language, Name};
 use heca_lib::prelude::HebrewMonth;
 use heca_lib::HebrewYear;
-use std::convert::TryInto;
+use serde::Serialize;
 use std::io::{BufWriter, StdoutLock, Write};
 use std::num::NonZeroI8;
 
-pub fn get(year: &HebrewYear) -> Vec<DayVal> {
-    let mut return_vec = vec![];
-    get_yud_kislev(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
-    get_yud_tes_kislev(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
-    get_chof_kislev(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
-    get_yud_beis_tammuz(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
-    get_yud_gimmel_tammuz(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
-    return_vec
-}
-
-fn get_yud_kislev(year: &HebrewYear) -> Option<DayVal> {
-    if year.year() < 5588 {
-        None
-    } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Kislev, NonZeroI8::new(10).unwrap())
-                .unwrap()
-                .try_into()
-                .unwrap(),
-            name: Name::ChabadHoliday(ChabadHoliday::YudKislev),
-            candle_lighting: None,
-        })
-    }
-}
-
-fn get_yud_tes_kislev(year: &HebrewYear) -> Option<DayVal> {
-    if year.year() <= 5559 {
-        None
-    } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Kislev, NonZeroI8::new(19).unwrap())
-                .unwrap()
-                .try_into()
-                .unwrap(),
-            name: Name::ChabadHoliday(ChabadHoliday::YudTesKislev),
-            candle_lighting: None,
-        })
-    }
-}
-
-fn get_chof_kislev(year: &HebrewYear) -> Option<DayVal> {
-    if year.year() <= 5559 {
-        None
-    } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Kislev, NonZeroI8::new(20).unwrap())
-                .unwrap()
-                .try_into()
-                .unwrap(),
-            name: Name::ChabadHoliday(ChabadHoliday::ChofKislev),
-            candle_lighting: None,
-        })
-    }
-}
+/// Every day on the Chabad calendar, with the first year it was kept and its date.
+const HOLIDAYS: [(ChabadHoliday, u64, HebrewMonth, i8); 14] = [
+    (ChabadHoliday::VavTishrei, 5726, HebrewMonth::Tishrei, 6),
+    (ChabadHoliday::YudKislev, 5588, HebrewMonth::Kislev, 10),
+    (ChabadHoliday::YudTesKislev, 5560, HebrewMonth::Kislev, 19),
+    (ChabadHoliday::ChofKislev, 5560, HebrewMonth::Kislev, 20),
+    (ChabadHoliday::HeiTeves, 5748, HebrewMonth::Teves, 5),
+    (ChabadHoliday::ChofDaledTeves, 5574, HebrewMonth::Teves, 24),
+    (ChabadHoliday::YudShvat, 5711, HebrewMonth::Shvat, 10),
+    (ChabadHoliday::ChofBeisShvat, 5749, HebrewMonth::Shvat, 22),
+    (ChabadHoliday::YudAlephNissan, 5663, HebrewMonth::Nissan, 11),
+    (ChabadHoliday::GimmelTammuz, 5755, HebrewMonth::Tammuz, 3),
+    (ChabadHoliday::YudBeisTammuz, 5688, HebrewMonth::Tammuz, 12),
+    (
+        ChabadHoliday::YudGimmelTammuz,
+        5688,
+        HebrewMonth::Tammuz,
+        13,
+    ),
+    (ChabadHoliday::ChofAv, 5705, HebrewMonth::Av, 20),
+    (ChabadHoliday::ChaiElul, 5459, HebrewMonth::Elul, 18),
+];
 
-fn get_yud_beis_tammuz(year: &HebrewYear) -> Option<DayVal> {
-    if year.year() <= 5687 {
-        None
-    } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Tammuz, NonZeroI8::new(12).unwrap())
-                .unwrap()
-                .try_into()
-                .unwrap(),
-            name: Name::ChabadHoliday(ChabadHoliday::YudBeisTammuz),
-            candle_lighting: None,
-        })
-    }
-}
-
-fn get_yud_gimmel_tammuz(year: &HebrewYear) -> Option<DayVal> {
-    if year.year() <= 5687 {
-        None
-    } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Tammuz, NonZeroI8::new(13).unwrap())
-                .unwrap()
-                .try_into()
-                .unwrap(),
-            name: Name::ChabadHoliday(ChabadHoliday::YudGimmelTammuz),
-            candle_lighting: None,
+pub fn get(year: &HebrewYear) -> Vec<DayVal> {
+    HOLIDAYS
+        .iter()
+        .filter(|(_, first_year, _, _)| year.year() >= *first_year)
+        .map(|(holiday, _, month, day)| {
+            DayVal::new(
+                year.get_hebrew_date(*month, NonZeroI8::new(*day).unwrap())
+                    .unwrap()
+                    .into(),
+                Name::ChabadHoliday(*holiday),
+            )
         })
-    }
+        .collect()
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ChabadHoliday {
+    VavTishrei,
     YudKislev,
     YudTesKislev,
     ChofKislev,
+    HeiTeves,
+    ChofDaledTeves,
+    YudShvat,
+    ChofBeisShvat,
+    YudAlephNissan,
+    GimmelTammuz,
     YudBeisTammuz,
     YudGimmelTammuz,
+    ChofAv,
+    ChaiElul,
+}
+
+/// What a day commemorates: the passing of a Rebbe or a member of his family, a release from
+/// prison, or a birthday.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Kind {
+    Hilula,
+    Geulah,
+    Birthday,
 }
 
 impl ChabadHoliday {
+    /// Tachanun isn't said on the days of Geulah.
+    pub fn no_tachanun(self) -> bool {
+        matches!(
+            self,
+            Self::YudKislev
+                | Self::YudTesKislev
+                | Self::ChofKislev
+                | Self::YudBeisTammuz
+                | Self::YudGimmelTammuz
+        )
+    }
+
+    /// What the day commemorates. Hei Teves, when the court returned the Rebbe's library, is none
+    /// of these.
+    pub fn kind(self) -> Option<Kind> {
+        match self {
+            Self::VavTishrei
+            | Self::ChofDaledTeves
+            | Self::YudShvat
+            | Self::ChofBeisShvat
+            | Self::GimmelTammuz
+            | Self::ChofAv => Some(Kind::Hilula),
+            Self::YudKislev
+            | Self::YudTesKislev
+            | Self::ChofKislev
+            | Self::YudBeisTammuz
+            | Self::YudGimmelTammuz => Some(Kind::Geulah),
+            Self::YudAlephNissan | Self::ChaiElul => Some(Kind::Birthday),
+            Self::HeiTeves => None,
+        }
+    }
+
     pub fn pretty_print(
         &self,
         lock: &mut BufWriter<StdoutLock<'_>>,
         language: Language,
     ) -> Option<usize> {
-        let p = match language {
-            Language::English => match self {
-                Self::YudKislev => lock.write(b"Yud Kislev").ok()?,
-                Self::YudTesKislev => lock.write(b"Yud Tes Kislev").ok()?,
-                Self::ChofKislev => lock.write(b"Chof Kislev").ok()?,
-                Self::YudBeisTammuz => lock.write(b"Yud Beis Tammuz").ok()?,
-                Self::YudGimmelTammuz => lock.write(b"Yud Gimmel Tammuz").ok()?,
-            },
-            Language::Hebrew => match self {
-                Self::YudKislev => lock.write("י בכסלו".as_bytes()).ok()?,
-                Self::YudTesKislev => lock.write("י\"ט כסלו".as_bytes()).ok()?,
-                Self::ChofKislev => lock.write("כ כסלו".as_bytes()).ok()?,
-                Self::YudBeisTammuz => lock.write("י\"ב תמוז".as_bytes()).ok()?,
-                Self::YudGimmelTammuz => lock.write("י\"ג תמוז".as_bytes()).ok()?,
-            },
+        let name = match self {
+            Self::VavTishrei => "Vav Tishrei",
+            Self::YudKislev => "Yud Kislev",
+            Self::YudTesKislev => "Yud Tes Kislev",
+            Self::ChofKislev => "Chof Kislev",
+            Self::HeiTeves => "Hei Teves",
+            Self::ChofDaledTeves => "Chof Daled Teves",
+            Self::YudShvat => "Yud Shvat",
+            Self::ChofBeisShvat => "Chof Beis Shvat",
+            Self::YudAlephNissan => "Yud Aleph Nissan",
+            Self::GimmelTammuz => "Gimmel Tammuz",
+            Self::YudBeisTammuz => "Yud Beis Tammuz",
+            Self::YudGimmelTammuz => "Yud Gimmel Tammuz",
+            Self::ChofAv => "Chof Av",
+            Self::ChaiElul => "Chai Elul",
         };
-        Some(p)
+        lock.write(language.tr(name).as_bytes()).ok()
     }
 }
@@ -0,0 +1,117 @@
+use crate::args::types::Language;
+use crate::prelude::print;
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use heca_lib::prelude::{Location, TorahReading, TorahReadingType};
+use heca_lib::HebrewYear;
+use serde::ser::*;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+
+/// What's read on a Shabbos in the other location, when it isn't the same as what's read here.
+/// This happens when the eighth day of Pesach or the second day of Shavuos is on Shabbos, until
+/// Chutz La'aretz catches up by combining two parshiyos that Israel reads separately.
+#[derive(Clone, Copy)]
+pub struct OtherLocation {
+    pub location: Location,
+    pub reading: TorahReading,
+}
+
+impl std::fmt::Debug for OtherLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.reading)
+    }
+}
+
+impl Serialize for OtherLocation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("OtherLocation", 3)?;
+        state.serialize_field("location", &self.location)?;
+        match self.reading {
+            TorahReading::Shabbos(parsha) => {
+                state.serialize_field("type", "Shabbos")?;
+                state.serialize_field("name", &parsha)?;
+            }
+            TorahReading::YomTov(yom_tov) => {
+                state.serialize_field("type", "YomTov")?;
+                state.serialize_field("name", &yom_tov)?;
+            }
+            TorahReading::Chol(chol) => {
+                state.serialize_field("type", "Chol")?;
+                state.serialize_field("name", &chol)?;
+            }
+            TorahReading::SpecialParsha(special_parsha) => {
+                state.serialize_field("type", "SpecialParsha")?;
+                state.serialize_field("name", &special_parsha)?;
+            }
+        }
+        state.end()
+    }
+}
+
+impl OtherLocation {
+    pub fn pretty(&self, language: Language) -> String {
+        let location = match self.location {
+            Location::Israel => "In Israel",
+            Location::Chul => "In Chutz La'aretz",
+        };
+        format!(
+            "{}: {}",
+            language.tr(location),
+            print::torah_reading(self.reading, language)
+        )
+    }
+}
+
+/// What's read on every Shabbos of the year outside of `location`, by the evening on which the
+/// Shabbos starts.
+pub fn get(
+    year: &HebrewYear,
+    location: Location,
+) -> (Location, HashMap<DateTime<Utc>, TorahReading>) {
+    let other = match location {
+        Location::Israel => Location::Chul,
+        Location::Chul => Location::Israel,
+    };
+    let readings = year
+        .get_holidays(
+            other,
+            &[TorahReadingType::Shabbos, TorahReadingType::YomTov],
+        )
+        .into_iter()
+        .filter_map(|x| {
+            let day: DateTime<Utc> = x.day().into();
+            if (day + Duration::days(1)).weekday() == Weekday::Sat {
+                Some((day, x.name()))
+            } else {
+                None
+            }
+        })
+        .collect();
+    (other, readings)
+}
+
+/// Compares what's read here on `day` with what's read in the other location. Two different
+/// days of Yom Tov aren't a divergence, since only the parsha is ever out of step.
+pub fn compare(
+    day: DateTime<Utc>,
+    reading: TorahReading,
+    other: &(Location, HashMap<DateTime<Utc>, TorahReading>),
+) -> Option<OtherLocation> {
+    let (location, readings) = other;
+    let other_reading = *readings.get(&day)?;
+    if other_reading == reading {
+        return None;
+    }
+    match (reading, other_reading) {
+        (TorahReading::Shabbos(_), TorahReading::Shabbos(_))
+        | (TorahReading::Shabbos(_), TorahReading::YomTov(_))
+        | (TorahReading::YomTov(_), TorahReading::Shabbos(_)) => Some(OtherLocation {
+            location: *location,
+            reading: other_reading,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,556 @@
+use crate::args::types::{Language, Minhag};
+use chrono::{DateTime, Duration, Utc};
+use heca_lib::prelude::{
+    HebrewMonth, Location, Parsha, SpecialParsha, TorahReading, TorahReadingType, YomTov,
+};
+use heca_lib::{HebrewDate, HebrewYear};
+use serde::ser::*;
+use serde::{Serialize, Serializer};
+use std::convert::TryInto;
+use std::num::NonZeroI8;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Navi {
+    Yehoshua,
+    Shoftim,
+    ShmuelAleph,
+    ShmuelBeis,
+    MelachimAleph,
+    MelachimBeis,
+    Yeshayahu,
+    Yirmiyahu,
+    Yechezkel,
+    Hoshea,
+    Yoel,
+    Amos,
+    Ovadia,
+    Micha,
+    Chavakuk,
+    Zecharia,
+    Malachi,
+}
+
+use Navi::*;
+
+/// A range of verses, from `start` to `end` inclusive. Each is a (chapter, verse) pair.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Section {
+    pub book: Navi,
+    pub start: (u8, u8),
+    pub end: (u8, u8),
+}
+
+const fn s(
+    book: Navi,
+    start_chapter: u8,
+    start_verse: u8,
+    end_chapter: u8,
+    end_verse: u8,
+) -> Section {
+    Section {
+        book,
+        start: (start_chapter, start_verse),
+        end: (end_chapter, end_verse),
+    }
+}
+
+impl Serialize for Section {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Section", 3)?;
+        state.serialize_field("book", &self.book)?;
+        state.serialize_field("start", &format!("{}:{}", self.start.0, self.start.1))?;
+        state.serialize_field("end", &format!("{}:{}", self.end.0, self.end.1))?;
+        state.end()
+    }
+}
+
+impl Section {
+    fn pretty(&self, language: Language) -> String {
+        let (start, end) = (self.start, self.end);
+        let book = navi_name(self.book, language);
+        if start == end {
+            format!("{} {}:{}", book, start.0, start.1)
+        } else if start.0 == end.0 {
+            format!("{} {}:{}-{}", book, start.0, start.1, end.1)
+        } else {
+            format!("{} {}:{}-{}:{}", book, start.0, start.1, end.0, end.1)
+        }
+    }
+}
+
+fn navi_name(book: Navi, language: Language) -> &'static str {
+    language.tr(match book {
+        Yehoshua => "Yehoshua",
+        Shoftim => "Shoftim",
+        ShmuelAleph => "Shmuel I",
+        ShmuelBeis => "Shmuel II",
+        MelachimAleph => "Melachim I",
+        MelachimBeis => "Melachim II",
+        Yeshayahu => "Yeshayahu",
+        Yirmiyahu => "Yirmiyahu",
+        Yechezkel => "Yechezkel",
+        Hoshea => "Hoshea",
+        Yoel => "Yoel",
+        Amos => "Amos",
+        Ovadia => "Ovadia",
+        Micha => "Micha",
+        Chavakuk => "Chavakuk",
+        Zecharia => "Zecharia",
+        Malachi => "Malachi",
+    })
+}
+
+/// The Haftarah, in the order it's read. Most are a single section, but some skip verses or
+/// continue in a different book.
+#[derive(Debug, Clone)]
+pub struct Haftarah(pub Vec<Section>);
+
+impl Serialize for Haftarah {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl Haftarah {
+    pub fn pretty(&self, language: Language) -> String {
+        self.0
+            .iter()
+            .map(|section| section.pretty(language))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Nusach Sefard reads the Ashkenazi list, and Chabad reads it besides where `parsha_haftarah`,
+/// `shuva` and `shimu` say otherwise. The Yemenites read the Sephardi list besides where
+/// `parsha_haftarah` says otherwise. Both Chabad and the Yemenites add verses on Shabbos Rosh Chodesh
+/// and Machar Chodesh, see `with_rosh_chodesh_verses`.
+fn is_sefardi(minhag: Minhag) -> bool {
+    minhag == Minhag::EdotHaMizrach || minhag == Minhag::Yemenite
+}
+
+fn by_minhag(minhag: Minhag, ashkenaz: &[Section], sefardi: &[Section]) -> Haftarah {
+    if is_sefardi(minhag) {
+        Haftarah(sefardi.to_vec())
+    } else {
+        Haftarah(ashkenaz.to_vec())
+    }
+}
+
+/// `day` is the evening on which the day starts. Weekday readings (besides Shabbos Chol HaMoed)
+/// don't have a Haftarah.
+pub fn get(
+    torah_reading: TorahReading,
+    day: DateTime<Utc>,
+    location: Location,
+    minhag: Minhag,
+) -> Option<Haftarah> {
+    let date: HebrewDate = (day + Duration::hours(1)).try_into().unwrap();
+    let shabbos = date_is_shabbos(day);
+    match torah_reading {
+        TorahReading::Shabbos(parsha) => Some(shabbos_haftarah(parsha, date, location, minhag)),
+        TorahReading::SpecialParsha(special_parsha) => {
+            Some(special_parsha_haftarah(special_parsha, minhag))
+        }
+        TorahReading::YomTov(yom_tov) => yom_tov_haftarah(yom_tov, shabbos, location, minhag),
+        TorahReading::Chol(_) => None,
+    }
+}
+
+fn date_is_shabbos(day: DateTime<Utc>) -> bool {
+    use chrono::Datelike;
+    (day + Duration::days(1)).weekday() == chrono::Weekday::Sat
+}
+
+fn special_parsha_haftarah(special_parsha: SpecialParsha, minhag: Minhag) -> Haftarah {
+    match special_parsha {
+        SpecialParsha::Shekalim => by_minhag(
+            minhag,
+            &[s(MelachimBeis, 12, 1, 12, 17)],
+            &[s(MelachimBeis, 11, 17, 12, 17)],
+        ),
+        SpecialParsha::Zachor => by_minhag(
+            minhag,
+            &[s(ShmuelAleph, 15, 2, 15, 34)],
+            &[s(ShmuelAleph, 15, 1, 15, 34)],
+        ),
+        SpecialParsha::Parah => by_minhag(
+            minhag,
+            &[s(Yechezkel, 36, 16, 36, 38)],
+            &[s(Yechezkel, 36, 16, 36, 36)],
+        ),
+        SpecialParsha::HaChodesh => by_minhag(
+            minhag,
+            &[s(Yechezkel, 45, 16, 46, 18)],
+            &[s(Yechezkel, 45, 18, 46, 15)],
+        ),
+    }
+}
+
+/// Shabbos Shuva.
+fn shuva(minhag: Minhag) -> Haftarah {
+    if minhag == Minhag::Chabad {
+        return Haftarah(vec![
+            s(Hoshea, 14, 2, 14, 10),
+            s(Yoel, 2, 15, 2, 27),
+            s(Micha, 7, 18, 7, 20),
+        ]);
+    }
+    by_minhag(
+        minhag,
+        &[s(Hoshea, 14, 2, 14, 10), s(Yoel, 2, 15, 2, 27)],
+        &[s(Hoshea, 14, 2, 14, 10), s(Micha, 7, 18, 7, 20)],
+    )
+}
+
+/// The second of the three Shabbosos of punishment. Chabad ends with both the Ashkenazi and the
+/// Sephardi verse.
+fn shimu(minhag: Minhag) -> Haftarah {
+    if minhag == Minhag::Chabad {
+        return Haftarah(vec![
+            s(Yirmiyahu, 2, 4, 2, 28),
+            s(Yirmiyahu, 3, 4, 3, 4),
+            s(Yirmiyahu, 4, 1, 4, 2),
+        ]);
+    }
+    by_minhag(
+        minhag,
+        &[s(Yirmiyahu, 2, 4, 2, 28), s(Yirmiyahu, 3, 4, 3, 4)],
+        &[s(Yirmiyahu, 2, 4, 2, 28), s(Yirmiyahu, 4, 1, 4, 2)],
+    )
+}
+
+/// When another Haftarah is read on Shabbos Rosh Chodesh or Machar Chodesh, Chabad and the
+/// Yemenites add the first and last verses of the Haftaros that were put off.
+fn with_rosh_chodesh_verses(
+    mut haftarah: Haftarah,
+    minhag: Minhag,
+    rosh_chodesh: bool,
+    machar_chodesh: bool,
+) -> Haftarah {
+    if minhag == Minhag::Chabad || minhag == Minhag::Yemenite {
+        if rosh_chodesh {
+            haftarah.0.push(s(Yeshayahu, 66, 1, 66, 1));
+            haftarah.0.push(s(Yeshayahu, 66, 23, 66, 23));
+        }
+        if machar_chodesh {
+            haftarah.0.push(s(ShmuelAleph, 20, 18, 20, 18));
+            haftarah.0.push(s(ShmuelAleph, 20, 42, 20, 42));
+        }
+    }
+    haftarah
+}
+
+fn special_parsha_on(date: HebrewDate, location: Location) -> Option<SpecialParsha> {
+    let this_day: DateTime<Utc> = date.into();
+    HebrewYear::new(date.year())
+        .unwrap()
+        .get_holidays(location, &[TorahReadingType::SpecialParsha])
+        .into_iter()
+        .find(|x| {
+            let day: DateTime<Utc> = x.day().into();
+            day == this_day
+        })
+        .and_then(|x| match x.name() {
+            TorahReading::SpecialParsha(special_parsha) => Some(special_parsha),
+            _ => None,
+        })
+}
+
+fn shabbos_haftarah(
+    parsha: Parsha,
+    date: HebrewDate,
+    location: Location,
+    minhag: Minhag,
+) -> Haftarah {
+    let year = HebrewYear::new(date.year()).unwrap();
+    let this_day: DateTime<Utc> = date.into();
+    let days_from = |month: HebrewMonth, day: i8| -> i64 {
+        let other: DateTime<Utc> = year
+            .get_hebrew_date(month, NonZeroI8::new(day).unwrap())
+            .unwrap()
+            .into();
+        (this_day - other).num_days()
+    };
+
+    // Shabbos Shuva.
+    if date.month() == HebrewMonth::Tishrei && date.day().get() < 10 {
+        return shuva(minhag);
+    }
+
+    let day = date.day().get();
+    let rosh_chodesh = day == 30 || day == 1;
+    // The day before Rosh Hashana isn't Rosh Chodesh.
+    let machar_chodesh = (day == 29 || day == 30) && date.month() != HebrewMonth::Elul;
+
+    // The three Shabbosos of punishment, between the seventeenth of Tammuz and Tisha B'Av.
+    let after_seventeen_tammuz = days_from(HebrewMonth::Tammuz, 17);
+    if (1..=21).contains(&after_seventeen_tammuz) {
+        let haftarah = match (after_seventeen_tammuz - 1) / 7 {
+            0 => Haftarah(vec![s(Yirmiyahu, 1, 1, 2, 3)]),
+            1 => shimu(minhag),
+            _ => Haftarah(vec![s(Yeshayahu, 1, 1, 1, 27)]),
+        };
+        return with_rosh_chodesh_verses(haftarah, minhag, rosh_chodesh, machar_chodesh);
+    }
+
+    // The seven Shabbosos of consolation, from Tisha B'Av until Rosh Hashana.
+    let after_tisha_bav = days_from(HebrewMonth::Av, 9);
+    if after_tisha_bav > 0 {
+        let haftarah = Haftarah(vec![match (after_tisha_bav - 1) / 7 {
+            0 => s(Yeshayahu, 40, 1, 40, 26),
+            1 => s(Yeshayahu, 49, 14, 51, 3),
+            2 => s(Yeshayahu, 54, 11, 55, 5),
+            3 => s(Yeshayahu, 51, 12, 52, 12),
+            4 => s(Yeshayahu, 54, 1, 54, 10),
+            5 => s(Yeshayahu, 60, 1, 60, 22),
+            _ => s(Yeshayahu, 61, 10, 63, 9),
+        }]);
+        return with_rosh_chodesh_verses(haftarah, minhag, rosh_chodesh, machar_chodesh);
+    }
+
+    if let Some(special_parsha) = special_parsha_on(date, location) {
+        let haftarah = special_parsha_haftarah(special_parsha, minhag);
+        return with_rosh_chodesh_verses(haftarah, minhag, rosh_chodesh, machar_chodesh);
+    }
+
+    // Shabbos Chanukah. If Chanukah has two Shabbosos, the second has its own Haftarah.
+    let after_chanukah = days_from(HebrewMonth::Kislev, 25);
+    if (0..8).contains(&after_chanukah) {
+        let haftarah = if after_chanukah == 7 {
+            Haftarah(vec![s(MelachimAleph, 7, 40, 7, 50)])
+        } else {
+            Haftarah(vec![s(Zecharia, 2, 14, 4, 7)])
+        };
+        return with_rosh_chodesh_verses(haftarah, minhag, rosh_chodesh, machar_chodesh);
+    }
+
+    // The Yemenites read the parsha's Haftarah on Shabbos Rosh Chodesh and Machar Chodesh too.
+    if minhag == Minhag::Yemenite && (rosh_chodesh || machar_chodesh) {
+        let haftarah = parsha_haftarah(parsha, minhag);
+        return with_rosh_chodesh_verses(haftarah, minhag, rosh_chodesh, machar_chodesh);
+    }
+    if rosh_chodesh {
+        let haftarah = Haftarah(vec![s(Yeshayahu, 66, 1, 66, 24)]);
+        return with_rosh_chodesh_verses(haftarah, minhag, false, machar_chodesh);
+    }
+    if machar_chodesh {
+        return Haftarah(vec![s(ShmuelAleph, 20, 18, 20, 42)]);
+    }
+
+    // Shabbos HaGadol.
+    if date.month() == HebrewMonth::Nissan && (8..15).contains(&day) {
+        return Haftarah(vec![s(Malachi, 3, 4, 3, 24)]);
+    }
+
+    parsha_haftarah(parsha, minhag)
+}
+
+fn parsha_haftarah(parsha: Parsha, minhag: Minhag) -> Haftarah {
+    // Chabad follows the Sephardi custom for these.
+    if minhag == Minhag::Chabad {
+        match parsha {
+            Parsha::Vayetzei => return Haftarah(vec![s(Hoshea, 11, 7, 12, 12)]),
+            Parsha::Vayakhel => return Haftarah(vec![s(MelachimAleph, 7, 13, 7, 26)]),
+            Parsha::Pikudei | Parsha::VayakhelPikudei => {
+                return Haftarah(vec![s(MelachimAleph, 7, 40, 7, 50)])
+            }
+            _ => {}
+        }
+    }
+    if minhag == Minhag::Yemenite {
+        match parsha {
+            Parsha::Bereishis => return Haftarah(vec![s(Yeshayahu, 42, 1, 42, 16)]),
+            Parsha::Shemos => return Haftarah(vec![s(Yechezkel, 16, 1, 16, 14)]),
+            _ => {}
+        }
+    }
+    match parsha {
+        Parsha::Bereishis => by_minhag(
+            minhag,
+            &[s(Yeshayahu, 42, 5, 43, 10)],
+            &[s(Yeshayahu, 42, 5, 42, 21)],
+        ),
+        Parsha::Noach => by_minhag(
+            minhag,
+            &[s(Yeshayahu, 54, 1, 55, 5)],
+            &[s(Yeshayahu, 54, 1, 54, 10)],
+        ),
+        Parsha::LechLecha => Haftarah(vec![s(Yeshayahu, 40, 27, 41, 16)]),
+        Parsha::Vayeira => by_minhag(
+            minhag,
+            &[s(MelachimBeis, 4, 1, 4, 37)],
+            &[s(MelachimBeis, 4, 1, 4, 23)],
+        ),
+        Parsha::ChayeiSara => Haftarah(vec![s(MelachimAleph, 1, 1, 1, 31)]),
+        Parsha::Toldos => Haftarah(vec![s(Malachi, 1, 1, 2, 7)]),
+        Parsha::Vayetzei => by_minhag(
+            minhag,
+            &[s(Hoshea, 12, 13, 14, 10)],
+            &[s(Hoshea, 11, 7, 12, 12)],
+        ),
+        Parsha::Vayishlach => Haftarah(vec![s(Ovadia, 1, 1, 1, 21)]),
+        Parsha::Vayeshev => Haftarah(vec![s(Amos, 2, 6, 3, 8)]),
+        Parsha::Miketz => Haftarah(vec![s(MelachimAleph, 3, 15, 4, 1)]),
+        Parsha::Vayigash => Haftarah(vec![s(Yechezkel, 37, 15, 37, 28)]),
+        Parsha::Vayechi => Haftarah(vec![s(MelachimAleph, 2, 1, 2, 12)]),
+        Parsha::Shemos => by_minhag(
+            minhag,
+            &[s(Yeshayahu, 27, 6, 28, 13), s(Yeshayahu, 29, 22, 29, 23)],
+            &[s(Yirmiyahu, 1, 1, 2, 3)],
+        ),
+        Parsha::Vaeira => Haftarah(vec![s(Yechezkel, 28, 25, 29, 21)]),
+        Parsha::Bo => Haftarah(vec![s(Yirmiyahu, 46, 13, 46, 28)]),
+        Parsha::Beshalach => by_minhag(
+            minhag,
+            &[s(Shoftim, 4, 4, 5, 31)],
+            &[s(Shoftim, 5, 1, 5, 31)],
+        ),
+        Parsha::Yisro => by_minhag(
+            minhag,
+            &[s(Yeshayahu, 6, 1, 7, 6), s(Yeshayahu, 9, 5, 9, 6)],
+            &[s(Yeshayahu, 6, 1, 6, 13)],
+        ),
+        Parsha::Mishpatim => Haftarah(vec![
+            s(Yirmiyahu, 34, 8, 34, 22),
+            s(Yirmiyahu, 33, 25, 33, 26),
+        ]),
+        Parsha::Terumah => Haftarah(vec![s(MelachimAleph, 5, 26, 6, 13)]),
+        Parsha::Tetzaveh => Haftarah(vec![s(Yechezkel, 43, 10, 43, 27)]),
+        Parsha::KiSisa => by_minhag(
+            minhag,
+            &[s(MelachimAleph, 18, 1, 18, 39)],
+            &[s(MelachimAleph, 18, 20, 18, 39)],
+        ),
+        Parsha::Vayakhel => by_minhag(
+            minhag,
+            &[s(MelachimAleph, 7, 40, 7, 50)],
+            &[s(MelachimAleph, 7, 13, 7, 26)],
+        ),
+        Parsha::Pikudei | Parsha::VayakhelPikudei => by_minhag(
+            minhag,
+            &[s(MelachimAleph, 7, 51, 8, 21)],
+            &[s(MelachimAleph, 7, 40, 7, 50)],
+        ),
+        Parsha::Vayikra => Haftarah(vec![s(Yeshayahu, 43, 21, 44, 23)]),
+        Parsha::Tzav => Haftarah(vec![s(Yirmiyahu, 7, 21, 8, 3), s(Yirmiyahu, 9, 22, 9, 23)]),
+        Parsha::Shemini => by_minhag(
+            minhag,
+            &[s(ShmuelBeis, 6, 1, 7, 17)],
+            &[s(ShmuelBeis, 6, 1, 6, 19)],
+        ),
+        Parsha::Tazriya => Haftarah(vec![s(MelachimBeis, 4, 42, 5, 19)]),
+        Parsha::Metzorah | Parsha::TazriyaMetzorah => Haftarah(vec![s(MelachimBeis, 7, 3, 7, 20)]),
+        Parsha::AchareiMos => by_minhag(
+            minhag,
+            &[s(Yechezkel, 22, 1, 22, 19)],
+            &[s(Yechezkel, 22, 1, 22, 16)],
+        ),
+        Parsha::Kedoshim | Parsha::AchareiMosKedoshim => by_minhag(
+            minhag,
+            &[s(Amos, 9, 7, 9, 15)],
+            &[s(Yechezkel, 20, 2, 20, 20)],
+        ),
+        Parsha::Emor => Haftarah(vec![s(Yechezkel, 44, 15, 44, 31)]),
+        Parsha::Behar => Haftarah(vec![s(Yirmiyahu, 32, 6, 32, 27)]),
+        Parsha::Bechukosai | Parsha::BeharBechukosai => {
+            Haftarah(vec![s(Yirmiyahu, 16, 19, 17, 14)])
+        }
+        Parsha::Bamidbar => Haftarah(vec![s(Hoshea, 2, 1, 2, 22)]),
+        Parsha::Naso => Haftarah(vec![s(Shoftim, 13, 2, 13, 25)]),
+        Parsha::Behaaloscha => Haftarah(vec![s(Zecharia, 2, 14, 4, 7)]),
+        Parsha::Shlach => Haftarah(vec![s(Yehoshua, 2, 1, 2, 24)]),
+        Parsha::Korach => Haftarah(vec![s(ShmuelAleph, 11, 14, 12, 22)]),
+        Parsha::Chukas => Haftarah(vec![s(Shoftim, 11, 1, 11, 33)]),
+        Parsha::Balak | Parsha::ChukasBalak => Haftarah(vec![s(Micha, 5, 6, 6, 8)]),
+        Parsha::Pinchas => Haftarah(vec![s(MelachimAleph, 18, 46, 19, 21)]),
+        Parsha::Matos => Haftarah(vec![s(Yirmiyahu, 1, 1, 2, 3)]),
+        Parsha::Maasei | Parsha::MatosMaasei => shimu(minhag),
+        Parsha::Devarim => Haftarah(vec![s(Yeshayahu, 1, 1, 1, 27)]),
+        Parsha::Vaeschanan => Haftarah(vec![s(Yeshayahu, 40, 1, 40, 26)]),
+        Parsha::Eikev => Haftarah(vec![s(Yeshayahu, 49, 14, 51, 3)]),
+        Parsha::Reeh => Haftarah(vec![s(Yeshayahu, 54, 11, 55, 5)]),
+        Parsha::Shoftim => Haftarah(vec![s(Yeshayahu, 51, 12, 52, 12)]),
+        Parsha::KiSeitzei => Haftarah(vec![s(Yeshayahu, 54, 1, 54, 10)]),
+        Parsha::KiSavoh => Haftarah(vec![s(Yeshayahu, 60, 1, 60, 22)]),
+        Parsha::Nitzavim | Parsha::NitzavimVayelech => Haftarah(vec![s(Yeshayahu, 61, 10, 63, 9)]),
+        Parsha::Vayelech => shuva(minhag),
+        Parsha::Haazinu => Haftarah(vec![s(ShmuelBeis, 22, 1, 22, 51)]),
+    }
+}
+
+fn yom_tov_haftarah(
+    yom_tov: YomTov,
+    shabbos: bool,
+    location: Location,
+    minhag: Minhag,
+) -> Option<Haftarah> {
+    let chul = location == Location::Chul;
+    Some(match yom_tov {
+        YomTov::RoshHashanah1 => Haftarah(vec![s(ShmuelAleph, 1, 1, 2, 10)]),
+        YomTov::RoshHashanah2 => by_minhag(
+            minhag,
+            &[s(Yirmiyahu, 31, 1, 31, 19)],
+            &[s(Yirmiyahu, 31, 1, 31, 20)],
+        ),
+        YomTov::YomKippur => Haftarah(vec![s(Yeshayahu, 57, 14, 58, 14)]),
+        YomTov::Sukkos1 => Haftarah(vec![s(Zecharia, 14, 1, 14, 21)]),
+        YomTov::Sukkos2 if chul => Haftarah(vec![s(MelachimAleph, 8, 2, 8, 21)]),
+        YomTov::Sukkos2
+        | YomTov::Sukkos3
+        | YomTov::Sukkos4
+        | YomTov::Sukkos5
+        | YomTov::Sukkos6
+        | YomTov::Sukkos7 => {
+            if shabbos {
+                Haftarah(vec![s(Yechezkel, 38, 18, 39, 16)])
+            } else {
+                return None;
+            }
+        }
+        YomTov::ShminiAtzeres if chul => by_minhag(
+            minhag,
+            &[s(MelachimAleph, 8, 54, 8, 66)],
+            &[s(MelachimAleph, 8, 54, 9, 1)],
+        ),
+        YomTov::ShminiAtzeres | YomTov::SimchasTorah => by_minhag(
+            minhag,
+            &[s(Yehoshua, 1, 1, 1, 18)],
+            &[s(Yehoshua, 1, 1, 1, 9)],
+        ),
+        YomTov::Pesach1 => by_minhag(
+            minhag,
+            &[
+                s(Yehoshua, 3, 5, 3, 7),
+                s(Yehoshua, 5, 2, 6, 1),
+                s(Yehoshua, 6, 27, 6, 27),
+            ],
+            &[s(Yehoshua, 5, 2, 6, 1), s(Yehoshua, 6, 27, 6, 27)],
+        ),
+        YomTov::Pesach2 if chul => Haftarah(vec![
+            s(MelachimBeis, 23, 1, 23, 9),
+            s(MelachimBeis, 23, 21, 23, 25),
+        ]),
+        YomTov::Pesach2 | YomTov::Pesach3 | YomTov::Pesach4 | YomTov::Pesach5 | YomTov::Pesach6 => {
+            if shabbos {
+                Haftarah(vec![s(Yechezkel, 37, 1, 37, 14)])
+            } else {
+                return None;
+            }
+        }
+        YomTov::Pesach7 => Haftarah(vec![s(ShmuelBeis, 22, 1, 22, 51)]),
+        YomTov::Pesach8 => Haftarah(vec![s(Yeshayahu, 10, 32, 12, 6)]),
+        YomTov::Shavuos1 => Haftarah(vec![s(Yechezkel, 1, 1, 1, 28), s(Yechezkel, 3, 12, 3, 12)]),
+        YomTov::Shavuos2 => by_minhag(
+            minhag,
+            &[s(Chavakuk, 2, 20, 3, 19)],
+            &[s(Chavakuk, 3, 1, 3, 19)],
+        ),
+    })
+}
@@ -0,0 +1,39 @@
+use crate::algorithms::tachanun::{is_chanukah, is_yom_haatzmaut_or_yom_yerushalayim};
+use crate::args::types::Minhag;
+use heca_lib::prelude::{HebrewMonth, Location};
+use heca_lib::HebrewDate;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Hallel {
+    Full,
+    Half,
+    None,
+}
+
+pub fn get(date: HebrewDate, minhag: Minhag, location: Location) -> Hallel {
+    let day = date.day().get();
+    let chul = location == Location::Chul;
+    match date.month() {
+        // Sukkos, Shmini Atzeres and (in Chutz La'aretz) Simchas Torah.
+        HebrewMonth::Tishrei if day >= 15 && (day <= 22 || (chul && day == 23)) => {
+            return Hallel::Full
+        }
+        HebrewMonth::Nissan if day == 15 || (chul && day == 16) => return Hallel::Full,
+        HebrewMonth::Nissan if day >= 16 && (day <= 21 || (chul && day == 22)) => {
+            return Hallel::Half
+        }
+        HebrewMonth::Sivan if day == 6 || (chul && day == 7) => return Hallel::Full,
+        _ => {}
+    };
+    if is_chanukah(date) {
+        return Hallel::Full;
+    }
+    if minhag == Minhag::EdotHaMizrach && is_yom_haatzmaut_or_yom_yerushalayim(date) {
+        return Hallel::Full;
+    }
+    if (day == 1 && date.month() != HebrewMonth::Tishrei) || day == 30 {
+        return Hallel::Half;
+    }
+    Hallel::None
+}
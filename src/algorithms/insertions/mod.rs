@@ -0,0 +1,115 @@
+use crate::algorithms::tachanun::{is_chanukah, weekday};
+use crate::args::types::Minhag;
+use chrono::{DateTime, Duration, TimeZone, Utc, Weekday};
+use heca_lib::prelude::{HebrewMonth, Location};
+use heca_lib::HebrewDate;
+use serde::Serialize;
+
+/// The additions to the Amidah (and Birkas HaMazon) that are said on a given day.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Insertions {
+    pub yaaleh_veyavo: bool,
+    pub al_hanissim: bool,
+    pub mashiv_haruach: Rain,
+    pub tal_umatar: bool,
+}
+
+/// What is said in the second blessing of the Amidah. On Shmini Atzeres and on the first day of
+/// Pesach, this is what's said from Mussaf onwards.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Rain {
+    MashivHaRuach,
+    MoridHaTal,
+    None,
+}
+
+/// `walled_city` is whether Purim is kept on the 15th of Adar, like in Jerusalem.
+pub fn get(date: HebrewDate, minhag: Minhag, location: Location, walled_city: bool) -> Insertions {
+    Insertions {
+        yaaleh_veyavo: yaaleh_veyavo(date, location),
+        al_hanissim: al_hanissim(date, walled_city),
+        mashiv_haruach: mashiv_haruach(date, minhag, location),
+        tal_umatar: tal_umatar(date, location),
+    }
+}
+
+fn yaaleh_veyavo(date: HebrewDate, location: Location) -> bool {
+    let day = date.day().get();
+    let chul = location == Location::Chul;
+    if day == 1 || day == 30 {
+        return true;
+    }
+    match date.month() {
+        HebrewMonth::Tishrei => {
+            day <= 2 || day == 10 || (day >= 15 && (day <= 22 || (chul && day == 23)))
+        }
+        HebrewMonth::Nissan => day >= 15 && (day <= 21 || (chul && day == 22)),
+        HebrewMonth::Sivan => day == 6 || (chul && day == 7),
+        _ => false,
+    }
+}
+
+fn al_hanissim(date: HebrewDate, walled_city: bool) -> bool {
+    let purim = if walled_city { 15 } else { 14 };
+    is_chanukah(date)
+        || match date.month() {
+            HebrewMonth::Adar | HebrewMonth::Adar2 => date.day().get() == purim,
+            _ => false,
+        }
+}
+
+fn mashiv_haruach(date: HebrewDate, minhag: Minhag, location: Location) -> Rain {
+    let day = date.day().get();
+    let winter = match date.month() {
+        HebrewMonth::Tishrei => day >= 22,
+        HebrewMonth::Nissan => day < 15,
+        HebrewMonth::Iyar
+        | HebrewMonth::Sivan
+        | HebrewMonth::Tammuz
+        | HebrewMonth::Av
+        | HebrewMonth::Elul => false,
+        _ => true,
+    };
+    if winter {
+        Rain::MashivHaRuach
+    } else if minhag == Minhag::Ashkenaz && location == Location::Chul {
+        Rain::None
+    } else {
+        Rain::MoridHaTal
+    }
+}
+
+fn tal_umatar(date: HebrewDate, location: Location) -> bool {
+    if weekday(date) == Weekday::Sat {
+        return false;
+    }
+    let day = date.day().get();
+    match date.month() {
+        HebrewMonth::Tishrei => false,
+        HebrewMonth::Nissan => day < 15,
+        HebrewMonth::Iyar
+        | HebrewMonth::Sivan
+        | HebrewMonth::Tammuz
+        | HebrewMonth::Av
+        | HebrewMonth::Elul => false,
+        HebrewMonth::Cheshvan | HebrewMonth::Kislev if location == Location::Israel => {
+            date.month() == HebrewMonth::Kislev || day >= 7
+        }
+        HebrewMonth::Cheshvan | HebrewMonth::Kislev | HebrewMonth::Teves => {
+            let evening: DateTime<Utc> = date.into();
+            evening >= chul_tal_umatar_start(date.year())
+        }
+        _ => true,
+    }
+}
+
+/// In Chutz La'aretz, we start asking for rain at Maariv on the sixtieth day after the Tekufah of
+/// Tishrei. That's the evening of the 21st of November in the Julian calendar (or the 22nd, if the
+/// next year is a leap year).
+fn chul_tal_umatar_start(hebrew_year: u64) -> DateTime<Utc> {
+    let year = hebrew_year as i32 - 3761;
+    let julian_day = if (year + 1) % 4 == 0 { 22 } else { 21 };
+    let offset = year / 100 - year / 400 - 2;
+    Utc.ymd(year, 11, 1).and_hms(18, 0, 0) + Duration::days(i64::from(julian_day + offset - 1))
+}
@@ -1,7 +1,9 @@
+use crate::algorithms::tachanun::weekday;
 use crate::args::types::{DayVal, Language, Name};
 
+use chrono::{DateTime, Duration, Utc, Weekday};
 use heca_lib::prelude::{HebrewMonth, MonthSchedule};
-use heca_lib::HebrewYear;
+use heca_lib::{HebrewDate, HebrewYear};
 use std::convert::TryInto;
 use std::io::{BufWriter, StdoutLock, Write};
 use std::num::NonZeroI8;
@@ -13,22 +15,111 @@ pub fn get(year: &HebrewYear, exact_days: bool) -> Vec<DayVal> {
     get_yom_hashoah(year, exact_days).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
     get_yom_haaliyah(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
     get_sigd(year).and_then(|x| Some(return_vec.extend(std::iter::once(x))));
+    return_vec.extend(get_memorial_days(year, exact_days));
     return_vec
 }
 
+/// How a day set by the Knesset is moved so that it won't lead to breaking Shabbos.
+#[derive(Clone, Copy)]
+enum Postpone {
+    Never,
+    FridayToThursday,
+    ShabbosToSunday,
+    FridayAndShabbosToSunday,
+}
+
+/// The other days set by the Knesset, with the first year each was kept.
+const MEMORIAL_DAYS: [(IsraeliHoliday, u64, HebrewMonth, i8, Postpone); 7] = [
+    (
+        IsraeliHoliday::YomRabin,
+        5758,
+        HebrewMonth::Cheshvan,
+        12,
+        Postpone::FridayToThursday,
+    ),
+    (
+        IsraeliHoliday::BenGurionDay,
+        5737,
+        HebrewMonth::Kislev,
+        6,
+        Postpone::FridayAndShabbosToSunday,
+    ),
+    (
+        IsraeliHoliday::HebrewLanguageDay,
+        5772,
+        HebrewMonth::Teves,
+        21,
+        Postpone::ShabbosToSunday,
+    ),
+    (
+        IsraeliHoliday::FamilyDay,
+        5734,
+        HebrewMonth::Shvat,
+        30,
+        Postpone::Never,
+    ),
+    (
+        IsraeliHoliday::YomHaAliyahNissan,
+        5777,
+        HebrewMonth::Nissan,
+        10,
+        Postpone::Never,
+    ),
+    (
+        IsraeliHoliday::HerzlDay,
+        5765,
+        HebrewMonth::Iyar,
+        10,
+        Postpone::ShabbosToSunday,
+    ),
+    (
+        IsraeliHoliday::JabotinskyDay,
+        5765,
+        HebrewMonth::Tammuz,
+        29,
+        Postpone::ShabbosToSunday,
+    ),
+];
+
+fn get_memorial_days(year: &HebrewYear, exact_days: bool) -> Vec<DayVal> {
+    MEMORIAL_DAYS
+        .iter()
+        .filter(|(_, first_year, _, _, _)| year.year() >= *first_year)
+        .map(|(holiday, _, month, day, postpone)| {
+            let date: HebrewDate = year
+                .get_hebrew_date(*month, NonZeroI8::new(*day).unwrap())
+                .unwrap();
+            let offset = if exact_days {
+                0
+            } else {
+                match (postpone, weekday(date)) {
+                    (Postpone::FridayToThursday, Weekday::Fri) => -1,
+                    (Postpone::ShabbosToSunday, Weekday::Sat)
+                    | (Postpone::FridayAndShabbosToSunday, Weekday::Sat) => 1,
+                    (Postpone::FridayAndShabbosToSunday, Weekday::Fri) => 2,
+                    _ => 0,
+                }
+            };
+            let day: DateTime<Utc> = date.into();
+            DayVal::new(
+                day + Duration::days(offset),
+                Name::IsraeliHoliday(holiday.clone()),
+            )
+        })
+        .collect()
+}
+
 fn get_yom_haaliyah(year: &HebrewYear) -> Option<DayVal> {
     if year.year() < 5777 {
         None
     } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Cheshvan, NonZeroI8::new(7).unwrap())
+        Some(DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Cheshvan, NonZeroI8::new(7).unwrap())
                 .unwrap()
                 .try_into()
                 .unwrap(),
-            name: Name::IsraeliHoliday(IsraeliHoliday::YomHaAliyah),
-            candle_lighting: None,
-        })
+            Name::IsraeliHoliday(IsraeliHoliday::YomHaAliyah),
+        ))
     }
 }
 
@@ -36,15 +127,13 @@ fn get_yom_yerushalayim(year: &HebrewYear) -> Option<DayVal> {
     if year.year() < 5727 {
         None
     } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(28).unwrap())
+        Some(DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(28).unwrap())
                 .unwrap()
                 .try_into()
                 .unwrap(),
-            name: Name::IsraeliHoliday(IsraeliHoliday::YomYerushalayim),
-            candle_lighting: None,
-        })
+            Name::IsraeliHoliday(IsraeliHoliday::YomYerushalayim),
+        ))
     }
 }
 
@@ -52,15 +141,13 @@ fn get_sigd(year: &HebrewYear) -> Option<DayVal> {
     if year.year() < 5769 {
         None
     } else {
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Cheshvan, NonZeroI8::new(29).unwrap())
+        Some(DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Cheshvan, NonZeroI8::new(29).unwrap())
                 .unwrap()
                 .try_into()
                 .unwrap(),
-            name: Name::IsraeliHoliday(IsraeliHoliday::Sigd),
-            candle_lighting: None,
-        })
+            Name::IsraeliHoliday(IsraeliHoliday::Sigd),
+        ))
     }
 }
 
@@ -85,15 +172,13 @@ fn get_yom_hashoah(year: &HebrewYear, exact_days: bool) -> Option<DayVal> {
             }
         };
 
-        Some(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(27 + offset).unwrap())
+        Some(DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(27 + offset).unwrap())
                 .unwrap()
                 .try_into()
                 .unwrap(),
-            name: Name::IsraeliHoliday(IsraeliHoliday::YomHaShoah),
-            candle_lighting: None,
-        })
+            Name::IsraeliHoliday(IsraeliHoliday::YomHaShoah),
+        ))
     }
 }
 
@@ -124,24 +209,20 @@ fn get_yom_haatzmaut_and_yom_hazikaron(year: &HebrewYear, exact_days: bool) -> V
         }
     };
 
-    let yom_hazikaron = DayVal {
-        day: year
-            .get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(4 + offset).unwrap())
+    let yom_hazikaron = DayVal::new(
+        year.get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(4 + offset).unwrap())
             .unwrap()
             .try_into()
             .unwrap(),
-        name: Name::IsraeliHoliday(IsraeliHoliday::YomHaZikaron),
-        candle_lighting: None,
-    };
-    let yom_haatzmaut = DayVal {
-        day: year
-            .get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(5 + offset).unwrap())
+        Name::IsraeliHoliday(IsraeliHoliday::YomHaZikaron),
+    );
+    let yom_haatzmaut = DayVal::new(
+        year.get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(5 + offset).unwrap())
             .unwrap()
             .try_into()
             .unwrap(),
-        name: Name::IsraeliHoliday(IsraeliHoliday::YomHaAtzmaut),
-        candle_lighting: None,
-    };
+        Name::IsraeliHoliday(IsraeliHoliday::YomHaAtzmaut),
+    );
     vec![yom_hazikaron, yom_haatzmaut]
 }
 
@@ -151,8 +232,17 @@ pub enum IsraeliHoliday {
     YomHaAtzmaut,
     YomYerushalayim,
     YomHaShoah,
+    /// Kept in schools on 7 Cheshvan.
     YomHaAliyah,
+    /// Yom HaAliyah as set in law, on 10 Nissan.
+    YomHaAliyahNissan,
     Sigd,
+    YomRabin,
+    BenGurionDay,
+    HebrewLanguageDay,
+    FamilyDay,
+    HerzlDay,
+    JabotinskyDay,
 }
 
 impl IsraeliHoliday {
@@ -161,24 +251,21 @@ impl IsraeliHoliday {
         lock: &mut BufWriter<StdoutLock<'_>>,
         language: Language,
     ) -> Option<usize> {
-        let p = match language {
-            Language::English => match self {
-                Self::YomHaAtzmaut => lock.write(b"Yom HaAtzmaut").ok()?,
-                Self::YomHaZikaron => lock.write(b"Yom HaZikaron").ok()?,
-                Self::YomYerushalayim => lock.write(b"Yom Yerushalayim").ok()?,
-                Self::YomHaShoah => lock.write(b"Yom HaShoah").ok()?,
-                Self::YomHaAliyah => lock.write(b"Yom HaAliyah").ok()?,
-                Self::Sigd => lock.write(b"Sigd").ok()?,
-            },
-            Language::Hebrew => match self {
-                Self::YomHaAtzmaut => lock.write("יום העצמאות".as_bytes()).ok()?,
-                Self::YomHaZikaron => lock.write("יום הזיכרון".as_bytes()).ok()?,
-                Self::YomYerushalayim => lock.write("יום ירושלים".as_bytes()).ok()?,
-                Self::YomHaShoah => lock.write("יום השואה".as_bytes()).ok()?,
-                Self::YomHaAliyah => lock.write("יום העלייה".as_bytes()).ok()?,
-                Self::Sigd => lock.write("סיגד".as_bytes()).ok()?,
-            },
+        let name = match self {
+            Self::YomHaAtzmaut => "Yom HaAtzmaut",
+            Self::YomHaZikaron => "Yom HaZikaron",
+            Self::YomYerushalayim => "Yom Yerushalayim",
+            Self::YomHaShoah => "Yom HaShoah",
+            Self::YomHaAliyah => "Yom HaAliyah",
+            Self::YomHaAliyahNissan => "Yom HaAliyah (10 Nissan)",
+            Self::Sigd => "Sigd",
+            Self::YomRabin => "Yitzhak Rabin Memorial Day",
+            Self::BenGurionDay => "Ben-Gurion Day",
+            Self::HebrewLanguageDay => "Hebrew Language Day",
+            Self::FamilyDay => "Family Day",
+            Self::HerzlDay => "Herzl Day",
+            Self::JabotinskyDay => "Jabotinsky Day",
         };
-        Some(p)
+        lock.write(language.tr(name).as_bytes()).ok()
     }
 }
@@ -0,0 +1,134 @@
+use crate::args::types::{DayVal, Language, Name};
+use crate::prelude::{gregorian_date, hebrew_month};
+use chrono::{DateTime, Duration, Utc};
+use heca_lib::prelude::HebrewMonth;
+use heca_lib::{HebrewDate, HebrewYear};
+use serde::ser::*;
+use serde::{Serialize, Serializer};
+use std::convert::TryInto;
+use std::io::{BufWriter, StdoutLock, Write};
+
+// A month is 29 days, 12 hours and 793 chalakim. A chelek is 10/3 of a second.
+const CHALAKIM_BETWEEN_MOLAD: i64 = 29 * 24 * 1080 + 12 * 1080 + 793;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum KiddushLevanaStart {
+    ThreeDays,
+    SevenDays,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum KiddushLevanaEnd {
+    HalfMonth,
+    FifteenDays,
+}
+
+pub fn get(year: &HebrewYear, start: KiddushLevanaStart, end: KiddushLevanaEnd) -> Vec<DayVal> {
+    let months: &[HebrewMonth] = if year.is_leap_year() {
+        &[
+            HebrewMonth::Tishrei,
+            HebrewMonth::Cheshvan,
+            HebrewMonth::Kislev,
+            HebrewMonth::Teves,
+            HebrewMonth::Shvat,
+            HebrewMonth::Adar1,
+            HebrewMonth::Adar2,
+            HebrewMonth::Nissan,
+            HebrewMonth::Iyar,
+            HebrewMonth::Sivan,
+            HebrewMonth::Tammuz,
+            HebrewMonth::Av,
+            HebrewMonth::Elul,
+        ]
+    } else {
+        &[
+            HebrewMonth::Tishrei,
+            HebrewMonth::Cheshvan,
+            HebrewMonth::Kislev,
+            HebrewMonth::Teves,
+            HebrewMonth::Shvat,
+            HebrewMonth::Adar,
+            HebrewMonth::Nissan,
+            HebrewMonth::Iyar,
+            HebrewMonth::Sivan,
+            HebrewMonth::Tammuz,
+            HebrewMonth::Av,
+            HebrewMonth::Elul,
+        ]
+    };
+
+    months
+        .iter()
+        .map(|month| {
+            let molad = year.get_molad(*month).unwrap();
+            let molad_time =
+                molad.get_day_utc() + Duration::seconds(i64::from(molad.get_chalakim()) * 10 / 3);
+            let earliest = molad_time
+                + match start {
+                    KiddushLevanaStart::ThreeDays => Duration::days(3),
+                    KiddushLevanaStart::SevenDays => Duration::days(7),
+                };
+            let latest = molad_time
+                + match end {
+                    KiddushLevanaEnd::HalfMonth => {
+                        Duration::seconds(CHALAKIM_BETWEEN_MOLAD * 10 / 3 / 2)
+                    }
+                    KiddushLevanaEnd::FifteenDays => Duration::days(15),
+                };
+            let first_night: HebrewDate = earliest.try_into().unwrap();
+            DayVal::new(
+                first_night.into(),
+                Name::KiddushLevana(KiddushLevana {
+                    hebrew_month: *month,
+                    earliest,
+                    latest,
+                }),
+            )
+        })
+        .collect()
+}
+
+/// The window in which Kiddush Levana may be said. Like the molad, the times are in traditional
+/// Jerusalem time.
+#[derive(Debug, Clone)]
+pub struct KiddushLevana {
+    pub hebrew_month: HebrewMonth,
+    pub earliest: DateTime<Utc>,
+    pub latest: DateTime<Utc>,
+}
+
+impl Serialize for KiddushLevana {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("KiddushLevana", 2)?;
+        state.serialize_field("earliest", &self.earliest.naive_utc())?;
+        state.serialize_field("latest", &self.latest.naive_utc())?;
+        state.end()
+    }
+}
+
+impl KiddushLevana {
+    pub fn pretty_print(
+        &self,
+        lock: &mut BufWriter<StdoutLock<'_>>,
+        language: Language,
+    ) -> Option<usize> {
+        let p = lock
+            .write(
+                language
+                    .tr_format(
+                        "Kiddush Levana {}: From {} until {}",
+                        &[
+                            &hebrew_month(self.hebrew_month, language),
+                            &gregorian_date(&self.earliest, "%A %B %-d %H:%M", language),
+                            &gregorian_date(&self.latest, "%A %B %-d %H:%M", language),
+                        ],
+                    )
+                    .as_bytes(),
+            )
+            .ok()?;
+        Some(p)
+    }
+}
@@ -1,4 +1,16 @@
+pub mod aliyot;
 pub mod candle_lighting;
 pub mod chabad_holidays;
+pub mod divergence;
+pub mod haftarah;
+pub mod hallel;
+pub mod insertions;
 pub mod israeli_holidays;
+pub mod kiddush_levana;
+pub mod mourning_periods;
+pub mod purim;
+pub mod rare_events;
 pub mod shabbos_mevarchim;
+pub mod special_shabbosos;
+pub mod tachanun;
+pub mod wedding;
@@ -0,0 +1,101 @@
+use crate::args::types::{DayVal, Language, Minhag, Name};
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc, Weekday};
+use heca_lib::prelude::HebrewMonth;
+use heca_lib::HebrewYear;
+use std::io::{BufWriter, StdoutLock, Write};
+use std::num::NonZeroI8;
+
+/// The periods in which weddings and other celebrations aren't held.
+pub fn get(year: &HebrewYear, minhag: Minhag) -> Vec<DayVal> {
+    let evening = |month, day| -> DateTime<Utc> {
+        year.get_hebrew_date(month, NonZeroI8::new(day).unwrap())
+            .unwrap()
+            .into()
+    };
+    let sefirah_end = match minhag {
+        Minhag::Ashkenaz => evening(HebrewMonth::Iyar, 18),
+        // Until the morning of the thirty-fourth day of the Omer, so its night is still included.
+        Minhag::EdotHaMizrach | Minhag::Yemenite => {
+            evening(HebrewMonth::Iyar, 19) + Duration::hours(12)
+        }
+        Minhag::Sefard | Minhag::Chabad => evening(HebrewMonth::Sivan, 3),
+    };
+    // When Tisha B'Av is on Shabbos, the fast is pushed off to Sunday.
+    let nine_av = evening(HebrewMonth::Av, 9);
+    let three_weeks_end = if (nine_av + Duration::days(1)).weekday() == Weekday::Sat {
+        evening(HebrewMonth::Av, 11)
+    } else {
+        evening(HebrewMonth::Av, 10)
+    };
+
+    vec![
+        (
+            evening(HebrewMonth::Nissan, 16),
+            Period::Sefirah,
+            sefirah_end,
+        ),
+        (
+            evening(HebrewMonth::Tammuz, 17),
+            Period::ThreeWeeks,
+            three_weeks_end,
+        ),
+        (
+            evening(HebrewMonth::Av, 1),
+            Period::NineDays,
+            three_weeks_end,
+        ),
+    ]
+    .into_iter()
+    .map(|(day, period, end)| {
+        DayVal::new(day, Name::MourningPeriod(MourningPeriod { period, end }))
+    })
+    .collect()
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Period {
+    Sefirah,
+    ThreeWeeks,
+    NineDays,
+}
+
+/// A period that starts on the evening of `DayVal::day` and is over at `end`, which is on an
+/// evening (18:00 UTC, like every other day) or, for the Sephardi Sefirah, a morning (06:00 UTC).
+#[derive(Debug, Clone, Copy)]
+pub struct MourningPeriod {
+    pub period: Period,
+    pub end: DateTime<Utc>,
+}
+
+impl MourningPeriod {
+    pub fn pretty_print(
+        &self,
+        lock: &mut BufWriter<StdoutLock<'_>>,
+        language: Language,
+    ) -> Option<usize> {
+        let last_day = format!(
+            "{}/{}/{}",
+            self.end.year(),
+            self.end.month(),
+            self.end.day()
+        );
+        let period = match self.period {
+            Period::Sefirah => "Sefirah",
+            Period::ThreeWeeks => "The Three Weeks",
+            Period::NineDays => "The Nine Days",
+        };
+        let format = if self.end.hour() < 12 {
+            "{}, until the morning of {}"
+        } else {
+            "{}, through {}"
+        };
+        let p = lock
+            .write(
+                language
+                    .tr_format(format, &[&language.tr(period), &last_day])
+                    .as_bytes(),
+            )
+            .ok()?;
+        Some(p)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::args::types::{DayVal, MinorDays, Name};
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use heca_lib::prelude::{Chol, HebrewMonth, TorahReading};
+use heca_lib::HebrewYear;
+use std::num::NonZeroI8;
+
+/// The evening on which Shushan Purim starts.
+fn shushan_purim(year: &HebrewYear) -> DateTime<Utc> {
+    let month = if year.is_leap_year() {
+        HebrewMonth::Adar2
+    } else {
+        HebrewMonth::Adar
+    };
+    year.get_hebrew_date(month, NonZeroI8::new(15).unwrap())
+        .unwrap()
+        .into()
+}
+
+/// When Shushan Purim is on Shabbos, walled cities split Purim over three days.
+pub fn is_purim_meshulash(year: &HebrewYear) -> bool {
+    (shushan_purim(year) + Duration::days(1)).weekday() == Weekday::Sat
+}
+
+/// Walled cities don't keep Purim on the 14th, and in a Purim Meshulash year Shushan Purim itself
+/// is replaced by the three days returned by `get_walled_city`.
+pub fn kept_in_walled_city(year: &HebrewYear, reading: TorahReading) -> bool {
+    match reading {
+        TorahReading::Chol(Chol::Purim) => false,
+        TorahReading::Chol(Chol::ShushanPurim) => !is_purim_meshulash(year),
+        _ => true,
+    }
+}
+
+/// In a Purim Meshulash year, the Megillah is read (and gifts are given to the poor) on Friday,
+/// Al HaNissim is said and the Torah reading of Purim is the Maftir on Shabbos, and the Purim
+/// feast is held (and mishloach manos is sent) on Sunday.
+pub fn get_walled_city(year: &HebrewYear) -> Vec<DayVal> {
+    if !is_purim_meshulash(year) {
+        return vec![];
+    }
+    let shabbos = shushan_purim(year);
+    vec![
+        (
+            shabbos - Duration::days(1),
+            MinorDays::PurimMeshulashMegillah,
+        ),
+        (shabbos, MinorDays::PurimMeshulashAlHaNissim),
+        (shabbos + Duration::days(1), MinorDays::PurimMeshulashSeudah),
+    ]
+    .into_iter()
+    .map(|(day, name)| DayVal::new(day, Name::MinorDays(name)))
+    .collect()
+}
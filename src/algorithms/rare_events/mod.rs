@@ -0,0 +1,142 @@
+use crate::args::types::{DayVal, Language, Name};
+use crate::prelude::{gregorian_date, hebrew_month};
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc, Weekday};
+use heca_lib::prelude::HebrewMonth;
+use heca_lib::{HebrewDate, HebrewYear};
+use std::convert::TryInto;
+use std::io::{BufWriter, StdoutLock, Write};
+use std::num::NonZeroI8;
+
+// According to Shmuel, a year is 365 days and 6 hours, so a season is 91 days and 7.5 hours.
+const SECONDS_BETWEEN_TEKUFOS: i64 = 91 * 24 * 60 * 60 + 7 * 60 * 60 + 30 * 60;
+
+/// Events that come around once in many years, or that are calculated from Shmuel's year rather
+/// than from the molad.
+pub fn get(year: &HebrewYear) -> Vec<DayVal> {
+    let mut ret: Vec<DayVal> = tekufos(year)
+        .into_iter()
+        .flat_map(|(tekufa, birkas_hachama)| {
+            let mut events = vec![day_val(tekufa.time, RareEvent::Tekufa(tekufa))];
+            if birkas_hachama {
+                events.push(day_val(tekufa.time, RareEvent::BirkasHaChama));
+            }
+            events
+        })
+        .collect();
+
+    // Hakhel was on the first day of Chol HaMoed Sukkos after the Shmita year.
+    if year.year() % 7 == 1 {
+        ret.push(day_val(
+            year.get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(16).unwrap())
+                .unwrap()
+                .into(),
+            RareEvent::Hakhel,
+        ));
+    }
+
+    let erev_pesach: DateTime<Utc> = year
+        .get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(14).unwrap())
+        .unwrap()
+        .into();
+    if (erev_pesach + Duration::days(1)).weekday() == Weekday::Sat {
+        ret.push(day_val(erev_pesach, RareEvent::ErevPesachOnShabbos));
+    }
+    ret
+}
+
+fn day_val(time: DateTime<Utc>, rare_event: RareEvent) -> DayVal {
+    let date: HebrewDate = time.try_into().unwrap();
+    DayVal::new(date.into(), Name::RareEvent(rare_event))
+}
+
+/// Every Tekufah in the year, and whether Birkas HaChama is said on it. The Tekufah of Nissan
+/// 5769 was at the start of Wednesday night, when the sun returned to where it was created, and
+/// it does so again every 28 years.
+fn tekufos(year: &HebrewYear) -> Vec<(Tekufa, bool)> {
+    let tekufas_nissan_5769 = Utc.ymd(2009, 4, 7).and_hms(18, 0, 0);
+    let start: DateTime<Utc> = year
+        .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+        .unwrap()
+        .into();
+    let end: DateTime<Utc> = HebrewYear::new(year.year() + 1)
+        .unwrap()
+        .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+        .unwrap()
+        .into();
+    let first = (start - tekufas_nissan_5769).num_seconds() / SECONDS_BETWEEN_TEKUFOS;
+    (first - 1..first + 6)
+        .filter_map(|n| {
+            let time = tekufas_nissan_5769 + Duration::seconds(n * SECONDS_BETWEEN_TEKUFOS);
+            if time < start || time >= end {
+                return None;
+            }
+            let season = match n.rem_euclid(4) {
+                0 => Season::Nissan,
+                1 => Season::Tammuz,
+                2 => Season::Tishrei,
+                _ => Season::Teves,
+            };
+            Some((Tekufa { season, time }, n.rem_euclid(4 * 28) == 0))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Season {
+    Nissan,
+    Tammuz,
+    Tishrei,
+    Teves,
+}
+
+/// Like the molad, the time of the Tekufah is in traditional Jerusalem time.
+#[derive(Debug, Clone, Copy)]
+pub struct Tekufa {
+    pub season: Season,
+    pub time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RareEvent {
+    Tekufa(Tekufa),
+    BirkasHaChama,
+    Hakhel,
+    ErevPesachOnShabbos,
+}
+
+impl RareEvent {
+    pub fn pretty_print(
+        &self,
+        lock: &mut BufWriter<StdoutLock<'_>>,
+        language: Language,
+    ) -> Option<usize> {
+        let p = match self {
+            Self::Tekufa(tekufa) => {
+                let month = match tekufa.season {
+                    Season::Nissan => HebrewMonth::Nissan,
+                    Season::Tammuz => HebrewMonth::Tammuz,
+                    Season::Tishrei => HebrewMonth::Tishrei,
+                    Season::Teves => HebrewMonth::Teves,
+                };
+                lock.write(
+                    language
+                        .tr_format(
+                            "Tekufas {}: {}",
+                            &[
+                                &hebrew_month(month, language),
+                                &gregorian_date(&tekufa.time, "%A %B %-d %H:%M", language),
+                            ],
+                        )
+                        .as_bytes(),
+                )
+                .ok()?
+            }
+            Self::BirkasHaChama => lock.write(language.tr("Birkas HaChama").as_bytes()).ok()?,
+            Self::Hakhel => lock.write(language.tr("Hakhel").as_bytes()).ok()?,
+            Self::ErevPesachOnShabbos => lock
+                .write(language.tr("Erev Pesach on Shabbos").as_bytes())
+                .ok()?,
+        };
+        Some(p)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::args::types::{DayVal, Language, Name};
-use crate::prelude::{hebrew_month_english, hebrew_month_hebrew};
+use crate::prelude::{gregorian_month, hebrew_month, weekday};
 use chrono::{DateTime, Datelike, Duration, Timelike, Utc, Weekday};
 use heca_lib::prelude::HebrewMonth;
 use heca_lib::{HebrewDate, HebrewYear};
@@ -9,6 +9,10 @@ use std::convert::TryInto;
 use std::io::{BufWriter, StdoutLock, Write};
 use std::num::NonZeroI8;
 
+// The molad is given in the mean solar time of Jerusalem (35.2137° east, or 2:20:51 after UTC),
+// while Israel Standard Time is exactly two hours after UTC.
+const JERUSALEM_MEAN_TIME_TO_STANDARD_TIME: i64 = 20 * 60 + 51;
+
 pub fn get(year: &HebrewYear) -> Vec<DayVal> {
     let mut v = Vec::new();
     let last_day_of_month: Vec<HebrewDate> = {
@@ -42,8 +46,7 @@ pub fn get(year: &HebrewYear) -> Vec<DayVal> {
                 .unwrap(),
             year.get_hebrew_date(HebrewMonth::Av, NonZeroI8::new(29).unwrap())
                 .unwrap(),
-            year.get_hebrew_date(HebrewMonth::Elul, NonZeroI8::new(29).unwrap())
-                .unwrap(),
+            // Tishrei isn't blessed, so there's no Shabbos Mevarchim in Elul.
         ];
 
         if year.is_leap_year() {
@@ -54,7 +57,7 @@ pub fn get(year: &HebrewYear) -> Vec<DayVal> {
         }
         v
     };
-    let shabbos_mevarchim: Vec<(DateTime<Utc>, HebrewMonth)> = last_day_of_month
+    let shabbos_mevarchim: Vec<(DateTime<Utc>, HebrewMonth, Vec<Weekday>)> = last_day_of_month
         .into_iter()
         .map(|x| {
             let gregorian_date: DateTime<Utc> = x.try_into().unwrap();
@@ -90,16 +93,29 @@ pub fn get(year: &HebrewYear) -> Vec<DayVal> {
                 HebrewMonth::Av => HebrewMonth::Elul,
                 HebrewMonth::Elul => HebrewMonth::Tishrei,
             };
-            (gregorian_date - Duration::days(offset), m)
+            // The 29th starts the night before, so its daytime is a day after `gregorian_date`.
+            let rosh_chodesh = if year
+                .get_hebrew_date(x.month(), NonZeroI8::new(30).unwrap())
+                .is_ok()
+            {
+                vec![
+                    (gregorian_date + Duration::days(2)).weekday(),
+                    (gregorian_date + Duration::days(3)).weekday(),
+                ]
+            } else {
+                vec![(gregorian_date + Duration::days(2)).weekday()]
+            };
+            (gregorian_date - Duration::days(offset), m, rosh_chodesh)
         })
         .collect();
     for num in shabbos_mevarchim {
         let cur_month = num.1;
         let molad = year.get_molad(cur_month).unwrap();
         let molad_day = molad.get_day_utc();
-        v.push(DayVal {
-            day: num.0,
-            name: Name::ShabbosMevarchim(ShabbosMevarchim {
+        let molad_time = molad_day + Duration::seconds(i64::from(molad.get_chalakim()) * 10 / 3);
+        v.push(DayVal::new(
+            num.0,
+            Name::ShabbosMevarchim(ShabbosMevarchim {
                 hebrew_month: cur_month,
                 gregorian_month: molad_day.month(),
                 gregorian_dow: molad_day.weekday(),
@@ -107,9 +123,10 @@ pub fn get(year: &HebrewYear) -> Vec<DayVal> {
                 hour: molad_day.hour(),
                 minute: molad_day.minute(),
                 chalakim: molad.get_chalakim(),
+                standard_time: molad_time - Duration::seconds(JERUSALEM_MEAN_TIME_TO_STANDARD_TIME),
+                rosh_chodesh: num.2,
             }),
-            candle_lighting: None,
-        });
+        ));
     }
     v
 }
@@ -123,6 +140,8 @@ pub struct ShabbosMevarchim {
     pub hour: u32,
     pub minute: u32,
     pub chalakim: u16,
+    pub standard_time: DateTime<Utc>,
+    pub rosh_chodesh: Vec<Weekday>,
 }
 
 impl Serialize for ShabbosMevarchim {
@@ -151,105 +170,71 @@ impl Serialize for ShabbosMevarchim {
     }
 }
 
+fn weekday_english(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Sun => "Sunday",
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Shabbos",
+    }
+}
+
 impl ShabbosMevarchim {
+    /// The Rosh Chodesh announcement as said by the gabbai, e.g. "Rosh Chodesh Kislev will be on
+    /// Shabbos and Sunday".
+    pub fn announcement(&self, language: Language) -> String {
+        let month = hebrew_month(self.hebrew_month, language);
+        match self.rosh_chodesh[..] {
+            [day] => language.tr_format(
+                "Rosh Chodesh {} will be on {}",
+                &[&month, &weekday(day, language)],
+            ),
+            [first, second] => language.tr_format(
+                "Rosh Chodesh {} will be on {} and {}",
+                &[
+                    &month,
+                    &weekday(first, language),
+                    &weekday(second, language),
+                ],
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn rosh_chodesh_json(&self) -> Vec<&'static str> {
+        self.rosh_chodesh
+            .iter()
+            .map(|x| weekday_english(*x))
+            .collect()
+    }
+
     pub fn pretty_print(
         &self,
         lock: &mut BufWriter<StdoutLock<'_>>,
         language: Language,
     ) -> Option<usize> {
-        let p = match language {
-            Language::English => {
-                let mut p = lock.write(b"Shabbos Mevarchim ").ok()?;
-                p += lock
-                    .write(hebrew_month_english(self.hebrew_month).as_ref())
-                    .ok()?;
-                let gregorian_month = match self.gregorian_month {
-                    1 => "January",
-                    2 => "February",
-                    3 => "March",
-                    4 => "April",
-                    5 => "May",
-                    6 => "June",
-                    7 => "July",
-                    8 => "August",
-                    9 => "September",
-                    10 => "October",
-                    11 => "November",
-                    12 => "December",
-                    _ => unreachable!(),
-                };
-                let mut gregorian_day = [b'\0'; 2];
-                let mut hour_arr = [b'\0'; 2];
-                let mut minute_arr = [b'\0'; 2];
-                let mut chalakim_arr = [b'\0'; 2];
-                let count_gd = itoa::write(&mut gregorian_day[..], self.gregorian_day).unwrap();
-                let count_h = itoa::write(&mut hour_arr[..], self.hour).unwrap();
-                let count_m = itoa::write(&mut minute_arr[..], self.minute).unwrap();
-                let count_chalakim = itoa::write(&mut chalakim_arr[..], self.chalakim).unwrap();
-                p += lock.write(b": Molad is on ").ok()?;
-                p += match self.gregorian_dow {
-                    Weekday::Sun => lock.write(b"Sunday").ok()?,
-                    Weekday::Mon => lock.write(b"Monday").ok()?,
-                    Weekday::Tue => lock.write(b"Tuesday").ok()?,
-                    Weekday::Wed => lock.write(b"Wednesday").ok()?,
-                    Weekday::Thu => lock.write(b"Thursday").ok()?,
-                    Weekday::Fri => lock.write(b"Friday").ok()?,
-                    Weekday::Sat => lock.write(b"Shabbos").ok()?,
-                };
-                lock.write(b" ").ok()?;
-                p += lock.write(gregorian_month.as_bytes()).ok()?;
-                p += lock.write(b" ").ok()?;
-                p += lock.write(&gregorian_day[..count_gd]).ok()?;
-                p += lock.write(b", ").ok()?;
-                p += lock.write(&hour_arr[..count_h]).ok()?;
-                p += lock.write(b":").ok()?;
-                if count_m == 1 {
-                    p += lock.write(b"0").ok()?;
-                }
-                p += lock.write(&minute_arr[..count_m]).ok()?;
-                p += lock.write(b" and ").ok()?;
-                p += lock.write(&chalakim_arr[..count_chalakim]).ok()?;
-                p += lock.write(b" Chalakim").ok()?;
-                p
-            }
-            Language::Hebrew => {
-                let mut p = lock.write("שבת מברכים ".as_bytes()).ok()?;
-                p += lock
-                    .write(hebrew_month_hebrew(self.hebrew_month).as_ref())
-                    .ok()?;
-                let mut gregorian_day = [b'\0'; 2];
-                let mut hour_arr = [b'\0'; 2];
-                let mut minute_arr = [b'\0'; 2];
-                let mut chalakim_arr = [b'\0'; 2];
-                let count_gd = itoa::write(&mut gregorian_day[..], self.gregorian_day).unwrap();
-                let count_h = itoa::write(&mut hour_arr[..], self.hour).unwrap();
-                let count_m = itoa::write(&mut minute_arr[..], self.minute).unwrap();
-                let count_chalakim = itoa::write(&mut chalakim_arr[..], self.chalakim).unwrap();
-                p += lock.write(" זמן המולד: יום ".as_bytes()).ok()?;
-                p += match self.gregorian_dow {
-                    Weekday::Sun => lock.write("ראשון".as_bytes()).ok()?,
-                    Weekday::Mon => lock.write("שני".as_bytes()).ok()?,
-                    Weekday::Tue => lock.write("שלישי".as_bytes()).ok()?,
-                    Weekday::Wed => lock.write("רביעי".as_bytes()).ok()?,
-                    Weekday::Thu => lock.write("חמישי".as_bytes()).ok()?,
-                    Weekday::Fri => lock.write("שישי".as_bytes()).ok()?,
-                    Weekday::Sat => lock.write("שבת קודש".as_bytes()).ok()?,
-                };
-                p += lock.write(b" ").ok()?;
-                p += lock.write(&gregorian_day[..count_gd]).ok()?;
-                p += lock.write(b", ").ok()?;
-                p += lock.write(&hour_arr[..count_h]).ok()?;
-                p += lock.write(b":").ok()?;
-                if count_m == 1 {
-                    p += lock.write(b"0").ok()?;
-                }
-                p += lock.write(&minute_arr[..count_m]).ok()?;
-                p += lock.write(" ו ".as_bytes()).ok()?;
-                p += lock.write(&chalakim_arr[..count_chalakim]).ok()?;
-                p += lock.write(" חלקים".as_bytes()).ok()?;
-                p
-            }
-        };
+        let p = lock
+            .write(
+                language
+                    .tr_format(
+                        "Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}",
+                        &[
+                            &hebrew_month(self.hebrew_month, language),
+                            &weekday(self.gregorian_dow, language),
+                            &gregorian_month(self.gregorian_month, language),
+                            &self.gregorian_day,
+                            &format!("{}:{:02}", self.hour, self.minute),
+                            &self.chalakim,
+                            &self.standard_time.format("%H:%M:%S"),
+                            &self.announcement(language),
+                        ],
+                    )
+                    .as_bytes(),
+            )
+            .ok()?;
         Some(p)
     }
 }
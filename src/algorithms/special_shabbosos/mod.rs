@@ -0,0 +1,95 @@
+use crate::algorithms::shabbos_mevarchim;
+use crate::args::types::{DayVal, MinorDays, Name};
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use heca_lib::prelude::{
+    HebrewMonth, Location, Parsha, SpecialParsha, TorahReading, TorahReadingType,
+};
+use heca_lib::{HebrewDate, HebrewYear};
+use std::convert::TryInto;
+use std::num::NonZeroI8;
+
+/// Every Shabbos of the year that has a name of its own. Shabbos HaGadol, Chazon, Nachamu and
+/// Shuva are listed with the other minor holidays.
+pub fn get(year: &HebrewYear, location: Location) -> Vec<DayVal> {
+    let mut ret = Vec::new();
+    let first_of_chanukah: DateTime<Utc> = year
+        .get_hebrew_date(HebrewMonth::Kislev, NonZeroI8::new(25).unwrap())
+        .unwrap()
+        .into();
+    // In Israel, Chol HaMoed starts on the second day of Yom Tov.
+    let first_day_of_chol_hamoed = if location == Location::Israel { 16 } else { 17 };
+
+    for day in shabbosos(year) {
+        let date: HebrewDate = (day + Duration::hours(1)).try_into().unwrap();
+        let day_of_month = date.day().get();
+        let mut push = |name| ret.push(DayVal::new(day, Name::MinorDays(name)));
+
+        if day_of_month == 30 || (day_of_month == 1 && date.month() != HebrewMonth::Tishrei) {
+            push(MinorDays::ShabbosRoshChodesh);
+        }
+        if day_of_month == 29 {
+            push(MinorDays::ShabbosMacharChodesh);
+        }
+        let days_into_chanukah = (day - first_of_chanukah).num_days();
+        if (0..7).contains(&days_into_chanukah) {
+            push(MinorDays::ShabbosChanukah);
+        } else if days_into_chanukah == 7 {
+            push(MinorDays::ShabbosChanukahSheni);
+        }
+        if (first_day_of_chol_hamoed..=20).contains(&day_of_month) {
+            match date.month() {
+                HebrewMonth::Tishrei => push(MinorDays::ShabbosCholHaMoedSukkos),
+                HebrewMonth::Nissan => push(MinorDays::ShabbosCholHaMoedPesach),
+                _ => {}
+            }
+        }
+    }
+
+    ret.extend(
+        year.get_holidays(
+            location,
+            &[TorahReadingType::Shabbos, TorahReadingType::SpecialParsha],
+        )
+        .into_iter()
+        .filter_map(|x| {
+            let name = match x.name() {
+                TorahReading::Shabbos(Parsha::Beshalach) => MinorDays::ShabbosShira,
+                TorahReading::SpecialParsha(SpecialParsha::Shekalim) => MinorDays::ShabbosShekalim,
+                TorahReading::SpecialParsha(SpecialParsha::Zachor) => MinorDays::ShabbosZachor,
+                TorahReading::SpecialParsha(SpecialParsha::Parah) => MinorDays::ShabbosParah,
+                TorahReading::SpecialParsha(SpecialParsha::HaChodesh) => {
+                    MinorDays::ShabbosHaChodesh
+                }
+                _ => return None,
+            };
+            Some(DayVal::new(x.day().into(), Name::MinorDays(name)))
+        }),
+    );
+
+    ret.extend(
+        shabbos_mevarchim::get(year)
+            .into_iter()
+            .map(|x| DayVal::new(x.day, Name::MinorDays(MinorDays::ShabbosMevarchim))),
+    );
+    ret
+}
+
+/// The evenings on which every Shabbos of the year starts.
+fn shabbosos(year: &HebrewYear) -> impl Iterator<Item = DateTime<Utc>> {
+    let rosh_hashana: DateTime<Utc> = year
+        .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+        .unwrap()
+        .into();
+    let next_rosh_hashana: DateTime<Utc> = HebrewYear::new(year.year() + 1)
+        .unwrap()
+        .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
+        .unwrap()
+        .into();
+    let days_until_friday = (Weekday::Fri.num_days_from_sunday() + 7
+        - rosh_hashana.weekday().num_days_from_sunday())
+        % 7;
+    let first_shabbos = rosh_hashana + Duration::days(i64::from(days_until_friday));
+    (0..)
+        .map(move |week| first_shabbos + Duration::weeks(week))
+        .take_while(move |day| *day < next_rosh_hashana)
+}
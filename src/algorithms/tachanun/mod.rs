@@ -0,0 +1,107 @@
+use crate::algorithms::chabad_holidays;
+use crate::algorithms::israeli_holidays::{self, IsraeliHoliday};
+use crate::args::types::{Minhag, Name};
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use heca_lib::prelude::HebrewMonth;
+use heca_lib::{HebrewDate, HebrewYear};
+use serde::Serialize;
+use std::convert::TryInto;
+use std::num::NonZeroI8;
+
+/// Whether Tachanun is said at Shacharis and at Mincha.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Tachanun {
+    pub shacharis: bool,
+    pub mincha: bool,
+}
+
+pub fn get(date: HebrewDate, minhag: Minhag) -> Tachanun {
+    let shacharis = !omitted(date, minhag);
+    // Tachanun isn't said at Mincha before a day on which it isn't said, nor on Erev Shabbos.
+    let mincha = shacharis && weekday(date) != Weekday::Fri && !omitted(next_day(date), minhag);
+    Tachanun { shacharis, mincha }
+}
+
+fn omitted(date: HebrewDate, minhag: Minhag) -> bool {
+    if weekday(date) == Weekday::Sat || date.day().get() == 1 || date.day().get() == 30 {
+        return true;
+    }
+    if is_chanukah(date) {
+        return true;
+    }
+    let day = date.day().get();
+    let omitted = match date.month() {
+        HebrewMonth::Tishrei => day <= 2 || day >= 9,
+        HebrewMonth::Shvat => day == 15,
+        HebrewMonth::Adar | HebrewMonth::Adar1 | HebrewMonth::Adar2 => day == 14 || day == 15,
+        HebrewMonth::Nissan => true,
+        HebrewMonth::Iyar => day == 14 || day == 18,
+        HebrewMonth::Sivan => day <= 12,
+        HebrewMonth::Av => day == 9 || day == 15 || (day == 10 && tisha_bav_nidche(date)),
+        HebrewMonth::Elul => day == 29,
+        _ => false,
+    };
+    if omitted {
+        return true;
+    }
+
+    match minhag {
+        Minhag::Chabad => {
+            let this_day: DateTime<Utc> = date.into();
+            chabad_holidays::get(&HebrewYear::new(date.year()).unwrap())
+                .iter()
+                .any(|holiday| match &holiday.name {
+                    Name::ChabadHoliday(chabad_holiday) => {
+                        chabad_holiday.no_tachanun() && holiday.day == this_day
+                    }
+                    _ => false,
+                })
+        }
+        Minhag::EdotHaMizrach => is_yom_haatzmaut_or_yom_yerushalayim(date),
+        Minhag::Ashkenaz | Minhag::Sefard | Minhag::Yemenite => false,
+    }
+}
+
+pub fn is_yom_haatzmaut_or_yom_yerushalayim(date: HebrewDate) -> bool {
+    let this_day: DateTime<Utc> = date.into();
+    israeli_holidays::get(&HebrewYear::new(date.year()).unwrap(), false)
+        .iter()
+        .any(|holiday| match &holiday.name {
+            Name::IsraeliHoliday(IsraeliHoliday::YomHaAtzmaut)
+            | Name::IsraeliHoliday(IsraeliHoliday::YomYerushalayim) => holiday.day == this_day,
+            _ => false,
+        })
+}
+
+/// Chanukah starts on the 25th of Kislev and lasts for eight days, ending in Teves.
+pub fn is_chanukah(date: HebrewDate) -> bool {
+    let this_day: DateTime<Utc> = date.into();
+    let first_day: DateTime<Utc> = HebrewYear::new(date.year())
+        .unwrap()
+        .get_hebrew_date(HebrewMonth::Kislev, NonZeroI8::new(25).unwrap())
+        .unwrap()
+        .into();
+    this_day >= first_day && this_day < first_day + Duration::days(8)
+}
+
+/// The weekday of the daytime of a Hebrew date (which starts at sunset the evening before).
+pub fn weekday(date: HebrewDate) -> Weekday {
+    let evening: DateTime<Utc> = date.into();
+    (evening + Duration::days(1)).weekday()
+}
+
+pub fn next_day(date: HebrewDate) -> HebrewDate {
+    let evening: DateTime<Utc> = date.into();
+    (evening + Duration::days(1) + Duration::hours(1))
+        .try_into()
+        .unwrap()
+}
+
+/// If the ninth of Av is on Shabbos, the fast is pushed off to Sunday.
+fn tisha_bav_nidche(date: HebrewDate) -> bool {
+    let ninth = HebrewYear::new(date.year())
+        .unwrap()
+        .get_hebrew_date(HebrewMonth::Av, NonZeroI8::new(9).unwrap())
+        .unwrap();
+    weekday(ninth) == Weekday::Sat
+}
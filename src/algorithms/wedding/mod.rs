@@ -0,0 +1,110 @@
+use crate::algorithms::mourning_periods::{self, Period};
+use crate::algorithms::tachanun::weekday;
+use crate::args::types::{Language, Minhag, Name};
+use chrono::{DateTime, Duration, Utc, Weekday};
+use heca_lib::prelude::{Chol, HebrewMonth, Location, TorahReading, TorahReadingType, YomTov};
+use heca_lib::{HebrewDate, HebrewYear};
+use serde::Serialize;
+
+/// Why a wedding isn't held on a given day.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Reason {
+    Shabbos,
+    YomTov,
+    CholHaMoed,
+    Fast,
+    Sefirah,
+    ThreeWeeks,
+    NineDays,
+    AseresYemeiTeshuva,
+}
+
+/// Every reason not to hold a wedding on the given day. A wedding may be held if there are none.
+pub fn get(date: HebrewDate, location: Location, minhag: Minhag) -> Vec<Reason> {
+    let this_day: DateTime<Utc> = date.into();
+    let year = HebrewYear::new(date.year()).unwrap();
+    let mut ret = vec![];
+
+    if weekday(date) == Weekday::Sat {
+        ret.push(Reason::Shabbos);
+    }
+
+    year.get_holidays(
+        location,
+        &[TorahReadingType::YomTov, TorahReadingType::Chol],
+    )
+    .into_iter()
+    .filter(|x| {
+        let day: DateTime<Utc> = x.day().into();
+        day == this_day
+    })
+    .filter_map(|x| match x.name() {
+        TorahReading::YomTov(yom_tov) => Some(match yom_tov {
+            YomTov::Sukkos2 | YomTov::Pesach2 if location == Location::Israel => Reason::CholHaMoed,
+            YomTov::Sukkos3
+            | YomTov::Sukkos4
+            | YomTov::Sukkos5
+            | YomTov::Sukkos6
+            | YomTov::Sukkos7
+            | YomTov::Pesach3
+            | YomTov::Pesach4
+            | YomTov::Pesach5
+            | YomTov::Pesach6 => Reason::CholHaMoed,
+            _ => Reason::YomTov,
+        }),
+        TorahReading::Chol(Chol::TzomGedalia)
+        | TorahReading::Chol(Chol::TenTeves)
+        | TorahReading::Chol(Chol::TaanisEsther)
+        | TorahReading::Chol(Chol::SeventeenTammuz)
+        | TorahReading::Chol(Chol::NineAv) => Some(Reason::Fast),
+        _ => None,
+    })
+    .for_each(|reason| {
+        if !ret.contains(&reason) {
+            ret.push(reason)
+        }
+    });
+
+    // Sephardim only hold back from weddings from Rosh Chodesh Av (Shulchan Aruch OC 551:2).
+    let sefardi = match minhag {
+        Minhag::Ashkenaz | Minhag::Sefard | Minhag::Chabad => false,
+        Minhag::EdotHaMizrach | Minhag::Yemenite => true,
+    };
+    // A period can end in the morning, so check the daytime.
+    let midday = this_day + Duration::hours(18);
+    mourning_periods::get(&year, minhag)
+        .into_iter()
+        .for_each(|x| match x.name {
+            Name::MourningPeriod(period) if x.day <= midday && midday < period.end => {
+                match period.period {
+                    Period::Sefirah => ret.push(Reason::Sefirah),
+                    Period::ThreeWeeks if !sefardi => ret.push(Reason::ThreeWeeks),
+                    Period::NineDays if sefardi => ret.push(Reason::NineDays),
+                    // The Nine Days are part of the Three Weeks.
+                    Period::ThreeWeeks | Period::NineDays => {}
+                }
+            }
+            _ => {}
+        });
+
+    // Sephardim don't hold back from weddings between Rosh Hashana and Yom Kippur.
+    if !sefardi && date.month() == HebrewMonth::Tishrei && date.day().get() <= 10 {
+        ret.push(Reason::AseresYemeiTeshuva);
+    }
+    ret
+}
+
+impl Reason {
+    pub fn pretty(self, language: Language) -> &'static str {
+        language.tr(match self {
+            Reason::Shabbos => "Shabbos",
+            Reason::YomTov => "Yom Tov",
+            Reason::CholHaMoed => "Chol HaMoed",
+            Reason::Fast => "a fast day",
+            Reason::Sefirah => "Sefirah",
+            Reason::ThreeWeeks => "the Three Weeks",
+            Reason::NineDays => "the Nine Days",
+            Reason::AseresYemeiTeshuva => "the Ten Days of Repentance",
+        })
+    }
+}
@@ -1,46 +1,134 @@
+use crate::algorithms::candle_lighting::City;
+use crate::args::natural::parse_natural;
 use crate::args::prelude::{parse_hebrew, ConfigDateFmt, ConfigDateType};
-use crate::args::types::{AppError, Command, ConvertArgs, ConvertType, Language};
+use crate::args::types::{
+    AppError, Command, ConvertArgs, ConvertBatchArgs, ConvertDetails, ConvertType, Language,
+};
 use crate::args::DATE_TOKEN;
 use chrono::prelude::*;
 use heca_lib::HebrewDate;
+use std::convert::TryInto;
 
 pub fn parse_options(
     date: &str,
     language: Language,
     datefmt: ConfigDateFmt,
     date_type: ConfigDateType,
+    city: Option<City>,
+    details: Option<ConvertDetails>,
 ) -> Result<Command, AppError> {
-    let sp = date.split(&DATE_TOKEN[..]).collect::<Vec<&str>>();
-    if sp.len() != 3 {
+    let (date, time) = split_time(date)?;
+    Ok(Command::Convert(ConvertArgs {
+        language,
+        date: parse_date(date, datefmt, date_type)?,
+        time,
+        city,
+        details,
+    }))
+}
+
+pub fn parse_batch_options(
+    input: &str,
+    column: Option<&str>,
+    header: bool,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+    city: Option<City>,
+    details: Option<ConvertDetails>,
+) -> Result<Command, AppError> {
+    Ok(Command::ConvertBatch(ConvertBatchArgs {
+        input: if input == "-" {
+            None
+        } else {
+            Some(input.into())
+        },
+        column: column
+            .map(|column| {
+                column
+                    .parse()
+                    .map_err(|_| AppError::ColumnError(column.into()))
+            })
+            .transpose()?,
+        header,
+        datefmt,
+        date_type,
+        city,
+        details,
+    }))
+}
+
+/// Splits the time of day off a date written as "2024-03-10T19:30" or "2024/3/10 19:30".
+pub fn split_time(date: &str) -> Result<(&str, Option<NaiveTime>), AppError> {
+    let date = date.trim();
+    match date.rfind(&['T', ' '][..]) {
+        Some(index) if date[index + 1..].contains(':') => {
+            let time = &date[index + 1..];
+            let time = NaiveTime::parse_from_str(time, "%H:%M")
+                .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M:%S"))
+                .map_err(|_| AppError::CannotParseTime(time.into()))?;
+            Ok((&date[..index], Some(time)))
+        }
+        _ => Ok((date, None)),
+    }
+}
+
+/// Reads a date as a Hebrew date. A Gregorian date is taken to mean the daytime of that date.
+pub fn parse_hebrew_day(
+    date: &str,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<HebrewDate, AppError> {
+    Ok(match parse_date(date, datefmt, date_type)? {
+        ConvertType::Hebrew(date) => date,
+        ConvertType::Gregorian(date) => date.and_hms(0, 0, 1).try_into()?,
+    })
+}
+
+pub fn parse_date(
+    date: &str,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<ConvertType, AppError> {
+    if let Some(date) = parse_natural(date) {
+        return date;
+    }
+    let sp = date
+        .split(&DATE_TOKEN[..])
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<&str>>();
+    // A Hebrew date can have a fourth word, as in "ה׳ אדר ב׳ תשע״ט".
+    let hebrew_only = sp.len() == 4;
+    if sp.len() != 3 && !hebrew_only {
         return Err(AppError::SplitDateError);
     }
 
     Ok(match date_type {
-        ConfigDateType::Hebrew => parse_convert_hebrew(&sp, language)?,
-        ConfigDateType::Gregorian => parse_convert_gregorian(&sp, datefmt, language)?,
+        ConfigDateType::Hebrew => parse_convert_hebrew(&sp)?,
+        ConfigDateType::Gregorian if hebrew_only => return Err(AppError::SplitDateError),
+        ConfigDateType::Gregorian => parse_convert_gregorian(&sp, datefmt)?,
         ConfigDateType::Fuzzy => {
-            if sp[1].parse::<u8>().is_ok() {
-                parse_convert_gregorian(&sp, datefmt, language)?
+            if !hebrew_only && sp[1].parse::<u8>().is_ok() {
+                parse_convert_gregorian(&sp, datefmt)?
             } else {
-                parse_convert_hebrew(&sp, language)?
+                parse_convert_hebrew(&sp)?
             }
         }
     })
 }
 
-pub fn parse_convert_hebrew(sp: &[&str], language: Language) -> Result<Command, AppError> {
+pub fn parse_convert_hebrew(sp: &[&str]) -> Result<ConvertType, AppError> {
     let (day, month, year) = parse_hebrew(sp)?;
-    Ok(Command::Convert(ConvertArgs {
-        language,
-        date: ConvertType::Hebrew(HebrewDate::from_ymd(year.unwrap(), month, day)?),
-    }))
+    Ok(ConvertType::Hebrew(HebrewDate::from_ymd(
+        year.unwrap(),
+        month,
+        day,
+    )?))
 }
 
 pub fn parse_convert_gregorian(
     sp: &[&str],
     format: ConfigDateFmt,
-    language: Language,
-) -> Result<Command, AppError> {
+) -> Result<ConvertType, AppError> {
     let (day, month, year) = match format {
         ConfigDateFmt::ISO | ConfigDateFmt::B => {
             let year = sp[0]
@@ -81,12 +169,9 @@ pub fn parse_convert_gregorian(
             (day, month, year)
         }
     };
-    Ok(Command::Convert(ConvertArgs {
-        language,
-        date: ConvertType::Gregorian(
-            Utc.ymd_opt(year, month, day)
-                .single()
-                .ok_or_else(|| AppError::InvalidGregorianDate(year, month, day))?,
-        ),
-    }))
+    Ok(ConvertType::Gregorian(
+        Utc.ymd_opt(year, month, day)
+            .single()
+            .ok_or_else(|| AppError::InvalidGregorianDate(year, month, day))?,
+    ))
 }
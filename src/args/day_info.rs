@@ -0,0 +1,33 @@
+use crate::args::convert::parse_date;
+use crate::args::prelude::{get_location, get_minhag, Config, ConfigDateFmt, ConfigDateType};
+use crate::args::types::{AppError, Command, ConvertType, DayInfoArgs, Language};
+use clap::ArgMatches;
+use heca_lib::HebrewDate;
+use std::convert::TryInto;
+
+pub fn parse_options(
+    matches: &ArgMatches<'_>,
+    config: &Config,
+    language: Language,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<Command, AppError> {
+    let date: HebrewDate = match parse_date(matches.value_of("Date").unwrap(), datefmt, date_type)?
+    {
+        ConvertType::Hebrew(date) => date,
+        ConvertType::Gregorian(date) => date.and_hms(0, 0, 1).try_into()?,
+    };
+
+    let place = get_location(matches.value_of("Location"), config, language)?;
+    let location = place.location();
+    let walled_city = place.is_walled_city();
+
+    let minhag = get_minhag(matches.value_of("Minhag"))?;
+
+    Ok(Command::DayInfo(DayInfoArgs {
+        date,
+        location,
+        walled_city,
+        minhag,
+    }))
+}
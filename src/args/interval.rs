@@ -0,0 +1,39 @@
+use crate::args::convert::parse_hebrew_day;
+use crate::args::prelude::{ConfigDateFmt, ConfigDateType};
+use crate::args::types::{AddArgs, AppError, Command, DiffArgs, Unit};
+use clap::ArgMatches;
+
+pub fn parse_diff_options(
+    matches: &ArgMatches<'_>,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<Command, AppError> {
+    let from = parse_hebrew_day(matches.value_of("From").unwrap(), datefmt, date_type)?;
+    let to = parse_hebrew_day(matches.value_of("To").unwrap(), datefmt, date_type)?;
+
+    Ok(Command::Diff(DiffArgs { from, to }))
+}
+
+pub fn parse_add_options(
+    matches: &ArgMatches<'_>,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<Command, AppError> {
+    let date = parse_hebrew_day(matches.value_of("Date").unwrap(), datefmt, date_type)?;
+    let amount = matches.value_of("Amount").unwrap();
+    let amount = amount
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| AppError::CannotParseAmount(amount.into()))?;
+    let unit = match matches.value_of("Unit").unwrap() {
+        "day" | "days" => Unit::Days,
+        "week" | "weeks" => Unit::Weeks,
+        "month" | "months" => Unit::Months,
+        "year" | "years" => Unit::Years,
+        "hebrew-month" | "hebrew-months" => Unit::HebrewMonths,
+        "hebrew-year" | "hebrew-years" => Unit::HebrewYears,
+        x => unreachable!("{}", x),
+    };
+
+    Ok(Command::Add(AddArgs { date, amount, unit }))
+}
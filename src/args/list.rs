@@ -1,11 +1,15 @@
-use crate::algorithms::candle_lighting::{City, CITIES};
-use crate::args::prelude::{str_to_location, Config};
+use crate::algorithms::kiddush_levana::{KiddushLevanaEnd, KiddushLevanaStart};
+use crate::args::convert::parse_hebrew_day;
+use crate::args::prelude::{
+    get_city, get_location, get_minhag, Config, ConfigDateFmt, ConfigDateType,
+};
 use crate::args::types::{
     AppError, Command, CustomHoliday, DailyStudy, Event, Language, ListArgs, MinorHoliday,
     RambamChapters, YearType,
 };
+use chrono::prelude::*;
 use clap::ArgMatches;
-use heca_lib::prelude::{Location, TorahReadingType};
+use heca_lib::prelude::TorahReadingType;
 
 use std::env;
 use std::ops::Deref;
@@ -16,16 +20,30 @@ pub fn parse_options(
     language: Language,
     custom_days: &[CustomHoliday],
 ) -> Result<Command, AppError> {
+    if let (Some(from), Some(to)) = (matches.value_of("From"), matches.value_of("To")) {
+        let date_type = match matches.value_of("YearType") {
+            Some("hebrew") => ConfigDateType::Hebrew,
+            Some("gregorian") => ConfigDateType::Gregorian,
+            _ => ConfigDateType::Fuzzy,
+        };
+        let from = parse_hebrew_day(from, ConfigDateFmt::ISO, date_type)?;
+        let to = parse_hebrew_day(to, ConfigDateFmt::ISO, date_type)?;
+        if DateTime::<Utc>::from(to) < DateTime::<Utc>::from(from) {
+            return Err(AppError::ToBeforeFrom);
+        }
+        return list_args(
+            matches,
+            config,
+            language,
+            custom_days,
+            YearType::Range(from, to),
+        );
+    }
     let year_num = matches
         .value_of("Year")
         .unwrap()
         .parse()
         .expect("The supplied year must be a number");
-    let amnt_years = matches
-        .value_of("AmountYears")
-        .unwrap()
-        .parse()
-        .expect("Amount of years must be a number");
 
     let year = if let Some(year_type) = matches.value_of("YearType") {
         match year_type {
@@ -59,6 +77,22 @@ pub fn parse_options(
         YearType::Gregorian(year_num)
     };
 
+    list_args(matches, config, language, custom_days, year)
+}
+
+fn list_args(
+    matches: &ArgMatches<'_>,
+    config: &Config,
+    language: Language,
+    custom_days: &[CustomHoliday],
+    year: YearType,
+) -> Result<Command, AppError> {
+    let amnt_years = matches
+        .value_of("AmountYears")
+        .unwrap()
+        .parse()
+        .expect("Amount of years must be a number");
+
     let no_sort = if matches.occurrences_of("NoSort") > 0 {
         true
     } else if let Some(no_sort) = env::var_os("HECA_NOSORT") {
@@ -81,69 +115,26 @@ pub fn parse_options(
         }
     };
 
-    let location = if let Some(location) = matches.value_of("Location") {
-        str_to_location(location)?
-    } else if let Some(location) = env::var_os("LOC") {
-        let location: String = location.into_string().unwrap();
-        str_to_location(&location)?
-    } else if let Some(location) = env::var_os("HECA_LOCATION") {
-        let location: String = location.into_string().unwrap();
-        str_to_location(&location)?
-    } else if let Some(location) = &config.location {
-        *location
-    } else if language == Language::Hebrew {
-        Location::Israel
-    } else {
-        Location::Chul
+    let kiddush_levana_start = match matches.value_of("KiddushLevanaStart") {
+        Some("7") => KiddushLevanaStart::SevenDays,
+        _ => KiddushLevanaStart::ThreeDays,
     };
 
-    let city = if let Some(city) = matches.value_of("City") {
-        Some(String::from(city))
-    } else if let Some(city) = env::var_os("HECA_CITY") {
-        Some(city.to_string_lossy().into_owned())
-    } else if let Some(city) = &config.default_city {
-        Some(city.clone())
-    } else {
-        None
+    let kiddush_levana_end = match matches.value_of("KiddushLevanaEnd") {
+        Some("15") => KiddushLevanaEnd::FifteenDays,
+        _ => KiddushLevanaEnd::HalfMonth,
     };
 
-    let city: Option<City> = if let Some(city) = city {
-        let res = config
-            .cities
-            .as_ref()
-            .and_then(|config_city_vec| {
-                config_city_vec
-                    .iter()
-                    .find(|config_city| config_city.name == city)
-                    .and_then(|x| Some(x.clone()))
-            })
-            .and_then(|config_city| {
-                Some(City {
-                    name: config_city.name.into(),
-                    time_zone: config_city.time_zone.clone(),
-                    latitude: config_city.latitude,
-                    longitude: config_city.longitude,
-                    candlelighting_to_sunset: config_city.light_candles_before_shkiya,
-                })
-            })
-            .or_else(|| {
-                CITIES.iter().find(|x| city == x.name).and_then(|x| {
-                    Some(City {
-                        candlelighting_to_sunset: x.candlelighting_to_sunset.clone(),
-                        latitude: x.latitude.clone(),
-                        longitude: x.longitude.clone(),
-                        name: x.name.clone(),
-                        time_zone: x.time_zone.clone(),
-                    })
-                })
-            });
-        match res {
-            Some(res) => Some(res),
-            None => return Err(AppError::CityNotFound(city)),
-        }
-    } else {
-        None
-    };
+    let place = get_location(matches.value_of("Location"), config, language)?;
+    let location = place.location();
+    let walled_city = place.is_walled_city();
+
+    let aliyot = matches.occurrences_of("Aliyot") > 0;
+    let haftarah = matches.occurrences_of("Haftarah") > 0;
+    let minhag = get_minhag(matches.value_of("Minhag"))?;
+    let triennial = matches.occurrences_of("Triennial") > 0;
+
+    let city = get_city(matches.value_of("City"), config)?;
 
     let events = matches
         .values_of("Events")
@@ -159,6 +150,7 @@ pub fn parse_options(
                 .map(|x| Event::CustomHoliday(x.clone()))
                 .collect(),
             "minor-holidays" => vec![Event::MinorHoliday(MinorHoliday::Minor)],
+            "special-shabbosos" => vec![Event::MinorHoliday(MinorHoliday::SpecialShabbosos)],
             "daf-yomi" => vec![Event::DailyStudy(DailyStudy::DafYomi)],
             "yerushalmi-yomi" => vec![Event::DailyStudy(DailyStudy::YerushalmiYomi)],
             "rambam-3-chapters" => {
@@ -170,6 +162,9 @@ pub fn parse_options(
             "chabad-holidays" => vec![Event::ChabadHolidays],
 
             "shabbos-mevarchim" => vec![Event::ShabbosMevarchim],
+            "kiddush-levana" => vec![Event::KiddushLevana],
+            "rare-events" => vec![Event::RareEvents],
+            "mourning-periods" => vec![Event::MourningPeriods],
             _ => unreachable!("{}", x),
         })
         .collect::<Vec<Event>>();
@@ -177,9 +172,16 @@ pub fn parse_options(
         city,
         year,
         location,
+        walled_city,
         events,
         amnt_years,
         no_sort,
         exact_days,
+        kiddush_levana_start,
+        kiddush_levana_end,
+        aliyot,
+        haftarah,
+        minhag,
+        triennial,
     }))
 }
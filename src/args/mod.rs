@@ -1,11 +1,19 @@
 use clap::{App, Arg, ArgMatches, SubCommand};
 
-mod convert;
+pub(crate) mod convert;
+mod day_info;
+mod interval;
 mod list;
+mod natural;
 pub(crate) mod prelude;
 pub mod types;
+mod wedding;
+mod year_info;
 
-use crate::args::prelude::{Config, ConfigDateFmt, ConfigDateType};
+use crate::args::prelude::{
+    get_city, get_location, str_to_language, str_to_transliteration, Config, ConfigDateFmt,
+    ConfigDateType, LANGUAGES,
+};
 use crate::args::types::*;
 use std::env;
 
@@ -32,9 +40,18 @@ where
                    .arg(Arg::with_name("language")
                        .long("language")
                        .help("Set language")
-                       .possible_values(&["en_US", "he_IL"])
+                       .possible_values(&LANGUAGES)
                        .takes_value(true)
                        .required(false))
+                   .arg(Arg::with_name("transliteration")
+                       .long("transliteration")
+                       .help("Set how Hebrew names are spelled in English: ashkenazi (Shabbos, Sukkos), sephardi (Shabbat, Sukkot) or academic (Shabbat, Ḥanukkah)")
+                       .possible_values(&["ashkenazi", "sephardi", "academic"])
+                       .takes_value(true)
+                       .required(false))
+                   .arg(Arg::with_name("thousands")
+                       .long("thousands")
+                       .help("Include the thousands in Hebrew years written in Hebrew letters (ה׳תשע״ט instead of תשע״ט)"))
                    .subcommand(SubCommand::with_name("convert")
                        .about("Converts Hebrew to Gregorian and back")
                        .arg(Arg::with_name("DateFormat")
@@ -51,9 +68,201 @@ where
                            .takes_value(true)
                            .required(false)
                            .default_value("fuzzy"))
+                       .arg(Arg::with_name("batch")
+                           .long("batch")
+                           .value_name("FILE")
+                           .help("Convert every line of FILE (or of the standard input if FILE is -) instead of a single date")
+                           .takes_value(true)
+                           .conflicts_with("Date"))
+                       .arg(Arg::with_name("column")
+                           .long("column")
+                           .help("With --batch, read the dates from this column of a CSV file (counting from 1)")
+                           .takes_value(true)
+                           .requires("batch"))
+                       .arg(Arg::with_name("header")
+                           .long("header")
+                           .help("With --batch, skip the first line (the header of a CSV file)")
+                           .requires("batch"))
+                       .arg(Arg::with_name("Details")
+                           .long("details")
+                           .help("Also show the day of the week, the events of the day (as in list), this week's parsha and the daily study"))
+                       .arg(Arg::with_name("Location")
+                           .long("location")
+                           .help("With --details, are you looking for an Israeli calendar or a Chutz La'aretz calendar? \"Jerusalem\" is an Israeli calendar that keeps Purim on the 15th of Adar")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["Chul", "Israel", "Jerusalem"]))
+                       .arg(Arg::with_name("City")
+                           .long("city")
+                           .help("The city whose sunset decides which Hebrew date a time (as in 2024-03-10T19:30) falls on. Can be a built-in city or a city specified in the config file.")
+                           .takes_value(true)
+                           .required(false))
+                       .arg(Arg::with_name("Date")
+                           .required_unless("batch")
+                           .takes_value(true)))
+                   .subcommand(SubCommand::with_name("year-info")
+                       .about("Shows the Shmita cycle, Maaser year and keviah of a Hebrew year")
+                       .arg(Arg::with_name("Year")
+                           .required(true)
+                           .takes_value(true)))
+                   .subcommand(SubCommand::with_name("day-info")
+                       .about("Shows which prayers are said on a given day")
+                       .arg(Arg::with_name("DateFormat")
+                           .long("datefmt")
+                           .help("Set date format (for Gregorian only): US or M for mm/dd/yyyy, UK or L for dd/mm/yyyy, ISO or B for yyyy/mm/dd")
+                           .possible_values(&["US", "M", "UK", "L", "ISO", "B"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("ISO"))
+                       .arg(Arg::with_name("T")
+                           .long("type")
+                           .long_help("Force the date to be read as type T, where T is either \"hebrew\" (then date must be written as '5/אדרא/5779'), as \"gregorian\" (where the date must be written as '1996/12/19'), or fuzzy (is Hebrew if year is above 4000, Gregorian otherwise).")
+                           .possible_values(&["hebrew", "gregorian", "fuzzy"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("fuzzy"))
+                       .arg(Arg::with_name("Location")
+                           .long("location")
+                           .help("Are you looking for an Israeli calendar or a Chutz La'aretz calendar? \"Jerusalem\" is an Israeli calendar that keeps Purim on the 15th of Adar")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["Chul", "Israel", "Jerusalem"]))
+                       .arg(Arg::with_name("Minhag")
+                           .long("minhag")
+                           .help("Whose customs to follow. Default is \"ashkenaz\"")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["ashkenaz", "sefard", "chabad", "edot-hamizrach", "yemenite"]))
                        .arg(Arg::with_name("Date")
                            .required(true)
                            .takes_value(true)))
+                   .subcommand(SubCommand::with_name("wedding-check")
+                       .about("Shows whether a wedding may be held on a given day, and why not")
+                       .arg(Arg::with_name("DateFormat")
+                           .long("datefmt")
+                           .help("Set date format (for Gregorian only): US or M for mm/dd/yyyy, UK or L for dd/mm/yyyy, ISO or B for yyyy/mm/dd")
+                           .possible_values(&["US", "M", "UK", "L", "ISO", "B"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("ISO"))
+                       .arg(Arg::with_name("T")
+                           .long("type")
+                           .long_help("Force the date to be read as type T, where T is either \"hebrew\" (then date must be written as '5/אדרא/5779'), as \"gregorian\" (where the date must be written as '1996/12/19'), or fuzzy (is Hebrew if year is above 4000, Gregorian otherwise).")
+                           .possible_values(&["hebrew", "gregorian", "fuzzy"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("fuzzy"))
+                       .arg(Arg::with_name("Location")
+                           .long("location")
+                           .help("Are you looking for an Israeli calendar or a Chutz La'aretz calendar? \"Jerusalem\" is an Israeli calendar that keeps Purim on the 15th of Adar")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["Chul", "Israel", "Jerusalem"]))
+                       .arg(Arg::with_name("Minhag")
+                           .long("minhag")
+                           .help("Whose customs to follow. Default is \"ashkenaz\"")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["ashkenaz", "sefard", "chabad", "edot-hamizrach", "yemenite"]))
+                       .arg(Arg::with_name("Date")
+                           .required(true)
+                           .takes_value(true)))
+                   .subcommand(SubCommand::with_name("wedding-dates")
+                       .about("Lists the days between two dates on which a wedding may be held")
+                       .arg(Arg::with_name("DateFormat")
+                           .long("datefmt")
+                           .help("Set date format (for Gregorian only): US or M for mm/dd/yyyy, UK or L for dd/mm/yyyy, ISO or B for yyyy/mm/dd")
+                           .possible_values(&["US", "M", "UK", "L", "ISO", "B"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("ISO"))
+                       .arg(Arg::with_name("T")
+                           .long("type")
+                           .long_help("Force the date to be read as type T, where T is either \"hebrew\" (then date must be written as '5/אדרא/5779'), as \"gregorian\" (where the date must be written as '1996/12/19'), or fuzzy (is Hebrew if year is above 4000, Gregorian otherwise).")
+                           .possible_values(&["hebrew", "gregorian", "fuzzy"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("fuzzy"))
+                       .arg(Arg::with_name("Location")
+                           .long("location")
+                           .help("Are you looking for an Israeli calendar or a Chutz La'aretz calendar? \"Jerusalem\" is an Israeli calendar that keeps Purim on the 15th of Adar")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["Chul", "Israel", "Jerusalem"]))
+                       .arg(Arg::with_name("Minhag")
+                           .long("minhag")
+                           .help("Whose customs to follow. Default is \"ashkenaz\"")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["ashkenaz", "sefard", "chabad", "edot-hamizrach", "yemenite"]))
+                       .arg(Arg::with_name("From")
+                           .required(true)
+                           .takes_value(true))
+                       .arg(Arg::with_name("To")
+                           .required(true)
+                           .takes_value(true)))
+                   .subcommand(SubCommand::with_name("diff")
+                       .about("Shows how long it is from one date to another, in the Hebrew and in the Gregorian calendar")
+                       .arg(Arg::with_name("DateFormat")
+                           .long("datefmt")
+                           .help("Set date format (for Gregorian only): US or M for mm/dd/yyyy, UK or L for dd/mm/yyyy, ISO or B for yyyy/mm/dd")
+                           .possible_values(&["US", "M", "UK", "L", "ISO", "B"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("ISO"))
+                       .arg(Arg::with_name("T")
+                           .long("type")
+                           .long_help("Force the dates to be read as type T, where T is either \"hebrew\" (then date must be written as '5/אדרא/5779'), as \"gregorian\" (where the date must be written as '1996/12/19'), or fuzzy (is Hebrew if year is above 4000, Gregorian otherwise).")
+                           .possible_values(&["hebrew", "gregorian", "fuzzy"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("fuzzy"))
+                       .arg(Arg::with_name("From")
+                           .required(true)
+                           .takes_value(true))
+                       .arg(Arg::with_name("To")
+                           .required(true)
+                           .takes_value(true)))
+                   .subcommand(SubCommand::with_name("add")
+                       .about("Adds days, weeks, or Hebrew or Gregorian months or years to a date")
+                       .arg(Arg::with_name("DateFormat")
+                           .long("datefmt")
+                           .help("Set date format (for Gregorian only): US or M for mm/dd/yyyy, UK or L for dd/mm/yyyy, ISO or B for yyyy/mm/dd")
+                           .possible_values(&["US", "M", "UK", "L", "ISO", "B"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("ISO"))
+                       .arg(Arg::with_name("T")
+                           .long("type")
+                           .long_help("Force the date to be read as type T, where T is either \"hebrew\" (then date must be written as '5/אדרא/5779'), as \"gregorian\" (where the date must be written as '1996/12/19'), or fuzzy (is Hebrew if year is above 4000, Gregorian otherwise).")
+                           .possible_values(&["hebrew", "gregorian", "fuzzy"])
+                           .takes_value(true)
+                           .required(false)
+                           .default_value("fuzzy"))
+                       .arg(Arg::with_name("Date")
+                           .required(true)
+                           .takes_value(true))
+                       .arg(Arg::with_name("Amount")
+                           .help("How many to add, like 12 or +12. To go back, write -- before it, as in -- -12")
+                           .required(true)
+                           .takes_value(true))
+                       .arg(Arg::with_name("Unit")
+                           .required(true)
+                           .takes_value(true)
+                           .possible_values(&[
+                               "day",
+                               "days",
+                               "week",
+                               "weeks",
+                               "month",
+                               "months",
+                               "year",
+                               "years",
+                               "hebrew-month",
+                               "hebrew-months",
+                               "hebrew-year",
+                               "hebrew-years"
+                           ])))
                    .subcommand(SubCommand::with_name("list")
                        .arg(Arg::with_name("YearType")
                            .long("type")
@@ -69,12 +278,39 @@ where
                                .long("exact-days")
                                .help("If showing modern Israeli holidays, show them on their true days, ignoring the recommendation of the Rabbanut to celebrate them early or late to avoid breaking Shabbos.")
                        )
+                       .arg(Arg::with_name("KiddushLevanaStart")
+                           .long("kiddush-levana-start")
+                           .help("When is the earliest time to say Kiddush Levana? Three or seven days after the molad. Default is \"3\"")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["3", "7"]))
+                       .arg(Arg::with_name("KiddushLevanaEnd")
+                           .long("kiddush-levana-end")
+                           .help("When is the latest time to say Kiddush Levana? Halfway between the molads or fifteen days after the molad. Default is \"half\"")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["half", "15"]))
+                       .arg(Arg::with_name("Aliyot")
+                           .long("aliyot")
+                           .help("Show the verses read in each aliyah of every Torah reading"))
+                       .arg(Arg::with_name("Haftarah")
+                           .long("haftarah")
+                           .help("Show the Haftarah read after every Shabbos and Yom Tov Torah reading"))
+                       .arg(Arg::with_name("Triennial")
+                           .long("triennial")
+                           .help("Show the part of the weekly parsha read in this year of the triennial cycle"))
+                       .arg(Arg::with_name("Minhag")
+                           .long("minhag")
+                           .help("Whose customs to follow when choosing the Haftarah, the Chanukah reading and the end of the Sefirah mourning period. Default is \"ashkenaz\"")
+                           .takes_value(true)
+                           .required(false)
+                           .possible_values(&["ashkenaz", "sefard", "chabad", "edot-hamizrach", "yemenite"]))
                        .arg(Arg::with_name("Location")
                            .long("location")
-                           .help("Are you looking for an Israeli calendar or a Chutz La'aretz calendar?")
+                           .help("Are you looking for an Israeli calendar or a Chutz La'aretz calendar? \"Jerusalem\" is an Israeli calendar that keeps Purim on the 15th of Adar")
                            .takes_value(true)
                            .required(false)
-                           .possible_values(&["Chul", "Israel"]))
+                           .possible_values(&["Chul", "Israel", "Jerusalem"]))
                        .arg(Arg::with_name("AmountYears")
                            .long("years")
                            .help("Generate events for n years")
@@ -107,31 +343,81 @@ where
                            "rambam-1-chapter",
                            "israeli-holidays",
                            "chabad-holidays",
-                           "shabbos-mevarchim"
+                           "shabbos-mevarchim",
+                           "kiddush-levana",
+                           "special-shabbosos",
+                           "rare-events",
+                           "mourning-periods"
                        ])
                        .default_value("yom-tov"))
+                       .arg(Arg::with_name("From")
+                       .long("from")
+                       .help("List the events from this date instead of a whole year. It's read the way convert reads a date")
+                       .takes_value(true)
+                       .requires("To")
+                       .conflicts_with("Year"))
+                       .arg(Arg::with_name("To")
+                       .long("to")
+                       .help("With --from, list the events through this date")
+                       .takes_value(true)
+                       .requires("From"))
                        .arg(Arg::with_name("Year")
-                       .required(true)
+                       .required_unless("From")
                        .takes_value(true))).get_matches_safe()?, output_type)
 }
 
 fn get_language(config_language: Option<Language>, passed_language: Option<&str>) -> Language {
     if let Some(language) = passed_language {
-        match language {
-            "en_US" => Language::English,
-            "he_IL" => Language::Hebrew,
-            _ => unreachable!(),
-        }
+        str_to_language(language).unwrap()
     } else if let Some(language) = config_language {
         language
     } else if let Ok(language) = env::var("LANG") {
-        if language == "he_IL.UTF-8" || language == "he_IL" {
-            Language::Hebrew
+        str_to_language(&language).unwrap_or(Language::English(Transliteration::Ashkenazi))
+    } else {
+        Language::English(Transliteration::Ashkenazi)
+    }
+}
+
+/// The language, with the transliteration spelling its names if it's English.
+fn get_transliteration(
+    language: Language,
+    config_transliteration: Option<Transliteration>,
+    passed_transliteration: Option<&str>,
+) -> Result<Language, AppError> {
+    Ok(match language {
+        Language::English(_) => Language::English(if let Some(t) = passed_transliteration {
+            str_to_transliteration(t)?
+        } else if let Some(t) = config_transliteration {
+            t
         } else {
-            Language::English
+            Transliteration::Ashkenazi
+        }),
+        language => language,
+    })
+}
+
+fn get_datefmt(matches: &ArgMatches<'_>) -> ConfigDateFmt {
+    if let Some(datefmt) = matches.value_of("DateFormat") {
+        match datefmt {
+            "ISO" => ConfigDateFmt::ISO,
+            "B" => ConfigDateFmt::B,
+            "M" => ConfigDateFmt::M,
+            "UK" => ConfigDateFmt::UK,
+            "L" => ConfigDateFmt::L,
+            "US" => ConfigDateFmt::US,
+            _ => unreachable!(),
         }
     } else {
-        Language::English
+        ConfigDateFmt::ISO
+    }
+}
+
+fn get_date_type(matches: &ArgMatches<'_>) -> ConfigDateType {
+    match matches.value_of("T").unwrap() {
+        "hebrew" => ConfigDateType::Hebrew,
+        "gregorian" => ConfigDateType::Gregorian,
+        "fuzzy" => ConfigDateType::Fuzzy,
+        _ => unreachable!(),
     }
 }
 
@@ -148,35 +434,75 @@ fn parse_args(matches: ArgMatches<'_>, output_type: OutputType) -> Result<MainAr
         None => None,
     };
 
-    let language = get_language(config_file.language, matches.value_of("language"));
+    let language = get_transliteration(
+        get_language(config_file.language, matches.value_of("language")),
+        config_file.transliteration,
+        matches.value_of("transliteration"),
+    )?;
 
     let command = if let Some(matches) = matches.subcommand_matches("list") {
         list::parse_options(matches, &config_file, language, &config_file.custom_days)?
     } else if let Some(matches) = matches.subcommand_matches("convert") {
-        let datefmt = if let Some(datefmt) = matches.value_of("DateFormat") {
-            match datefmt {
-                "ISO" => ConfigDateFmt::ISO,
-                "B" => ConfigDateFmt::B,
-                "M" => ConfigDateFmt::M,
-                "UK" => ConfigDateFmt::UK,
-                "L" => ConfigDateFmt::L,
-                "US" => ConfigDateFmt::US,
-                _ => unreachable!(),
-            }
+        let details = if matches.is_present("Details") {
+            let place = get_location(matches.value_of("Location"), &config_file, language)?;
+            Some(ConvertDetails {
+                location: place.location(),
+                walled_city: place.is_walled_city(),
+                custom_days: config_file.custom_days.clone(),
+            })
         } else {
-            ConfigDateFmt::ISO
+            None
         };
-        convert::parse_options(
-            matches.value_of("Date").unwrap(),
+        if let Some(input) = matches.value_of("batch") {
+            convert::parse_batch_options(
+                input,
+                matches.value_of("column"),
+                matches.is_present("header"),
+                get_datefmt(matches),
+                get_date_type(matches),
+                get_city(matches.value_of("City"), &config_file)?,
+                details,
+            )?
+        } else {
+            convert::parse_options(
+                matches.value_of("Date").unwrap(),
+                language,
+                get_datefmt(matches),
+                get_date_type(matches),
+                get_city(matches.value_of("City"), &config_file)?,
+                details,
+            )?
+        }
+    } else if let Some(matches) = matches.subcommand_matches("day-info") {
+        day_info::parse_options(
+            matches,
+            &config_file,
+            language,
+            get_datefmt(matches),
+            get_date_type(matches),
+        )?
+    } else if let Some(matches) = matches.subcommand_matches("wedding-check") {
+        wedding::parse_check_options(
+            matches,
+            &config_file,
+            language,
+            get_datefmt(matches),
+            get_date_type(matches),
+        )?
+    } else if let Some(matches) = matches.subcommand_matches("wedding-dates") {
+        wedding::parse_dates_options(
+            matches,
+            &config_file,
             language,
-            datefmt,
-            match matches.value_of("T").unwrap() {
-                "hebrew" => ConfigDateType::Hebrew,
-                "gregorian" => ConfigDateType::Gregorian,
-                "fuzzy" => ConfigDateType::Fuzzy,
-                _ => unreachable!(),
-            },
+            get_datefmt(matches),
+            get_date_type(matches),
         )?
+    } else if let Some(matches) = matches.subcommand_matches("year-info") {
+        year_info::parse_options(matches)?
+    } else if let Some(matches) = matches.subcommand_matches("diff") {
+        interval::parse_diff_options(matches, get_datefmt(matches), get_date_type(matches))?
+    } else if let Some(matches) = matches.subcommand_matches("add") {
+        interval::parse_add_options(matches, get_datefmt(matches), get_date_type(matches))?
     } else {
         return Err(AppError::ArgUndefinedError(String::from(matches.usage())));
     };
@@ -185,6 +511,8 @@ fn parse_args(matches: ArgMatches<'_>, output_type: OutputType) -> Result<MainAr
         custom_days: None,
         output_type,
         language,
+        thousands: matches.is_present("thousands"),
+        strings: config_file.strings,
         command,
     })
 }
@@ -0,0 +1,242 @@
+//! Dates written in words instead of numbers: `today`, `tomorrow`, `yesterday`, `next shabbos`,
+//! a number of days or weeks from today (`+30d`, `-2w`), holidays and days of Hebrew months, with
+//! or without a year (`pesach 2025`, `erev pesach 5785`, `next chanukah`, `9 av 2025`).
+//!
+//! Holidays are found by the names they have in `list`'s JSON, so anything `list` shows (apart
+//! from the weekly parsha, custom holidays and daily study) can be converted. Days are numbered the
+//! way they are there, so `pesach` (or `pesach 1`) is the first day of Pesach and `pesach 8` the
+//! last.
+
+use crate::algorithms::{chabad_holidays, israeli_holidays};
+use crate::args::prelude::parse_hebrew;
+use crate::args::types::{AppError, ConvertType, DayVal, Name};
+use crate::prelude::constants::get_minor_holidays;
+use chrono::prelude::*;
+use chrono::Duration;
+use heca_lib::prelude::{HebrewMonth, Location, TorahReadingType};
+use heca_lib::{HebrewDate, HebrewYear};
+use std::convert::TryInto;
+use std::num::NonZeroI8;
+
+/// Other spellings of words in the JSON names.
+const WORDS: [(&str, &str); 8] = [
+    ("shabbat", "shabbos"),
+    ("sukkot", "sukkos"),
+    ("shavuot", "shavuos"),
+    ("atzeret", "atzeres"),
+    ("hashana", "hashanah"),
+    ("hanukkah", "chanukah"),
+    ("hanukah", "chanukah"),
+    ("chanuka", "chanukah"),
+];
+
+/// Other names of holidays, without spaces, as they're matched.
+const NAMES: [(&str, &str); 4] = [
+    ("tishabav", "nineav"),
+    ("tishabeav", "nineav"),
+    ("tubishvat", "fifteenshvat"),
+    ("tubshvat", "fifteenshvat"),
+];
+
+/// Reads a date written in words. Returns `None` if it isn't one, so it can be read as a number.
+pub fn parse_natural(date: &str) -> Option<Result<ConvertType, AppError>> {
+    let input = date;
+    let date = date.trim().to_lowercase();
+    let today = Utc.from_utc_date(&Local::today().naive_local());
+    match date.as_ref() {
+        "today" => return Some(Ok(ConvertType::Gregorian(today))),
+        "tomorrow" => return Some(Ok(ConvertType::Gregorian(today + Duration::days(1)))),
+        "yesterday" => return Some(Ok(ConvertType::Gregorian(today - Duration::days(1)))),
+        _ => {}
+    }
+    if let Some(day) = parse_relative(&date, today) {
+        return Some(
+            day.map(ConvertType::Gregorian)
+                .ok_or_else(|| AppError::DateOutOfRange(input.trim().into())),
+        );
+    }
+
+    let mut words: Vec<&str> = date.split_whitespace().collect();
+    if words.first() == Some(&"next") {
+        words.remove(0);
+    }
+    // A small number after a name is the day of the holiday (`pesach 8`), unless another number
+    // comes before it (`pesach 8 25`).
+    let year = match words.as_slice() {
+        [.., before, last] => match last.parse::<u64>() {
+            Ok(year) if year >= 1000 || before.parse::<u64>().is_ok() => {
+                words.pop();
+                Some(year)
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    // A day of a Hebrew month with a Gregorian year (or without a year), as in `9 av 2025`.
+    if let [day, month @ ..] = words.as_slice() {
+        if let Ok((day, month, _)) = parse_hebrew(&[day, &month.join(" ")]) {
+            return Some(
+                find_date(day, month, year, today)
+                    .map(ConvertType::Hebrew)
+                    .ok_or_else(|| AppError::DateNotFound(input.trim().into())),
+            );
+        }
+    }
+    let name: String = words
+        .iter()
+        .map(|word| {
+            WORDS
+                .iter()
+                .find(|(spelling, _)| spelling == word)
+                .map_or(*word, |(_, json_spelling)| json_spelling)
+        })
+        .collect::<String>()
+        .replace(|c: char| !c.is_alphanumeric(), "");
+    let name = NAMES
+        .iter()
+        .find(|(other_name, _)| *other_name == name)
+        .map_or(&name[..], |(_, json_name)| json_name);
+    if !name.chars().any(char::is_alphabetic) {
+        return None;
+    }
+    if year.is_none() && (name == "shabbos" || name == "saturday") {
+        let days_until_shabbos = 6 - today.weekday().num_days_from_sunday() as i64;
+        return Some(Ok(ConvertType::Gregorian(
+            today
+                + Duration::days(if days_until_shabbos == 0 {
+                    7
+                } else {
+                    days_until_shabbos
+                }),
+        )));
+    }
+    match find_holiday(name, year, today) {
+        Some(day) => Some(Ok(ConvertType::Hebrew(day))),
+        // A holiday that the year doesn't have, or a year that can't be calculated.
+        None if year.is_some() && find_holiday(name, None, today).is_some() => {
+            Some(Err(AppError::DateNotFound(input.trim().into())))
+        }
+        None => None,
+    }
+}
+
+/// Reads a number of days or weeks from today, like `+30d` or `-2w`. Returns `None` if it isn't
+/// one, and `Some(None)` if the day would be out of range.
+fn parse_relative(date: &str, today: Date<Utc>) -> Option<Option<Date<Utc>>> {
+    let sign = match date.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let amount = date.get(1..date.len() - 1)?;
+    if amount.is_empty() || !amount.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let unit = match date.chars().last()? {
+        'd' => 1,
+        'w' => 7,
+        _ => return None,
+    };
+    Some(
+        amount
+            .parse::<i64>()
+            .ok()
+            .and_then(|amount| amount.checked_mul(unit * sign))
+            .and_then(|days| (today.num_days_from_ce() as i64).checked_add(days))
+            .and_then(|day| day.try_into().ok())
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .map(|day| Utc.from_utc_date(&day)),
+    )
+}
+
+/// Finds the holiday in a Hebrew year, or in the Gregorian year if it's below 4000 (as in
+/// `convert`'s fuzzy type). Without a year, finds the next one from today.
+fn find_holiday(name: &str, year: Option<u64>, today: Date<Utc>) -> Option<HebrewDate> {
+    find_day(year, today, |year| {
+        holidays(year)
+            .into_iter()
+            .filter(|x| match x.name.json_name() {
+                Some(json_name) => {
+                    let json_name = json_name.to_lowercase();
+                    // A holiday of one day is also its first day (`purim 1`).
+                    json_name == name
+                        || json_name == format!("{}1", name)
+                        || name == format!("{}1", json_name)
+                }
+                None => false,
+            })
+            .map(|x| x.day)
+            .collect()
+    })
+}
+
+/// Finds a day of a Hebrew month the same way as a holiday.
+fn find_date(
+    day: NonZeroI8,
+    month: HebrewMonth,
+    year: Option<u64>,
+    today: Date<Utc>,
+) -> Option<HebrewDate> {
+    find_day(year, today, |year| {
+        year.get_hebrew_date(month, day)
+            .ok()
+            .map(DateTime::<Utc>::from)
+            .into_iter()
+            .collect()
+    })
+}
+
+/// Finds the first of the days `days_of` gives for each Hebrew year that's in the given year, or
+/// that's from today on if there isn't one.
+fn find_day(
+    year: Option<u64>,
+    today: Date<Utc>,
+    days_of: impl Fn(&HebrewYear) -> Vec<DateTime<Utc>>,
+) -> Option<HebrewDate> {
+    let (hebrew_years, from, to) = match year {
+        Some(year) if year >= 4000 => (vec![year], None, None),
+        Some(year) => (
+            vec![year + 3760, year + 3761],
+            Some(Utc.ymd_opt(year as i32, 1, 1).single()?),
+            Some(Utc.ymd_opt(year as i32, 12, 31).single()?),
+        ),
+        None => {
+            let this_year: HebrewDate = today.and_hms(0, 0, 1).try_into().ok()?;
+            (
+                vec![this_year.year(), this_year.year() + 1],
+                Some(today),
+                None,
+            )
+        }
+    };
+    hebrew_years
+        .into_iter()
+        .filter_map(|year| HebrewYear::new(year).ok())
+        .flat_map(|year| days_of(&year))
+        // A day starts at sunset of the Gregorian day before.
+        .map(|day| day + Duration::days(1))
+        .filter(|day| from.iter().all(|from| day.date() >= *from))
+        .filter(|day| to.iter().all(|to| day.date() <= *to))
+        .min()
+        .and_then(|day| day.date().and_hms(0, 0, 1).try_into().ok())
+}
+
+/// Every holiday of the year that `list` can show, as it's kept in Chutz La'aretz.
+fn holidays(year: &HebrewYear) -> Vec<DayVal> {
+    let mut ret: Vec<DayVal> = year
+        .get_holidays(
+            Location::Chul,
+            &[
+                TorahReadingType::YomTov,
+                TorahReadingType::Chol,
+                TorahReadingType::SpecialParsha,
+            ],
+        )
+        .into_iter()
+        .map(|x| DayVal::new(x.day().into(), Name::TorahReading(x.name())))
+        .collect();
+    ret.extend(get_minor_holidays(year));
+    ret.extend(israeli_holidays::get(year, false));
+    ret.extend(chabad_holidays::get(year));
+    ret
+}
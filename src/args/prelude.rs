@@ -1,29 +1,161 @@
-use crate::args::types::{AppError, CustomHoliday, DayMonth, Language};
+use crate::algorithms::candle_lighting::{City, CITIES};
+use crate::args::types::{
+    AppError, CustomHoliday, DayMonth, Language, Minhag, Place, Transliteration,
+};
 use crate::args::DATE_TOKEN;
-use heca_lib::prelude::{HebrewMonth, Location};
+use heca_lib::prelude::HebrewMonth;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::convert::TryInto;
+use std::env;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::ErrorKind;
 use std::num::NonZeroI8;
 use zmanim::prelude::tz::TimeZone;
 
-pub fn str_to_location(location: &str) -> Result<Location, AppError> {
+pub fn str_to_location(location: &str) -> Result<Place, AppError> {
     match location.to_lowercase().as_ref() {
-        "chul" => Ok(Location::Chul),
-        "israel" => Ok(Location::Israel),
+        "chul" => Ok(Place::Chul),
+        "israel" => Ok(Place::Israel),
+        "jerusalem" => Ok(Place::Jerusalem),
         x => Err(AppError::LocationError(x.into())),
     }
 }
 
+pub fn get_location(
+    passed_location: Option<&str>,
+    config: &Config,
+    language: Language,
+) -> Result<Place, AppError> {
+    Ok(if let Some(location) = passed_location {
+        str_to_location(location)?
+    } else if let Some(location) = env::var_os("LOC") {
+        let location: String = location.into_string().unwrap();
+        str_to_location(&location)?
+    } else if let Some(location) = env::var_os("HECA_LOCATION") {
+        let location: String = location.into_string().unwrap();
+        str_to_location(&location)?
+    } else if let Some(location) = &config.location {
+        *location
+    } else if language == Language::Hebrew {
+        Place::Israel
+    } else {
+        Place::Chul
+    })
+}
+
+/// Finds the city to use for the zmanim: the one that was passed, or the one in `HECA_CITY`, or
+/// the default city in the config file, looked up in the config file's cities and then in the
+/// built-in ones.
+pub fn get_city(passed_city: Option<&str>, config: &Config) -> Result<Option<City>, AppError> {
+    let city = if let Some(city) = passed_city {
+        Some(String::from(city))
+    } else if let Some(city) = env::var_os("HECA_CITY") {
+        Some(city.to_string_lossy().into_owned())
+    } else if let Some(city) = &config.default_city {
+        Some(city.clone())
+    } else {
+        None
+    };
+
+    Ok(if let Some(city) = city {
+        let res = config
+            .cities
+            .as_ref()
+            .and_then(|config_city_vec| {
+                config_city_vec
+                    .iter()
+                    .find(|config_city| config_city.name == city)
+                    .and_then(|x| Some(x.clone()))
+            })
+            .and_then(|config_city| {
+                Some(City {
+                    name: config_city.name.into(),
+                    time_zone: config_city.time_zone.clone(),
+                    latitude: config_city.latitude,
+                    longitude: config_city.longitude,
+                    candlelighting_to_sunset: config_city.light_candles_before_shkiya,
+                })
+            })
+            .or_else(|| {
+                CITIES.iter().find(|x| city == x.name).and_then(|x| {
+                    Some(City {
+                        candlelighting_to_sunset: x.candlelighting_to_sunset.clone(),
+                        latitude: x.latitude.clone(),
+                        longitude: x.longitude.clone(),
+                        name: x.name.clone(),
+                        time_zone: x.time_zone.clone(),
+                    })
+                })
+            });
+        match res {
+            Some(res) => Some(res),
+            None => return Err(AppError::CityNotFound(city)),
+        }
+    } else {
+        None
+    })
+}
+
+pub fn str_to_minhag(minhag: &str) -> Result<Minhag, AppError> {
+    match minhag.to_lowercase().as_ref() {
+        "ashkenaz" => Ok(Minhag::Ashkenaz),
+        "sefard" => Ok(Minhag::Sefard),
+        "chabad" => Ok(Minhag::Chabad),
+        "edot-hamizrach" => Ok(Minhag::EdotHaMizrach),
+        "yemenite" => Ok(Minhag::Yemenite),
+        x => Err(AppError::MinhagError(x.into())),
+    }
+}
+
+pub fn str_to_transliteration(transliteration: &str) -> Result<Transliteration, AppError> {
+    match transliteration.to_lowercase().as_ref() {
+        "ashkenazi" => Ok(Transliteration::Ashkenazi),
+        "sephardi" => Ok(Transliteration::Sephardi),
+        "academic" => Ok(Transliteration::Academic),
+        x => Err(AppError::TransliterationError(x.into())),
+    }
+}
+
+/// The locales that can be passed to `--language`, one for each language.
+pub const LANGUAGES: [&str; 7] = [
+    "en_US", "he_IL", "fr_FR", "ru_RU", "es_ES", "yi_US", "pt_BR",
+];
+
+/// The language of a locale like `he_IL` or `fr_CA.UTF-8`, by its language code.
+pub fn str_to_language(locale: &str) -> Option<Language> {
+    match locale.split(&['_', '.'][..]).next().unwrap() {
+        "en" => Some(Language::English(Transliteration::Ashkenazi)),
+        "he" => Some(Language::Hebrew),
+        "fr" => Some(Language::French),
+        "ru" => Some(Language::Russian),
+        "es" => Some(Language::Spanish),
+        "yi" => Some(Language::Yiddish),
+        "pt" => Some(Language::Portuguese),
+        _ => None,
+    }
+}
+
+pub fn get_minhag(passed_minhag: Option<&str>) -> Result<Minhag, AppError> {
+    Ok(if let Some(minhag) = passed_minhag {
+        str_to_minhag(minhag)?
+    } else if let Some(minhag) = env::var_os("HECA_MINHAG") {
+        str_to_minhag(&minhag.to_string_lossy())?
+    } else {
+        Minhag::Ashkenaz
+    })
+}
+
 pub struct Config {
     pub custom_days: Vec<CustomHoliday>,
     pub language: Option<Language>,
-    pub location: Option<Location>,
+    pub transliteration: Option<Transliteration>,
+    pub location: Option<Place>,
     pub exact_days: Option<bool>,
     pub default_city: Option<String>,
     pub cities: Option<Vec<ConfigCity>>,
+    pub strings: HashMap<String, String>,
 }
 
 #[cfg(macos)]
@@ -63,10 +195,12 @@ impl Config {
         };
         let mut custom_days = vec![];
         let mut language = None;
+        let mut transliteration = None;
         let mut default_city = None;
         let mut cities = None;
         let mut location = None;
         let mut exact_days = None;
+        let mut strings = HashMap::new();
         if let Some(ref mut file) = config_file {
             let mut f = String::new();
             file.read_to_string(&mut f)?;
@@ -90,10 +224,12 @@ impl Config {
                                 )
                             }),
                             language: c.language,
+                            transliteration: None,
                             location: c.location,
                             exact_days: c.exact_days,
                             default_city: c.default_city,
                             cities: c.cities,
+                            strings: None,
                         },
                         Err(_) => {
                             return Err(err.into());
@@ -110,17 +246,23 @@ impl Config {
             if let Some(loc) = config.location {
                 location = Some(str_to_location(loc.as_ref())?);
             }
+            if let Some(t) = config.transliteration {
+                transliteration = Some(str_to_transliteration(t.as_ref())?);
+            }
+            if let Some(strings_config) = config.strings {
+                strings = strings_config;
+            }
             if let Some(exact) = config.exact_days {
                 exact_days = Some(exact)
             };
             language = config
                 .language
-                .and_then(|lang_string| match lang_string.as_ref() {
-                    "en_US" => Some(Language::English),
-                    "he_IL" => Some(Language::Hebrew),
-                    l => panic!(
-                        "Wrong language type {} in config file. Must be \"en_US\" or \"he_IL\"",
-                        l
+                .map(|lang_string| match str_to_language(&lang_string) {
+                    Some(language) => language,
+                    None => panic!(
+                        "Wrong language type {} in config file. Must be one of {}",
+                        lang_string,
+                        LANGUAGES.join(", ")
                     ),
                 });
             if let Some(days) = config.days {
@@ -174,18 +316,23 @@ impl Config {
         Ok(Self {
             default_city,
             language,
+            transliteration,
             custom_days,
             location,
             exact_days,
             cities,
+            strings,
         })
     }
 }
 
+/// Marks that set Hebrew numerals apart from words, including the ones word processors use.
+const GERESH: [char; 7] = ['\'', '"', '׳', '״', '\u{2018}', '\u{2019}', '\u{201D}'];
+
+/// Parses a date written as day, month and (optionally) year. The day and the year can be written
+/// in digits or in Hebrew letters, and Adar I and Adar II can be written as two words.
 pub fn parse_hebrew(sp: &[&str]) -> Result<(NonZeroI8, HebrewMonth, Option<u64>), AppError> {
-    let day: i8 = sp[0]
-        .parse()
-        .map_err(|_| AppError::DayIsNotAValidNumber(sp[0].to_owned()))?;
+    let day = parse_number(sp[0]).ok_or_else(|| AppError::DayIsNotAValidNumber(sp[0].into()))?;
     let day = if let Some(day) = NonZeroI8::new(
         day.try_into()
             .map_err(|_| AppError::DayIsNotAValidNumber(sp[0].into()))?,
@@ -194,49 +341,128 @@ pub fn parse_hebrew(sp: &[&str]) -> Result<(NonZeroI8, HebrewMonth, Option<u64>)
     } else {
         return Err(AppError::DayIsNotAValidNumber(sp[0].to_owned()));
     };
-    let year = if let Some(y) = sp.get(2) {
-        Some(
-            y.parse()
-                .map_err(|_| AppError::YearIsNotANumber(sp[2].to_owned()))?,
-        )
+    let (month_text, year) = match sp.len() {
+        4 => (format!("{}{}", sp[1], sp[2]), sp.get(3)),
+        _ => (String::from(sp[1]), sp.get(2)),
+    };
+    let year = if let Some(y) = year {
+        let year = parse_number(y).ok_or_else(|| AppError::YearIsNotANumber((*y).to_owned()))?;
+        // A year without the thousands is in the current millennium.
+        Some(if year < 1000 { year + 5000 } else { year })
     } else {
         None
     };
-    let month = str_to_month(sp[1])
-        .or_else(|| str_to_month(&(String::from(sp[1]).to_lowercase())))
-        .ok_or_else(|| AppError::MonthNotParsed(sp[1].to_owned()))?;
+    let month = str_to_month(&month_text)
+        .or_else(|| str_to_month(&month_text.to_lowercase()))
+        .ok_or_else(|| AppError::MonthNotParsed(month_text.clone()))?;
     Ok((day, month, year))
 }
 
+/// Reads a number written in digits or in Hebrew letters. A single letter before the hundreds (as
+/// in התשע״ט, ה׳תשע״ט or ד׳תתקפ״ה) stands for the thousands. The letters must be written the usual
+/// way, from the largest to the smallest, with ט״ו and ט״ז for 15 and 16.
+fn parse_number(text: &str) -> Option<u64> {
+    if let Ok(number) = text.parse() {
+        return Some(number);
+    }
+    let values = text
+        .chars()
+        .filter(|c| !GERESH.contains(c))
+        .map(|c| match c {
+            'א'..='ט' => Some(c as u64 - 'א' as u64 + 1),
+            'י' => Some(10),
+            'כ' | 'ך' => Some(20),
+            'ל' => Some(30),
+            'מ' | 'ם' => Some(40),
+            'נ' | 'ן' => Some(50),
+            'ס' => Some(60),
+            'ע' => Some(70),
+            'פ' | 'ף' => Some(80),
+            'צ' | 'ץ' => Some(90),
+            'ק' => Some(100),
+            'ר' => Some(200),
+            'ש' => Some(300),
+            'ת' => Some(400),
+            _ => None,
+        })
+        .collect::<Option<Vec<u64>>>()?;
+    let (thousands, mut rest) = match values.as_slice() {
+        [thousands, hundreds, ..] if *thousands < 10 && *hundreds >= 100 => {
+            (thousands * 1000, &values[1..])
+        }
+        _ => (0, values.as_slice()),
+    };
+    let mut number = thousands;
+    while let [400, tail @ ..] = rest {
+        number += 400;
+        rest = tail;
+    }
+    if let [hundreds @ 100..=300, tail @ ..] = rest {
+        number += hundreds;
+        rest = tail;
+    }
+    let tens = match rest {
+        [tens @ 10..=90, tail @ ..] => {
+            rest = tail;
+            *tens
+        }
+        _ => 0,
+    };
+    number += tens;
+    number += match rest {
+        [] => 0,
+        // 15 and 16 are only written ט״ו and ט״ז.
+        [5..=6] if tens == 10 => return None,
+        [units] if *units < 10 => *units,
+        [9, units @ 6..=7] if tens == 0 => 9 + units,
+        _ => return None,
+    };
+    if number == 0 {
+        None
+    } else {
+        Some(number)
+    }
+}
+
 fn str_to_month(text: &str) -> Option<HebrewMonth> {
-    match text {
+    let text = text
+        .chars()
+        .filter(|c| !GERESH.contains(c))
+        .collect::<String>();
+    match text.as_ref() {
         "תשרי" => Some(HebrewMonth::Tishrei),
-        "חשוון" => Some(HebrewMonth::Cheshvan),
+        "חשוון" | "חשון" | "מרחשוון" | "מרחשון" => {
+            Some(HebrewMonth::Cheshvan)
+        }
         "כסלו" => Some(HebrewMonth::Kislev),
         "טבת" => Some(HebrewMonth::Teves),
         "שבט" => Some(HebrewMonth::Shvat),
         "אדר" => Some(HebrewMonth::Adar),
-        "אדרא" => Some(HebrewMonth::Adar1),
-        "אדרב" => Some(HebrewMonth::Adar2),
+        "אדרא" | "אדרראשון" => Some(HebrewMonth::Adar1),
+        "אדרב" | "אדרשני" => Some(HebrewMonth::Adar2),
         "ניסן" => Some(HebrewMonth::Nissan),
         "אייר" => Some(HebrewMonth::Iyar),
-        "סיוון" => Some(HebrewMonth::Sivan),
+        "סיוון" | "סיון" => Some(HebrewMonth::Sivan),
         "תמוז" => Some(HebrewMonth::Tammuz),
-        "אב" => Some(HebrewMonth::Av),
+        "אב" | "מנחםאב" => Some(HebrewMonth::Av),
         "אלול" => Some(HebrewMonth::Elul),
+        // No month starts with a ב, so it must be a prefix, as in "בכסלו".
+        title if title.starts_with('ב') => str_to_month(&title['ב'.len_utf8()..]),
         title => match title {
-            "tishrei" | "tishre" => Some(HebrewMonth::Tishrei),
-            "cheshvan" | "marcheshvan" | "mar cheshvan" => Some(HebrewMonth::Cheshvan),
+            "tishrei" | "tishre" | "tishri" => Some(HebrewMonth::Tishrei),
+            "cheshvan" | "marcheshvan" | "mar cheshvan" | "heshvan" | "ḥeshvan" => {
+                Some(HebrewMonth::Cheshvan)
+            }
             "kislev" => Some(HebrewMonth::Kislev),
             "teves" | "tevet" | "teiveis" => Some(HebrewMonth::Teves),
             "shvat" | "shevat" => Some(HebrewMonth::Shvat),
             "adar" => Some(HebrewMonth::Adar),
             "adar1" | "adar 1" | "adar aleph" | "adar rishon" => Some(HebrewMonth::Adar1),
-            "adar2" | "adar 2" | "adar beis" | "adar bet" | "adar sheini" => {
+            "adar2" | "adar 2" | "adar beis" | "adar bet" | "adar sheini" | "adar sheni" => {
                 Some(HebrewMonth::Adar2)
             }
-            "nissan" | "Nisan" => Some(HebrewMonth::Nissan),
-            "iyar" => Some(HebrewMonth::Iyar),
+            "nissan" | "nisan" => Some(HebrewMonth::Nissan),
+            "iyar" | "iyyar" => Some(HebrewMonth::Iyar),
             "sivan" => Some(HebrewMonth::Sivan),
             "tammuz" | "tamuz" => Some(HebrewMonth::Tammuz),
             "av" | "menachem av" => Some(HebrewMonth::Av),
@@ -273,12 +499,14 @@ pub struct ConfigCity {
 struct ConfigFile {
     days: Option<Vec<InnerDate>>,
     language: Option<String>,
+    transliteration: Option<String>,
     location: Option<String>,
     #[serde(rename = "exact-days")]
     exact_days: Option<bool>,
     #[serde(rename = "default-city")]
     default_city: Option<String>,
     cities: Option<Vec<ConfigCity>>,
+    strings: Option<HashMap<String, String>>,
 }
 #[derive(Deserialize)]
 struct InnerDate {
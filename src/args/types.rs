@@ -1,8 +1,15 @@
+use crate::algorithms::aliyot::{Reading, Triennial};
 use crate::algorithms::candle_lighting::CITIES;
 use crate::algorithms::chabad_holidays::ChabadHoliday;
+use crate::algorithms::divergence::OtherLocation;
+use crate::algorithms::haftarah::Haftarah;
 use crate::algorithms::israeli_holidays::IsraeliHoliday;
+use crate::algorithms::kiddush_levana::{KiddushLevana, KiddushLevanaEnd, KiddushLevanaStart};
+use crate::algorithms::mourning_periods::MourningPeriod;
+use crate::algorithms::rare_events::RareEvent;
+use crate::args::prelude::{ConfigDateFmt, ConfigDateType};
 
-use std::num::NonZeroI8;
+use std::num::{NonZeroI8, NonZeroUsize};
 
 use chrono::prelude::*;
 use heca_lib::prelude::*;
@@ -14,18 +21,45 @@ pub struct MainArgs {
     pub custom_days: Option<Vec<Name>>,
     pub output_type: OutputType,
     pub language: Language,
+    /// Whether Hebrew years written in Hebrew letters include the thousands.
+    pub thousands: bool,
+    /// Titles from the config file's `[strings]` table, by the JSON name of the event they replace.
+    pub strings: HashMap<String, String>,
     pub command: Command,
 }
 
 #[derive(Eq, PartialEq, Clone, Copy)]
 pub enum Language {
-    English,
+    English(Transliteration),
     Hebrew,
+    French,
+    Russian,
+    Spanish,
+    Yiddish,
+    Portuguese,
+}
+
+/// How Hebrew names are spelled in English.
+#[derive(Eq, PartialEq, Clone, Copy)]
+pub enum Transliteration {
+    /// Shabbos, Sukkos, Teves.
+    Ashkenazi,
+    /// Shabbat, Sukkot, Tevet.
+    Sephardi,
+    /// Shabbat, Sukkot, Tevet, with ḥ and kh (Ḥanukkah, Lekh Lekha).
+    Academic,
 }
 
 pub enum Command {
     Convert(ConvertArgs),
+    ConvertBatch(ConvertBatchArgs),
     List(ListArgs),
+    DayInfo(DayInfoArgs),
+    YearInfo(YearInfoArgs),
+    WeddingCheck(WeddingCheckArgs),
+    WeddingDates(WeddingDatesArgs),
+    Diff(DiffArgs),
+    Add(AddArgs),
 }
 
 #[derive(Eq, PartialEq, Copy, Clone)]
@@ -38,6 +72,32 @@ pub enum OutputType {
 pub struct ConvertArgs {
     pub date: ConvertType,
     pub language: Language,
+    /// The time of day the date was given with, in the city's time zone.
+    pub time: Option<NaiveTime>,
+    /// The city whose sunset decides which Hebrew date a time falls on.
+    pub city: Option<City>,
+    pub details: Option<ConvertDetails>,
+}
+
+pub struct ConvertBatchArgs {
+    /// The file with a date on every line, or the standard input if there isn't one.
+    pub input: Option<String>,
+    /// The column of a CSV file the dates are in, counting from 1.
+    pub column: Option<NonZeroUsize>,
+    /// Whether the first line is a header to skip.
+    pub header: bool,
+    pub datefmt: ConfigDateFmt,
+    pub date_type: ConfigDateType,
+    /// The city whose sunset decides which Hebrew date a time falls on.
+    pub city: Option<City>,
+    pub details: Option<ConvertDetails>,
+}
+
+/// What `convert --details` needs to find the events of a date.
+pub struct ConvertDetails {
+    pub location: Location,
+    pub walled_city: bool,
+    pub custom_days: Vec<CustomHoliday>,
 }
 
 #[derive(Debug)]
@@ -46,14 +106,102 @@ pub enum ConvertType {
     Hebrew(HebrewDate),
 }
 
+pub struct DayInfoArgs {
+    pub date: HebrewDate,
+    pub location: Location,
+    pub walled_city: bool,
+    pub minhag: Minhag,
+}
+
+pub struct YearInfoArgs {
+    pub year: u64,
+}
+
+pub struct WeddingCheckArgs {
+    pub date: HebrewDate,
+    pub location: Location,
+    pub minhag: Minhag,
+}
+
+pub struct WeddingDatesArgs {
+    pub from: HebrewDate,
+    pub to: HebrewDate,
+    pub location: Location,
+    pub minhag: Minhag,
+}
+
+pub struct DiffArgs {
+    pub from: HebrewDate,
+    pub to: HebrewDate,
+}
+
+pub struct AddArgs {
+    pub date: HebrewDate,
+    /// How many units to add. It's negative to go back.
+    pub amount: i64,
+    pub unit: Unit,
+}
+
+/// What `add` adds.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Unit {
+    Days,
+    Weeks,
+    /// Gregorian months.
+    Months,
+    /// Gregorian years.
+    Years,
+    /// Hebrew months, counting Adar I and Adar II as two months.
+    HebrewMonths,
+    HebrewYears,
+}
+
+/// heca_lib only knows about Israel and Chutz La'aretz, but Jerusalem (like every city that was
+/// walled in the days of Yehoshua) keeps Purim on the 15th of Adar.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Place {
+    Chul,
+    Israel,
+    Jerusalem,
+}
+
+impl Place {
+    pub fn location(self) -> Location {
+        match self {
+            Place::Chul => Location::Chul,
+            Place::Israel | Place::Jerusalem => Location::Israel,
+        }
+    }
+
+    pub fn is_walled_city(self) -> bool {
+        self == Place::Jerusalem
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Minhag {
+    Ashkenaz,
+    Sefard,
+    Chabad,
+    EdotHaMizrach,
+    Yemenite,
+}
+
 pub struct ListArgs {
     pub year: YearType,
     pub location: Location,
+    pub walled_city: bool,
     pub events: Vec<Event>,
     pub amnt_years: u64,
     pub no_sort: bool,
     pub exact_days: bool,
     pub city: Option<City>,
+    pub kiddush_levana_start: KiddushLevanaStart,
+    pub kiddush_levana_end: KiddushLevanaEnd,
+    pub aliyot: bool,
+    pub haftarah: bool,
+    pub minhag: Minhag,
+    pub triennial: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -65,6 +213,9 @@ pub enum Event {
     IsraeliHolidays,
     ChabadHolidays,
     ShabbosMevarchim,
+    KiddushLevana,
+    RareEvents,
+    MourningPeriods,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -98,6 +249,8 @@ pub struct DayMonth {
 pub enum YearType {
     Gregorian(u64),
     Hebrew(u64),
+    /// The days from one date through another.
+    Range(HebrewDate, HebrewDate),
 }
 
 #[derive(Clone, Debug)]
@@ -105,6 +258,25 @@ pub struct DayVal {
     pub day: chrono::DateTime<Utc>,
     pub name: Name,
     pub candle_lighting: Option<Option<DateTime<FixedOffset>>>,
+    pub reading: Option<Reading>,
+    pub haftarah: Option<Haftarah>,
+    pub other_location: Option<OtherLocation>,
+    pub triennial: Option<Triennial>,
+}
+
+impl DayVal {
+    /// A day with nothing to show besides its name. The rest is filled in by `list` as needed.
+    pub fn new(day: DateTime<Utc>, name: Name) -> DayVal {
+        DayVal {
+            day,
+            name,
+            candle_lighting: None,
+            reading: None,
+            haftarah: None,
+            other_location: None,
+            triennial: None,
+        }
+    }
 }
 
 impl Serialize for DayVal {
@@ -156,33 +328,13 @@ impl Serialize for DayVal {
             }
             Name::IsraeliHoliday(holiday) => {
                 state.serialize_field("type", "IsraeliHoliday")?;
-                match holiday {
-                    IsraeliHoliday::YomHaAtzmaut => {
-                        state.serialize_field("name", "YomHaAtzmaut")?
-                    }
-                    IsraeliHoliday::YomHaZikaron => {
-                        state.serialize_field("name", "YomHaZikaron")?
-                    }
-                    IsraeliHoliday::YomYerushalayim => {
-                        state.serialize_field("name", "YomYerushalayim")?
-                    }
-                    IsraeliHoliday::YomHaShoah => state.serialize_field("name", "YomHaShoah")?,
-                    IsraeliHoliday::YomHaAliyah => state.serialize_field("name", "YomHaAliyah")?,
-                    IsraeliHoliday::Sigd => state.serialize_field("name", "Sigd")?,
-                }
+                state.serialize_field("name", &format!("{:?}", holiday))?;
             }
             Name::ChabadHoliday(holiday) => {
                 state.serialize_field("type", "ChabadHoliday")?;
-                match holiday {
-                    ChabadHoliday::YudKislev => state.serialize_field("name", "YudKislev")?,
-                    ChabadHoliday::YudTesKislev => state.serialize_field("name", "YudTesKislev")?,
-                    ChabadHoliday::ChofKislev => state.serialize_field("name", "ChofKislev")?,
-                    ChabadHoliday::YudBeisTammuz => {
-                        state.serialize_field("name", "YudBeisTammuz")?
-                    }
-                    ChabadHoliday::YudGimmelTammuz => {
-                        state.serialize_field("name", "YudGimmelTammuz")?
-                    }
+                state.serialize_field("name", &format!("{:?}", holiday))?;
+                if let Some(kind) = holiday.kind() {
+                    state.serialize_field("kind", &kind)?;
                 }
             }
             Name::DailyStudy(daily_study) => {
@@ -213,8 +365,51 @@ impl Serialize for DayVal {
                 state.serialize_field("type", "ShabbosMevarchim")?;
                 state.serialize_field("month", &shabbos_mevarchim.hebrew_month)?;
                 state.serialize_field("molad", &shabbos_mevarchim)?;
+                state.serialize_field(
+                    "moladStandardTime",
+                    &shabbos_mevarchim.standard_time.naive_utc(),
+                )?;
+                state.serialize_field("roshChodesh", &shabbos_mevarchim.rosh_chodesh_json())?;
+            }
+            Name::KiddushLevana(kiddush_levana) => {
+                state.serialize_field("type", "KiddushLevana")?;
+                state.serialize_field("month", &kiddush_levana.hebrew_month)?;
+                state.serialize_field("window", &kiddush_levana)?;
+            }
+            Name::RareEvent(rare_event) => {
+                state.serialize_field("type", "RareEvent")?;
+                match rare_event {
+                    RareEvent::Tekufa(tekufa) => {
+                        state.serialize_field("name", "Tekufa")?;
+                        state.serialize_field("season", &format!("{:?}", tekufa.season))?;
+                        state.serialize_field("time", &tekufa.time.naive_utc())?;
+                    }
+                    RareEvent::BirkasHaChama => state.serialize_field("name", "BirkasHaChama")?,
+                    RareEvent::Hakhel => state.serialize_field("name", "Hakhel")?,
+                    RareEvent::ErevPesachOnShabbos => {
+                        state.serialize_field("name", "ErevPesachOnShabbos")?
+                    }
+                }
+            }
+            Name::MourningPeriod(mourning_period) => {
+                state.serialize_field("type", "MourningPeriod")?;
+                state.serialize_field("name", &format!("{:?}", mourning_period.period))?;
+                state.serialize_field("start", &self.day)?;
+                state.serialize_field("end", &mourning_period.end)?;
             }
         };
+        if let Some(reading) = &self.reading {
+            state.serialize_field("reading", reading)?;
+        }
+        if let Some(haftarah) = &self.haftarah {
+            state.serialize_field("haftarah", haftarah)?;
+        }
+        if let Some(other_location) = &self.other_location {
+            state.serialize_field("otherLocation", other_location)?;
+        }
+        if let Some(triennial) = &self.triennial {
+            state.serialize_field("triennial", triennial)?;
+        }
         state.end()
     }
 }
@@ -259,9 +454,8 @@ impl RambamThreeChapter {
 
 #[derive(Debug, Clone)]
 pub struct RambamChapter {
-    halacha_english: &'static str,
+    halacha: &'static str,
     halacha_json: &'static str,
-    halacha_hebrew: &'static str,
     chapter: u8,
 }
 
@@ -282,26 +476,23 @@ impl RambamChapter {
     pub fn from_days(day: u16) -> Self {
         let mut day = day;
         let mut index = 0;
-        let mut halacha_english;
+        let mut halacha;
         let mut halacha_json;
-        let mut halacha_hebrew;
 
         let chapter = loop {
-            halacha_english = RAMBAM[index].0;
-            halacha_hebrew = RAMBAM[index].1;
-            halacha_json = RAMBAM[index].2;
+            halacha = RAMBAM[index].0;
+            halacha_json = RAMBAM[index].1;
 
-            if day < (RAMBAM[index].3 as u16) {
+            if day < (RAMBAM[index].2 as u16) {
                 break day as u8 + 1;
             } else {
-                day -= RAMBAM[index].3 as u16;
+                day -= RAMBAM[index].2 as u16;
                 index += 1;
             }
         };
         Self {
-            halacha_english,
+            halacha,
             halacha_json,
-            halacha_hebrew,
             chapter,
         }
     }
@@ -311,24 +502,21 @@ impl RambamChapter {
         lock: &mut BufWriter<StdoutLock<'_>>,
         language: Language,
     ) -> Option<usize> {
-        let mut p = if language == Language::English {
-            lock.write(self.halacha_english.as_bytes()).ok()?
-        } else {
-            lock.write(self.halacha_hebrew.as_bytes()).ok()?
-        };
+        // Some halachos have the same name as a tractate of the Gemara (like Eruvin).
+        let halacha = format!("Rambam|{}", self.halacha);
+        let mut p = lock.write(language.tr(&halacha).as_bytes()).ok()?;
         p += lock.write(b" ").ok()?;
-        let mut daf_arr = [b'\0'; 3];
-        let count_y = itoa::write(&mut daf_arr[..], self.chapter).unwrap();
-        p += lock.write(&daf_arr[..count_y]).ok()?;
+        p += lock
+            .write(language.number(u64::from(self.chapter)).as_bytes())
+            .ok()?;
         Some(p)
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct YerushalmiYomi {
-    masechta_english: &'static str,
+    masechta: &'static str,
     masechta_json: &'static str,
-    masechta_hebrew: &'static str,
     daf: u8,
 }
 
@@ -336,26 +524,23 @@ impl YerushalmiYomi {
     pub fn from_days(day: u16) -> Self {
         let mut day = day;
         let mut index = 0;
-        let mut masechta_english;
+        let mut masechta;
         let mut masechta_json;
-        let mut masechta_hebrew;
 
         let daf = loop {
-            masechta_english = YERUSHALMI[index].0;
-            masechta_hebrew = YERUSHALMI[index].1;
-            masechta_json = YERUSHALMI[index].2;
+            masechta = YERUSHALMI[index].0;
+            masechta_json = YERUSHALMI[index].1;
 
-            if day < (YERUSHALMI[index].3 as u16) {
+            if day < (YERUSHALMI[index].2 as u16) {
                 break day as u8;
             } else {
-                day -= YERUSHALMI[index].3 as u16;
+                day -= YERUSHALMI[index].2 as u16;
                 index += 1;
             }
         };
         Self {
-            masechta_english,
+            masechta,
             masechta_json,
-            masechta_hebrew,
             daf,
         }
     }
@@ -364,15 +549,11 @@ impl YerushalmiYomi {
         lock: &mut BufWriter<StdoutLock<'_>>,
         language: Language,
     ) -> Option<usize> {
-        let mut p = if language == Language::English {
-            lock.write(self.masechta_english.as_bytes()).ok()?
-        } else {
-            lock.write(self.masechta_hebrew.as_bytes()).ok()?
-        };
+        let mut p = lock.write(language.tr(self.masechta).as_bytes()).ok()?;
         p += lock.write(b" ").ok()?;
-        let mut daf_arr = [b'\0'; 3];
-        let count_y = itoa::write(&mut daf_arr[..], self.daf + 1).unwrap();
-        p += lock.write(&daf_arr[..count_y]).ok()?;
+        p += lock
+            .write(language.number(u64::from(self.daf + 1)).as_bytes())
+            .ok()?;
         Some(p)
     }
 }
@@ -392,9 +573,8 @@ impl Serialize for YerushalmiYomi {
 
 #[derive(Debug, Clone)]
 pub struct Daf {
-    masechta_english: &'static str,
+    masechta: &'static str,
     masechta_json: &'static str,
-    masechta_hebrew: &'static str,
     daf: u8,
 }
 
@@ -412,32 +592,26 @@ impl Serialize for Daf {
 }
 
 impl Daf {
-    pub fn from_days(
-        day: u16,
-        gemaras: &[(&'static str, &'static str, &'static str, u8); 37],
-    ) -> Self {
+    pub fn from_days(day: u16, gemaras: &[(&'static str, &'static str, u8); 37]) -> Self {
         let mut day = day;
         let mut index = 0;
-        let mut masechta_english;
+        let mut masechta;
         let mut masechta_json;
-        let mut masechta_hebrew;
 
         let daf = loop {
-            masechta_english = gemaras[index].0;
-            masechta_hebrew = gemaras[index].1;
-            masechta_json = gemaras[index].2;
+            masechta = gemaras[index].0;
+            masechta_json = gemaras[index].1;
 
-            if day < (gemaras[index].3 as u16 - 1) {
+            if day < (gemaras[index].2 as u16 - 1) {
                 break day as u8;
             } else {
-                day -= gemaras[index].3 as u16 - 1;
+                day -= gemaras[index].2 as u16 - 1;
                 index += 1;
             }
         };
         Self {
-            masechta_english,
+            masechta,
             masechta_json,
-            masechta_hebrew,
             daf,
         }
     }
@@ -447,15 +621,11 @@ impl Daf {
         lock: &mut BufWriter<StdoutLock<'_>>,
         language: Language,
     ) -> Option<usize> {
-        let mut p = if language == Language::English {
-            lock.write(self.masechta_english.as_bytes()).ok()?
-        } else {
-            lock.write(self.masechta_hebrew.as_bytes()).ok()?
-        };
+        let mut p = lock.write(language.tr(self.masechta).as_bytes()).ok()?;
         p += lock.write(b" ").ok()?;
-        let mut daf_arr = [b'\0'; 3];
-        let count_y = itoa::write(&mut daf_arr[..], self.daf + 2).unwrap();
-        p += lock.write(&daf_arr[..count_y]).ok()?;
+        p += lock
+            .write(language.number(u64::from(self.daf + 2)).as_bytes())
+            .ok()?;
         Some(p)
     }
 }
@@ -469,6 +639,32 @@ pub enum Name {
     IsraeliHoliday(IsraeliHoliday),
     ChabadHoliday(ChabadHoliday),
     ShabbosMevarchim(ShabbosMevarchim),
+    KiddushLevana(KiddushLevana),
+    RareEvent(RareEvent),
+    MourningPeriod(MourningPeriod),
+}
+
+impl Name {
+    /// The JSON name of an event that's printed as just its title, which is what the config file's
+    /// `[strings]` table is keyed by. Events that print more than a title (like Shabbos Mevarchim's
+    /// molad) and custom holidays (which have their own titles) don't have one.
+    pub fn json_name(&self) -> Option<String> {
+        match self {
+            Name::TorahReading(TorahReading::YomTov(yt)) => Some(format!("{:?}", yt)),
+            Name::TorahReading(TorahReading::Chol(chol)) => Some(format!("{:?}", chol)),
+            Name::TorahReading(TorahReading::Shabbos(shabbos)) => Some(format!("{:?}", shabbos)),
+            Name::TorahReading(TorahReading::SpecialParsha(special_parsha)) => {
+                Some(format!("{:?}", special_parsha))
+            }
+            Name::MinorDays(days) => Some(format!("{:?}", days)),
+            Name::IsraeliHoliday(holiday) => Some(format!("{:?}", holiday)),
+            Name::ChabadHoliday(holiday) => Some(format!("{:?}", holiday)),
+            Name::RareEvent(RareEvent::BirkasHaChama) => Some("BirkasHaChama".into()),
+            Name::RareEvent(RareEvent::Hakhel) => Some("Hakhel".into()),
+            Name::RareEvent(RareEvent::ErevPesachOnShabbos) => Some("ErevPesachOnShabbos".into()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -533,18 +729,34 @@ pub enum MinorDays {
     FifteenAv,
     PurimKattan,
     ShushanPurimKattan,
+    PurimMeshulashMegillah,
+    PurimMeshulashAlHaNissim,
+    PurimMeshulashSeudah,
     ShabbosHaGadol,
     TaanisBechoros,
     ShabbosChazon,
     ShabbosNachamu,
     LeilSlichos,
     ShabbosShuva,
+    ShabbosRoshChodesh,
+    ShabbosMacharChodesh,
+    ShabbosShira,
+    ShabbosChanukah,
+    ShabbosChanukahSheni,
+    ShabbosCholHaMoedSukkos,
+    ShabbosCholHaMoedPesach,
+    ShabbosShekalim,
+    ShabbosZachor,
+    ShabbosParah,
+    ShabbosHaChodesh,
+    ShabbosMevarchim,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
 pub enum MinorHoliday {
     Omer,
     Minor,
+    SpecialShabbosos,
 }
 
 type Month = u32;
@@ -554,6 +766,8 @@ type Year = i32;
 #[derive(Debug)]
 pub enum AppError {
     LocationError(String),
+    MinhagError(String),
+    TransliterationError(String),
     DateSyntaxError(String),
     ConversionError(ConversionError),
     ArgError(clap::Error),
@@ -570,6 +784,18 @@ pub enum AppError {
     ReadError(String),
     TypeError(String),
     CityNotFound(String),
+    CannotParseTime(String),
+    TimeWithoutCity,
+    TimeWithHebrewDate,
+    NoSunset(String),
+    CannotParseAmount(String),
+    ColumnError(String),
+    YearTooLarge(i64),
+    DateNotFound(String),
+    DateOutOfRange(String),
+    ToBeforeFrom,
+    NoSuchColumn(usize),
+    LinesFailed(usize),
 }
 
 use clap::ErrorKind;
@@ -678,10 +904,63 @@ impl Serialize for AppError {
                 state.serialize_field("type", "LocationError")?;
                 state.serialize_field("error", e)?;
             }
+            AppError::MinhagError(e) => {
+                state.serialize_field("type", "MinhagError")?;
+                state.serialize_field("error", e)?;
+            }
+            AppError::TransliterationError(e) => {
+                state.serialize_field("type", "TransliterationError")?;
+                state.serialize_field("error", e)?;
+            }
             AppError::CityNotFound(e) => {
                 state.serialize_field("type", "CityNotFoundError")?;
                 state.serialize_field("error", e)?;
             }
+            AppError::CannotParseTime(time) => {
+                state.serialize_field("type", "CannotParseTime")?;
+                state.serialize_field("error", time)?;
+            }
+            AppError::TimeWithoutCity => {
+                state.serialize_field("type", "TimeWithoutCity")?;
+            }
+            AppError::TimeWithHebrewDate => {
+                state.serialize_field("type", "TimeWithHebrewDate")?;
+            }
+            AppError::NoSunset(city) => {
+                state.serialize_field("type", "NoSunset")?;
+                state.serialize_field("error", city)?;
+            }
+            AppError::CannotParseAmount(amount) => {
+                state.serialize_field("type", "CannotParseAmount")?;
+                state.serialize_field("error", amount)?;
+            }
+            AppError::ColumnError(column) => {
+                state.serialize_field("type", "ColumnError")?;
+                state.serialize_field("error", column)?;
+            }
+            AppError::YearTooLarge(year) => {
+                state.serialize_field("type", "YearTooLarge")?;
+                state.serialize_field("error", year)?;
+            }
+            AppError::DateNotFound(date) => {
+                state.serialize_field("type", "DateNotFound")?;
+                state.serialize_field("error", date)?;
+            }
+            AppError::DateOutOfRange(date) => {
+                state.serialize_field("type", "DateOutOfRange")?;
+                state.serialize_field("error", date)?;
+            }
+            AppError::ToBeforeFrom => {
+                state.serialize_field("type", "ToBeforeFrom")?;
+            }
+            AppError::NoSuchColumn(column) => {
+                state.serialize_field("type", "NoSuchColumn")?;
+                state.serialize_field("error", column)?;
+            }
+            AppError::LinesFailed(lines) => {
+                state.serialize_field("type", "LinesFailed")?;
+                state.serialize_field("error", lines)?;
+            }
         };
         state.end()
     }
@@ -735,7 +1014,17 @@ impl fmt::Display for AppError {
             AppError::ArgError(err) => write!(f, "{}", err),
             AppError::LocationError(e) => write!(
                 f,
-                "{} is not a valid location. Must be either \"Chul\" or \"Israel\"",
+                "{} is not a valid location. Must be one of \"Chul\", \"Israel\" or \"Jerusalem\"",
+                e
+            ),
+            AppError::MinhagError(e) => write!(
+                f,
+                "{} is not a valid minhag. Must be one of \"ashkenaz\", \"sefard\", \"chabad\", \"edot-hamizrach\" or \"yemenite\"",
+                e
+            ),
+            AppError::TransliterationError(e) => write!(
+                f,
+                "{} is not a valid transliteration. Must be one of \"ashkenazi\", \"sephardi\" or \"academic\"",
                 e
             ),
             AppError::CityNotFound(e) => {
@@ -751,6 +1040,41 @@ impl fmt::Display for AppError {
                     e, list_of_city_names
                 )
             }
+            AppError::CannotParseTime(time) => write!(
+                f,
+                "Cannot parse time {}. The time must be in the format of HH:MM",
+                time
+            ),
+            AppError::TimeWithoutCity => write!(
+                f,
+                "A time needs a city (with --city, HECA_CITY or default_city in the config file) to know when sunset is"
+            ),
+            AppError::TimeWithHebrewDate => write!(
+                f,
+                "A time can only be given with a Gregorian date, as a Hebrew date starts at sunset"
+            ),
+            AppError::NoSunset(city) => write!(f, "There is no sunset in {} on that day", city),
+            AppError::CannotParseAmount(amount) => write!(
+                f,
+                "Cannot parse amount {}. It must be a whole number, like 12, +12 or -12",
+                amount
+            ),
+            AppError::ColumnError(column) => write!(
+                f,
+                "{} is not a valid column. It must be a number above 0",
+                column
+            ),
+            AppError::YearTooLarge(year) => {
+                write!(f, "The year {} is too far in the future to calculate", year)
+            }
+            AppError::DateNotFound(date) => write!(f, "Cannot find the date {}", date),
+            AppError::DateOutOfRange(date) => {
+                write!(f, "{} is out of the range of dates that can be calculated", date)
+            }
+            AppError::ToBeforeFrom => write!(f, "--to is before --from"),
+            AppError::NoSuchColumn(column) => write!(f, "The line has no column {}", column),
+            AppError::LinesFailed(1) => write!(f, "1 line couldn't be converted"),
+            AppError::LinesFailed(lines) => write!(f, "{} lines couldn't be converted", lines),
         }
     }
 }
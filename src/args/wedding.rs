@@ -0,0 +1,42 @@
+use crate::args::convert::parse_hebrew_day;
+use crate::args::prelude::{get_location, get_minhag, Config, ConfigDateFmt, ConfigDateType};
+use crate::args::types::{AppError, Command, Language, WeddingCheckArgs, WeddingDatesArgs};
+use clap::ArgMatches;
+
+pub fn parse_check_options(
+    matches: &ArgMatches<'_>,
+    config: &Config,
+    language: Language,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<Command, AppError> {
+    let date = parse_hebrew_day(matches.value_of("Date").unwrap(), datefmt, date_type)?;
+    let location = get_location(matches.value_of("Location"), config, language)?.location();
+    let minhag = get_minhag(matches.value_of("Minhag"))?;
+
+    Ok(Command::WeddingCheck(WeddingCheckArgs {
+        date,
+        location,
+        minhag,
+    }))
+}
+
+pub fn parse_dates_options(
+    matches: &ArgMatches<'_>,
+    config: &Config,
+    language: Language,
+    datefmt: ConfigDateFmt,
+    date_type: ConfigDateType,
+) -> Result<Command, AppError> {
+    let from = parse_hebrew_day(matches.value_of("From").unwrap(), datefmt, date_type)?;
+    let to = parse_hebrew_day(matches.value_of("To").unwrap(), datefmt, date_type)?;
+    let location = get_location(matches.value_of("Location"), config, language)?.location();
+    let minhag = get_minhag(matches.value_of("Minhag"))?;
+
+    Ok(Command::WeddingDates(WeddingDatesArgs {
+        from,
+        to,
+        location,
+        minhag,
+    }))
+}
@@ -0,0 +1,10 @@
+use crate::args::types::{AppError, Command, YearInfoArgs};
+use clap::ArgMatches;
+
+pub fn parse_options(matches: &ArgMatches<'_>) -> Result<Command, AppError> {
+    let year = matches.value_of("Year").unwrap();
+    let year = year
+        .parse()
+        .map_err(|_| AppError::CannotParseYear(year.into()))?;
+    Ok(Command::YearInfo(YearInfoArgs { year }))
+}
@@ -1,18 +1,156 @@
-use crate::args::types::{AppError, ConvertArgs, ConvertType, Language, MainArgs, OutputType};
+use crate::algorithms::candle_lighting::City;
+use crate::algorithms::kiddush_levana::{KiddushLevanaEnd, KiddushLevanaStart};
+use crate::args::convert::{parse_date, split_time};
+use crate::args::types::{
+    AppError, ConvertArgs, ConvertBatchArgs, ConvertDetails, ConvertType, DailyStudy, DayVal,
+    Event, Language, ListArgs, MainArgs, Minhag, MinorHoliday, Name, OutputType, RambamChapters,
+    Transliteration, YearType,
+};
+use crate::list::{write_title, Titled};
 use crate::prelude::print;
 use crate::Runnable;
 use chrono::prelude::*;
 use chrono::Duration;
 use either::Either;
+use heca_lib::prelude::{TorahReading, TorahReadingType};
 use heca_lib::HebrewDate;
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
 use std::convert::TryInto;
+use std::fs::File;
+use std::io::{stdin, stdout, BufRead, BufReader, BufWriter, StdoutLock, Write};
 
 #[derive(Debug)]
 pub struct Return {
     pub day: Either<[chrono::DateTime<Utc>; 2], [HebrewDate; 2]>,
     pub orig_day: Either<HebrewDate, chrono::DateTime<Utc>>,
+    /// The time of day a Gregorian date was given with, which picks one of the two Hebrew dates.
+    pub at: Option<AtTime>,
+    pub details: Option<Details>,
+}
+
+#[derive(Debug)]
+pub struct AtTime {
+    pub time: NaiveTime,
+    pub city: String,
+    pub after_sunset: bool,
+}
+
+/// What a Hebrew date is, for `--details`.
+#[derive(Debug)]
+pub struct Details {
+    pub day: HebrewDate,
+    pub weekday: Weekday,
+    pub events: Vec<DayVal>,
+    /// The reading of this week's Shabbos, which is the Yom Tov's when one falls on it.
+    pub parsha: Option<DayVal>,
+    pub daily_study: Vec<DayVal>,
+}
+
+impl Details {
+    fn new(day: HebrewDate, details: &ConvertDetails) -> Result<Self, AppError> {
+        let mut events = vec![
+            Event::TorahReadingType(TorahReadingType::YomTov),
+            Event::TorahReadingType(TorahReadingType::Chol),
+            Event::TorahReadingType(TorahReadingType::Shabbos),
+            Event::TorahReadingType(TorahReadingType::SpecialParsha),
+            Event::MinorHoliday(MinorHoliday::Omer),
+            Event::MinorHoliday(MinorHoliday::Minor),
+            Event::MinorHoliday(MinorHoliday::SpecialShabbosos),
+            Event::IsraeliHolidays,
+            Event::ShabbosMevarchim,
+            Event::RareEvents,
+            Event::DailyStudy(DailyStudy::DafYomi),
+            Event::DailyStudy(DailyStudy::YerushalmiYomi),
+            Event::DailyStudy(DailyStudy::Rambam(RambamChapters::Three)),
+            Event::DailyStudy(DailyStudy::Rambam(RambamChapters::One)),
+        ];
+        events.extend(
+            details
+                .custom_days
+                .iter()
+                .map(|x| Event::CustomHoliday(x.clone())),
+        );
+        // The next parsha can be in the next year.
+        let days = ListArgs {
+            year: YearType::Hebrew(day.year()),
+            location: details.location,
+            walled_city: details.walled_city,
+            events,
+            amnt_years: 2,
+            // Keeps the events of a day in the order they're asked for.
+            no_sort: true,
+            exact_days: false,
+            city: None,
+            kiddush_levana_start: KiddushLevanaStart::ThreeDays,
+            kiddush_levana_end: KiddushLevanaEnd::HalfMonth,
+            aliyot: false,
+            haftarah: false,
+            minhag: Minhag::Ashkenaz,
+            triennial: false,
+        }
+        .get_days()?
+        .into_iter()
+        // There's no city to light candles in.
+        .map(|x| DayVal {
+            candle_lighting: None,
+            ..x
+        })
+        .collect::<Vec<_>>();
+
+        let first_day: DateTime<Utc> = day.into();
+        let weekday = (first_day + Duration::days(1)).weekday();
+        let shabbos = first_day + Duration::days(6 - i64::from(weekday.num_days_from_sunday()));
+        let is_parsha = |x: &DayVal| matches!(x.name, Name::TorahReading(TorahReading::Shabbos(_)));
+        let is_yom_tov = |x: &DayVal| matches!(x.name, Name::TorahReading(TorahReading::YomTov(_)));
+        // A Yom Tov or Chol HaMoed on Shabbos is read in place of the parsha.
+        let parsha = days
+            .iter()
+            .find(|x| x.day == shabbos && is_parsha(x))
+            .or_else(|| days.iter().find(|x| x.day == shabbos && is_yom_tov(x)))
+            .cloned();
+        let (daily_study, events) = days
+            .into_iter()
+            .filter(|x| x.day == first_day && !is_parsha(x))
+            .partition(|x| matches!(x.name, Name::DailyStudy(_)));
+        Ok(Details {
+            day,
+            weekday,
+            events,
+            parsha,
+            daily_study,
+        })
+    }
+}
+
+/// `convert --details` in JSON.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DetailsJson<'a> {
+    dates: &'a Return,
+    day: HebrewDate,
+    weekday: &'static str,
+    events: Vec<Titled<'a>>,
+    parsha: Option<Titled<'a>>,
+    daily_study: Vec<Titled<'a>>,
+}
+
+/// What a date is converted to in JSON.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Json<'a> {
+    Dates(&'a Return),
+    Details(DetailsJson<'a>),
+}
+
+impl Return {
+    /// The Hebrew date at the given time, if there was one.
+    fn hebrew_day_at(&self) -> Option<HebrewDate> {
+        match (&self.at, self.day) {
+            (Some(at), Either::Right(day)) => Some(day[at.after_sunset as usize]),
+            _ => None,
+        }
+    }
 }
 
 impl Serialize for Return {
@@ -20,6 +158,9 @@ impl Serialize for Return {
     where
         S: Serializer,
     {
+        if let Some(day) = self.hebrew_day_at() {
+            return day.serialize(serializer);
+        }
         match self.day {
             Either::Left(val) => serialize_array(val, serializer),
             Either::Right(val) => serialize_array(val, serializer),
@@ -40,89 +181,293 @@ where
 }
 
 impl Return {
-    fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
-        match args.language {
-            Language::English => match self.orig_day {
-                Either::Right(r) => println!(
-                    "{}: From {} {} {} to {} {} {}.",
-                    r.format("%A %B %-d %Y"),
-                    self.day.right().unwrap()[0].day(),
-                    print::hebrew_month_english(self.day.right().unwrap()[0].month()),
-                    self.day.right().unwrap()[0].year(),
-                    self.day.right().unwrap()[1].day(),
-                    print::hebrew_month_english(self.day.right().unwrap()[1].month()),
-                    self.day.right().unwrap()[1].year(),
-                ),
-                Either::Left(l) => println!(
-                    "{} {} {} -> From sunset {} to sunset {}.",
-                    l.day(),
-                    print::hebrew_month_english(l.month()),
-                    l.year(),
-                    self.day.left().unwrap()[0].format("%A %B %-d %Y"),
-                    self.day.left().unwrap()[1].format("%A %B %-d %Y"),
-                ),
-            },
-            Language::Hebrew => match self.orig_day {
-                Either::Right(r) => println!(
-                    "{}: {} {} {} - {} {} {}.",
-                    r.format("%A %B %-d %Y"),
-                    self.day.right().unwrap()[0].day(),
-                    print::hebrew_month_hebrew(self.day.right().unwrap()[0].month()),
-                    self.day.right().unwrap()[0].year(),
-                    self.day.right().unwrap()[1].day(),
-                    print::hebrew_month_hebrew(self.day.right().unwrap()[1].month()),
-                    self.day.right().unwrap()[1].year(),
-                ),
-                Either::Left(l) => println!(
-                    "{} {} {}: {} - {}.",
-                    l.day(),
-                    print::hebrew_month_hebrew(l.month()),
-                    l.year(),
-                    self.day.left().unwrap()[0].format("%A %B %-d %Y"),
-                    self.day.left().unwrap()[1].format("%A %B %-d %Y"),
+    fn pretty_print(
+        &self,
+        out: &mut BufWriter<StdoutLock<'_>>,
+        args: &MainArgs,
+    ) -> Result<(), AppError> {
+        let language = args.language;
+        match self.orig_day {
+            Either::Right(r) if self.at.is_some() => {
+                let at = self.at.as_ref().unwrap();
+                writeln!(
+                    out,
+                    "{}",
+                    language.tr_format(
+                        "{} at {} in {}: {}.",
+                        &[
+                            &print::gregorian_date(&r, "%A %B %-d %Y", language),
+                            &at.time.format("%H:%M").to_string(),
+                            &at.city,
+                            &print::hebrew_date(
+                                self.hebrew_day_at().unwrap(),
+                                language,
+                                args.thousands
+                            ),
+                        ],
+                    )
+                )
+            }
+            Either::Right(r) => writeln!(
+                out,
+                "{}",
+                language.tr_format(
+                    "{}: From {} to {}.",
+                    &[
+                        &print::gregorian_date(&r, "%A %B %-d %Y", language),
+                        &print::hebrew_date(self.day.right().unwrap()[0], language, args.thousands),
+                        &print::hebrew_date(self.day.right().unwrap()[1], language, args.thousands),
+                    ],
+                )
+            ),
+            Either::Left(l) => writeln!(
+                out,
+                "{}",
+                language.tr_format(
+                    "{} -> From sunset {} to sunset {}.",
+                    &[
+                        &print::hebrew_date(l, language, args.thousands),
+                        &print::gregorian_date(
+                            &self.day.left().unwrap()[0],
+                            "%A %B %-d %Y",
+                            language
+                        ),
+                        &print::gregorian_date(
+                            &self.day.left().unwrap()[1],
+                            "%A %B %-d %Y",
+                            language
+                        ),
+                    ],
+                )
+            ),
+        }?;
+        if let Some(details) = &self.details {
+            let day = print::hebrew_date(details.day, language, args.thousands);
+            let weekday = print::weekday(details.weekday, language);
+            let line = match details.parsha.as_ref().map(|x| &x.name) {
+                Some(Name::TorahReading(parsha)) => language.tr_format(
+                    "{}: {}. This week's parsha: {}.",
+                    &[&day, &weekday, &print::torah_reading(*parsha, language)],
                 ),
-            },
-        };
+                _ => format!("{}: {}.", day, weekday),
+            };
+            writeln!(out, "{}", line)?;
+            for event in details.events.iter().chain(&details.daily_study) {
+                write!(out, "  ")?;
+                write_title(out, event, args);
+                writeln!(out)?;
+            }
+        }
         Ok(())
     }
-    fn json_print(&self) -> Result<(), AppError> {
-        match &self.day {
-            Either::Right(r) => println!("{}", serde_json::to_string(&r).unwrap()),
-            Either::Left(r) => println!("{}", serde_json::to_string(&r).unwrap()),
-        };
+    fn json_print(&self, out: &mut impl Write, args: &MainArgs) -> Result<(), AppError> {
+        writeln!(out, "{}", serde_json::to_string(&self.json(args)).unwrap())?;
         Ok(())
     }
+
+    fn json<'a>(&'a self, args: &'a MainArgs) -> Json<'a> {
+        match &self.details {
+            Some(details) => Json::Details(DetailsJson {
+                dates: self,
+                day: details.day,
+                weekday: print::weekday(
+                    details.weekday,
+                    Language::English(Transliteration::Ashkenazi),
+                ),
+                events: details
+                    .events
+                    .iter()
+                    .map(|x| Titled::new(x, args))
+                    .collect(),
+                parsha: details.parsha.as_ref().map(|x| Titled::new(x, args)),
+                daily_study: details
+                    .daily_study
+                    .iter()
+                    .map(|x| Titled::new(x, args))
+                    .collect(),
+            }),
+            None => Json::Dates(self),
+        }
+    }
 }
 
 impl Return {
-    fn print(&self, args: &MainArgs) -> Result<(), AppError> {
+    fn print(&self, out: &mut BufWriter<StdoutLock<'_>>, args: &MainArgs) -> Result<(), AppError> {
         match args.output_type {
-            OutputType::JSON => self.json_print(),
-            OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
+            OutputType::JSON => self.json_print(out, args),
+            OutputType::Pretty | OutputType::Regular => self.pretty_print(out, args),
         }
     }
 }
 
-impl Runnable for ConvertArgs {
-    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
-        let ret = match self.date {
+impl Return {
+    /// Converts the date. If it was given with a time, the city's sunset decides which of the two
+    /// Hebrew dates the time falls on.
+    fn new(
+        date: &ConvertType,
+        time: Option<NaiveTime>,
+        city: Option<&City>,
+        details: Option<&ConvertDetails>,
+    ) -> Result<Self, AppError> {
+        let mut ret = match *date {
             ConvertType::Gregorian(date) => Return {
                 orig_day: Either::Right(date.and_hms(0, 0, 1)),
                 day: Either::Right([
                     date.and_hms(0, 0, 1).try_into()?,
                     date.and_hms(23, 0, 1).try_into()?,
                 ]),
+                at: match time {
+                    Some(time) => {
+                        use zmanim::prelude::Zmanim;
+                        let city = city.ok_or(AppError::TimeWithoutCity)?;
+                        let sunset = zmanim::get(
+                            &Zmanim::Sunset,
+                            city.latitude,
+                            city.longitude,
+                            date.naive_utc(),
+                            &city.time_zone,
+                        )
+                        .ok_or_else(|| AppError::NoSunset(city.name.to_string()))?;
+                        Some(AtTime {
+                            time,
+                            city: city.name.to_string(),
+                            after_sunset: time >= sunset.time(),
+                        })
+                    }
+                    None => None,
+                },
+                details: None,
             },
+            ConvertType::Hebrew(_) if time.is_some() => return Err(AppError::TimeWithHebrewDate),
             ConvertType::Hebrew(date) => Return {
                 orig_day: Either::Left(date),
                 day: Either::Left({
                     let first_day: DateTime<Utc> = date.into();
                     [first_day, first_day + Duration::days(1)]
                 }),
+                at: None,
+                details: None,
             },
         };
+        if let Some(details) = details {
+            let day = match (ret.hebrew_day_at(), ret.orig_day, ret.day) {
+                (Some(day), _, _) => day,
+                (None, Either::Left(day), _) => day,
+                (None, _, Either::Right(day)) => day[0],
+                _ => unreachable!(),
+            };
+            ret.details = Some(Details::new(day, details)?);
+        }
+        Ok(ret)
+    }
+}
+
+impl Runnable for ConvertArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let stdout = stdout();
+        let mut lock = BufWriter::new(stdout.lock());
+        Return::new(
+            &self.date,
+            self.time,
+            self.city.as_ref(),
+            self.details.as_ref(),
+        )?
+        .print(&mut lock, args)
+    }
+}
 
-        ret.print(args)?;
+/// A line of a batch as it's printed in JSON: what it was converted to, or why it couldn't be.
+#[derive(Serialize)]
+struct Line<'a> {
+    line: usize,
+    input: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    converted: Option<Json<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a AppError>,
+}
+
+impl Runnable for ConvertBatchArgs {
+    /// Converts every line, printing the lines that can't be converted (with their line numbers)
+    /// instead of stopping at them, and fails at the end if there were any. Blank lines are
+    /// skipped. In JSON, every line is printed as a line of its own.
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let stdin = stdin();
+        let mut input: Box<dyn BufRead> = match &self.input {
+            Some(path) => Box::new(BufReader::new(File::open(path)?)),
+            None => Box::new(stdin.lock()),
+        };
+        let stdout = stdout();
+        let mut lock = BufWriter::new(stdout.lock());
+        let mut line = Vec::new();
+        let mut failed = 0;
+        for index in 0.. {
+            line.clear();
+            if input.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+            if (index == 0 && self.header) || line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let field = match self.column {
+                Some(column) => csv::ReaderBuilder::new()
+                    .has_headers(false)
+                    .flexible(true)
+                    .from_reader(line.as_slice())
+                    .byte_records()
+                    .next()
+                    .and_then(Result::ok)
+                    .and_then(|record| record.get(column.get() - 1).map(<[u8]>::to_vec))
+                    .ok_or(AppError::NoSuchColumn(column.get())),
+                None => Ok(line.clone()),
+            };
+            let text = String::from_utf8_lossy(field.as_ref().unwrap_or(&line)).into_owned();
+            let date = text.trim();
+            let ret = field
+                .and_then(|field| {
+                    std::str::from_utf8(&field)
+                        .map(|_| ())
+                        .map_err(|_| AppError::ReadError("the line isn't valid UTF-8".into()))
+                })
+                .and_then(|_| split_time(date))
+                .and_then(|(date, time)| {
+                    Return::new(
+                        &parse_date(date, self.datefmt, self.date_type)?,
+                        time,
+                        self.city.as_ref(),
+                        self.details.as_ref(),
+                    )
+                });
+            if let Err(error) = &ret {
+                failed += 1;
+                if args.output_type != OutputType::JSON {
+                    lock.flush()?;
+                    eprintln!("Line {} ({}): {}", index + 1, date, error);
+                }
+            }
+            match args.output_type {
+                OutputType::JSON => writeln!(
+                    lock,
+                    "{}",
+                    serde_json::to_string(&Line {
+                        line: index + 1,
+                        input: date,
+                        converted: ret.as_ref().ok().map(|ret| ret.json(args)),
+                        error: ret.as_ref().err(),
+                    })
+                    .unwrap()
+                )?,
+                OutputType::Pretty | OutputType::Regular => {
+                    if let Ok(ret) = ret {
+                        ret.print(&mut lock, args)?;
+                    }
+                }
+            }
+        }
+        lock.flush()?;
+        if failed > 0 {
+            return Err(AppError::LinesFailed(failed));
+        }
         Ok(())
     }
 }
@@ -0,0 +1,89 @@
+use crate::algorithms::hallel::{self, Hallel};
+use crate::algorithms::insertions::{self, Insertions, Rain};
+use crate::algorithms::tachanun::{self, Tachanun};
+use crate::args::types::{AppError, DayInfoArgs, Language, MainArgs, OutputType};
+use crate::prelude::print;
+use crate::Runnable;
+use heca_lib::HebrewDate;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct Return {
+    pub day: HebrewDate,
+    pub tachanun: Tachanun,
+    pub hallel: Hallel,
+    pub insertions: Insertions,
+}
+
+impl Return {
+    fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
+        let tachanun = match (self.tachanun.shacharis, self.tachanun.mincha) {
+            (true, true) => "Tachanun is said at Shacharis and Mincha.",
+            (true, false) => "Tachanun is said at Shacharis but not at Mincha.",
+            _ => "Tachanun is not said.",
+        };
+        let hallel = match self.hallel {
+            Hallel::Full => "Full Hallel is said.",
+            Hallel::Half => "Half Hallel is said.",
+            Hallel::None => "Hallel is not said.",
+        };
+        println!(
+            "{}",
+            args.language.tr_format(
+                "{}: {} {} Said in the Amidah: {}.",
+                &[
+                    &print::hebrew_date(self.day, args.language, args.thousands),
+                    &args.language.tr(tachanun),
+                    &args.language.tr(hallel),
+                    &self.insertions_list(args.language).join(", "),
+                ],
+            )
+        );
+        Ok(())
+    }
+
+    fn insertions_list(&self, language: Language) -> Vec<&'static str> {
+        let mut list = vec![];
+        if self.insertions.yaaleh_veyavo {
+            list.push(language.tr("Yaaleh VeYavo"));
+        }
+        if self.insertions.al_hanissim {
+            list.push(language.tr("Al HaNissim"));
+        }
+        match self.insertions.mashiv_haruach {
+            Rain::MashivHaRuach => list.push(language.tr("Mashiv HaRuach")),
+            Rain::MoridHaTal => list.push(language.tr("Morid HaTal")),
+            Rain::None => {}
+        };
+        if self.insertions.tal_umatar {
+            list.push(language.tr("V'sen Tal uMatar"));
+        }
+        list
+    }
+
+    fn json_print(&self) -> Result<(), AppError> {
+        println!("{}", serde_json::to_string(&self).unwrap());
+        Ok(())
+    }
+
+    fn print(&self, args: &MainArgs) -> Result<(), AppError> {
+        match args.output_type {
+            OutputType::JSON => self.json_print(),
+            OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
+        }
+    }
+}
+
+impl Runnable for DayInfoArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let ret = Return {
+            day: self.date,
+            tachanun: tachanun::get(self.date, self.minhag),
+            hallel: hallel::get(self.date, self.minhag, self.location),
+            insertions: insertions::get(self.date, self.minhag, self.location, self.walled_city),
+        };
+
+        ret.print(args)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,350 @@
+//! Arithmetic on dates: how far apart two dates are (`diff`), and what date comes a number of
+//! days, weeks, months or years after another (`add`).
+//!
+//! Hebrew months are counted the way they come in the calendar, so a leap year has 13 of them.
+//! When adding Hebrew years, Adar becomes Adar 2 in a leap year, and Adar 1 and Adar 2 become
+//! Adar in a regular year, as with a Bar Mitzvah. A day that the month doesn't have (the 30th of
+//! a short month, or the 31st of a Gregorian month) becomes the month's last day.
+
+use crate::args::types::{AddArgs, AppError, DiffArgs, MainArgs, OutputType, Unit};
+use crate::prelude::print;
+use crate::Runnable;
+use chrono::prelude::*;
+use chrono::Duration;
+use heca_lib::prelude::{ConversionError, HebrewMonth};
+use heca_lib::{HebrewDate, HebrewYear};
+use serde::Serialize;
+use std::convert::TryInto;
+use std::num::NonZeroI8;
+
+const MONTHS: [HebrewMonth; 12] = [
+    HebrewMonth::Tishrei,
+    HebrewMonth::Cheshvan,
+    HebrewMonth::Kislev,
+    HebrewMonth::Teves,
+    HebrewMonth::Shvat,
+    HebrewMonth::Adar,
+    HebrewMonth::Nissan,
+    HebrewMonth::Iyar,
+    HebrewMonth::Sivan,
+    HebrewMonth::Tammuz,
+    HebrewMonth::Av,
+    HebrewMonth::Elul,
+];
+
+const LEAP_MONTHS: [HebrewMonth; 13] = [
+    HebrewMonth::Tishrei,
+    HebrewMonth::Cheshvan,
+    HebrewMonth::Kislev,
+    HebrewMonth::Teves,
+    HebrewMonth::Shvat,
+    HebrewMonth::Adar1,
+    HebrewMonth::Adar2,
+    HebrewMonth::Nissan,
+    HebrewMonth::Iyar,
+    HebrewMonth::Sivan,
+    HebrewMonth::Tammuz,
+    HebrewMonth::Av,
+    HebrewMonth::Elul,
+];
+
+/// The last Hebrew year that ends before the last day chrono can hold (in 262143 CE).
+const LAST_YEAR: i64 = 265_900;
+
+fn hebrew_year(year: i64) -> Result<HebrewYear, AppError> {
+    if year > LAST_YEAR {
+        return Err(AppError::YearTooLarge(year));
+    }
+    Ok(HebrewYear::new(year.max(0) as u64)?)
+}
+
+/// The months of a Hebrew year, in order.
+fn months_of(year: i64) -> Result<&'static [HebrewMonth], AppError> {
+    if hebrew_year(year)?.is_leap_year() {
+        Ok(&LEAP_MONTHS)
+    } else {
+        Ok(&MONTHS)
+    }
+}
+
+/// Builds a Hebrew date, moving the 30th to the 29th if the month is short.
+fn hebrew_ymd(year: i64, month: HebrewMonth, day: i8) -> Result<HebrewDate, AppError> {
+    let year = hebrew_year(year)?;
+    match year.get_hebrew_date(month, NonZeroI8::new(day).unwrap()) {
+        Err(ConversionError::TooManyDaysInMonth(_)) if day == 30 => {
+            Ok(year.get_hebrew_date(month, NonZeroI8::new(29).unwrap())?)
+        }
+        x => Ok(x?),
+    }
+}
+
+fn add_hebrew_months(date: HebrewDate, amount: i64) -> Result<HebrewDate, AppError> {
+    let position = (months_of(date.year() as i64)?
+        .iter()
+        .position(|x| *x == date.month())
+        .unwrap() as i64)
+        .saturating_add(amount);
+    // Every 19 years have 235 months, so whole cycles are skipped at once. A year that this
+    // saturates is out of range anyway.
+    let mut year = (date.year() as i64).saturating_add(position.div_euclid(235) * 19);
+    let mut position = position.rem_euclid(235);
+    let mut months = months_of(year)?;
+    while position >= months.len() as i64 {
+        position -= months.len() as i64;
+        year += 1;
+        months = months_of(year)?;
+    }
+    hebrew_ymd(year, months[position as usize], date.day().get())
+}
+
+fn add_hebrew_years(date: HebrewDate, amount: i64) -> Result<HebrewDate, AppError> {
+    let year = (date.year() as i64).saturating_add(amount);
+    let leap = months_of(year)?.len() == 13;
+    let month = match date.month() {
+        HebrewMonth::Adar if leap => HebrewMonth::Adar2,
+        HebrewMonth::Adar1 | HebrewMonth::Adar2 if !leap => HebrewMonth::Adar,
+        x => x,
+    };
+    hebrew_ymd(year, month, date.day().get())
+}
+
+fn add_gregorian_months(date: NaiveDate, amount: i64) -> Result<NaiveDate, AppError> {
+    let months = (date.year() as i64 * 12 + date.month0() as i64)
+        .checked_add(amount)
+        .ok_or_else(|| out_of_range(date, amount / 12))?;
+    let year = months
+        .div_euclid(12)
+        .try_into()
+        .map_err(|_| out_of_range(date, amount / 12))?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    let last_day = (28..=31)
+        .rev()
+        .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())
+        .ok_or_else(|| out_of_range(date, amount / 12))?;
+    Ok(NaiveDate::from_ymd(year, month, date.day().min(last_day)))
+}
+
+fn add_days(date: NaiveDate, amount: i64) -> Result<NaiveDate, AppError> {
+    (date.num_days_from_ce() as i64)
+        .checked_add(amount)
+        .and_then(|x| x.try_into().ok())
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or_else(|| out_of_range(date, amount / 365))
+}
+
+/// The error for going `years` Gregorian years from `date`, past the days that can be calculated.
+fn out_of_range(date: NaiveDate, years: i64) -> AppError {
+    if years > 0 {
+        AppError::YearTooLarge((date.year() as i64 + 3760).saturating_add(years))
+    } else {
+        AppError::ConversionError(ConversionError::YearTooSmall)
+    }
+}
+
+/// The Gregorian day on which most of a Hebrew day falls.
+fn gregorian_day(date: HebrewDate) -> Result<NaiveDate, AppError> {
+    let year = date.year() as i64;
+    if year > LAST_YEAR {
+        return Err(AppError::YearTooLarge(year));
+    }
+    DateTime::<Utc>::from(date)
+        .checked_add_signed(Duration::days(1))
+        .map(|x| x.naive_utc().date())
+        .ok_or(AppError::YearTooLarge(year))
+}
+
+fn hebrew_day(date: NaiveDate) -> Result<HebrewDate, AppError> {
+    Ok(Utc.from_utc_date(&date).and_hms(0, 0, 1).try_into()?)
+}
+
+#[derive(Debug, Serialize)]
+pub struct Span {
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+}
+
+/// Counts whole years, then whole months, then the days left, from the earlier date to the later
+/// one. The counts are negative if `from` is after `to`.
+fn span<T: Copy + Ord>(
+    from: T,
+    to: T,
+    add_years: impl Fn(T, i64) -> Result<T, AppError>,
+    add_months: impl Fn(T, i64) -> Result<T, AppError>,
+    days_between: impl Fn(T, T) -> Result<i64, AppError>,
+) -> Result<Span, AppError> {
+    let (first, last, sign) = if from <= to {
+        (from, to, 1)
+    } else {
+        (to, from, -1)
+    };
+    let mut years = 0;
+    while matches!(add_years(first, years + 1), Ok(x) if x <= last) {
+        years += 1;
+    }
+    let first = add_years(first, years)?;
+    let mut months = 0;
+    while matches!(add_months(first, months + 1), Ok(x) if x <= last) {
+        months += 1;
+    }
+    let first = add_months(first, months)?;
+    Ok(Span {
+        years: sign * years,
+        months: sign * months,
+        days: sign * days_between(first, last)?,
+    })
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiffReturn {
+    pub from: HebrewDate,
+    pub to: HebrewDate,
+    pub hebrew: Span,
+    pub gregorian: Span,
+    pub days: i64,
+}
+
+impl DiffReturn {
+    fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
+        let date = |x: HebrewDate| -> Result<String, AppError> {
+            Ok(format!(
+                "{} ({})",
+                print::hebrew_date(x, args.language, args.thousands),
+                print::gregorian_date(
+                    &Utc.from_utc_date(&gregorian_day(x)?).and_hms(0, 0, 0),
+                    "%B %-d %Y",
+                    args.language
+                )
+            ))
+        };
+        println!("{} - {}:", date(self.from)?, date(self.to)?);
+        for (calendar, span) in &[
+            (
+                "Hebrew calendar: years: {}, months: {}, days: {}.",
+                &self.hebrew,
+            ),
+            (
+                "Gregorian calendar: years: {}, months: {}, days: {}.",
+                &self.gregorian,
+            ),
+        ] {
+            println!(
+                "{}",
+                args.language.tr_format(
+                    calendar,
+                    &[
+                        &span.years.to_string(),
+                        &span.months.to_string(),
+                        &span.days.to_string()
+                    ]
+                )
+            );
+        }
+        println!(
+            "{}",
+            args.language
+                .tr_format("Days in all: {}.", &[&self.days.to_string()])
+        );
+        Ok(())
+    }
+
+    fn json_print(&self) -> Result<(), AppError> {
+        println!("{}", serde_json::to_string(&self).unwrap());
+        Ok(())
+    }
+
+    fn print(&self, args: &MainArgs) -> Result<(), AppError> {
+        match args.output_type {
+            OutputType::JSON => self.json_print(),
+            OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
+        }
+    }
+}
+
+impl Runnable for DiffArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let days_between = |from: NaiveDate, to: NaiveDate| Ok((to - from).num_days());
+        let ret = DiffReturn {
+            from: self.from,
+            to: self.to,
+            hebrew: span(
+                self.from,
+                self.to,
+                add_hebrew_years,
+                add_hebrew_months,
+                |from, to| days_between(gregorian_day(from)?, gregorian_day(to)?),
+            )?,
+            gregorian: span(
+                gregorian_day(self.from)?,
+                gregorian_day(self.to)?,
+                |x, amount| add_gregorian_months(x, amount * 12),
+                add_gregorian_months,
+                days_between,
+            )?,
+            days: days_between(gregorian_day(self.from)?, gregorian_day(self.to)?)?,
+        };
+        ret.print(args)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddReturn {
+    pub hebrew: HebrewDate,
+    pub gregorian: NaiveDate,
+}
+
+impl AddReturn {
+    fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
+        println!(
+            "{} ({})",
+            print::hebrew_date(self.hebrew, args.language, args.thousands),
+            print::gregorian_date(
+                &Utc.from_utc_date(&self.gregorian).and_hms(0, 0, 0),
+                "%A %B %-d %Y",
+                args.language
+            )
+        );
+        Ok(())
+    }
+
+    fn json_print(&self) -> Result<(), AppError> {
+        println!("{}", serde_json::to_string(&self).unwrap());
+        Ok(())
+    }
+
+    fn print(&self, args: &MainArgs) -> Result<(), AppError> {
+        match args.output_type {
+            OutputType::JSON => self.json_print(),
+            OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
+        }
+    }
+}
+
+impl Runnable for AddArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let day = gregorian_day(self.date)?;
+        let hebrew = match self.unit {
+            Unit::Days => hebrew_day(add_days(day, self.amount)?)?,
+            Unit::Weeks => hebrew_day(add_days(
+                day,
+                self.amount
+                    .checked_mul(7)
+                    .ok_or_else(|| out_of_range(day, self.amount / 52))?,
+            )?)?,
+            Unit::Months => hebrew_day(add_gregorian_months(day, self.amount)?)?,
+            Unit::Years => hebrew_day(add_gregorian_months(
+                day,
+                self.amount
+                    .checked_mul(12)
+                    .ok_or_else(|| out_of_range(day, self.amount))?,
+            )?)?,
+            Unit::HebrewMonths => add_hebrew_months(self.date, self.amount)?,
+            Unit::HebrewYears => add_hebrew_years(self.date, self.amount)?,
+        };
+        let ret = AddReturn {
+            hebrew,
+            gregorian: gregorian_day(hebrew)?,
+        };
+        ret.print(args)
+    }
+}
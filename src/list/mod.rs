@@ -1,10 +1,12 @@
-use crate::algorithms::{chabad_holidays, israeli_holidays, shabbos_mevarchim};
+use crate::algorithms::{
+    aliyot, chabad_holidays, divergence, haftarah, israeli_holidays, kiddush_levana,
+    mourning_periods, purim, rare_events, shabbos_mevarchim, special_shabbosos,
+};
 
-use crate::algorithms::candle_lighting::City;
 use crate::args::types::{
-    AppError, CustomHoliday, Daf, DailyStudy, DailyStudyOutput, DayVal, Event, Language, ListArgs,
-    MainArgs, MinorHoliday, Name, OutputType, RambamChapter, RambamChapters, RambamThreeChapter,
-    YearType, YerushalmiYomi,
+    AppError, CustomHoliday, Daf, DailyStudy, DailyStudyOutput, DayVal, Event, ListArgs, MainArgs,
+    MinorHoliday, Name, OutputType, RambamChapter, RambamChapters, RambamThreeChapter, YearType,
+    YerushalmiYomi,
 };
 use crate::prelude::constants::{get_minor_holidays, GEMARAS_FIRST_CYCLE, GEMARAS_SECOND_CYCLE};
 use crate::prelude::get_omer::get_omer;
@@ -18,9 +20,9 @@ use heca_lib::{HebrewDate, HebrewYear};
 use rayon::prelude::*;
 use serde::Serialize;
 use std::convert::{TryFrom, TryInto};
-use std::io::stdout;
 use std::io::BufWriter;
 use std::io::Write;
+use std::io::{stdout, StdoutLock};
 
 #[derive(Debug, Serialize)]
 #[serde(transparent)]
@@ -28,6 +30,41 @@ pub struct Return {
     list: Vec<DayVal>,
 }
 
+/// An event, with its title if the config file has one for it.
+#[derive(Serialize)]
+pub(crate) struct Titled<'a> {
+    #[serde(flatten)]
+    day: &'a DayVal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a String>,
+    /// Shabbos Mevarchim's announcement of Rosh Chodesh, in the chosen language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    announcement: Option<String>,
+}
+
+impl<'a> Titled<'a> {
+    pub(crate) fn new(day: &'a DayVal, args: &'a MainArgs) -> Self {
+        Titled {
+            day,
+            title: custom_title(day, args),
+            announcement: match &day.name {
+                Name::ShabbosMevarchim(shabbos_mevarchim) => {
+                    Some(shabbos_mevarchim.announcement(args.language))
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+/// The title the config file's `[strings]` table gives an event, if it has one.
+fn custom_title<'a>(d: &DayVal, args: &'a MainArgs) -> Option<&'a String> {
+    if args.strings.is_empty() {
+        return None;
+    }
+    d.name.json_name().and_then(|json| args.strings.get(&json))
+}
+
 impl Return {
     fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
         let stdout = stdout();
@@ -37,7 +74,6 @@ impl Return {
             let year = ret.year();
             let month = ret.month();
             let day = ret.day();
-            let name = d.name.clone();
 
             let mut year_arr = [b'\0'; 16];
             let mut month_arr = [b'\0'; 2];
@@ -45,86 +81,119 @@ impl Return {
             let count_y = itoa::write(&mut year_arr[..], year).unwrap();
             let count_m = itoa::write(&mut month_arr[..], month).unwrap();
             let count_d = itoa::write(&mut day_arr[..], day).unwrap();
-            match args.language {
-                Language::English => lock.write(b"Night of ").unwrap(),
-                Language::Hebrew => lock.write("ליל ".as_bytes()).unwrap(),
-            };
+            lock.write(args.language.tr("Night of ").as_bytes())
+                .unwrap();
             lock.write(&year_arr[..count_y as usize]).unwrap();
             lock.write(b"/").unwrap();
             lock.write(&month_arr[..count_m as usize]).unwrap();
             lock.write(b"/").unwrap();
             lock.write(&day_arr[..count_d as usize]).unwrap();
             lock.write(b": ").unwrap();
-            match name {
-                Name::TorahReading(name) => {
-                    let mut res = lock
-                        .write(print::torah_reading(name, args.language).as_bytes())
-                        .unwrap();
-                    if let Some(l) = d.candle_lighting {
-                        res += match args.language {
-                            Language::English => lock.write(b". Candle lighting").unwrap(),
-                            Language::Hebrew => lock.write(". הדלקת נרות".as_bytes()).unwrap(),
-                        };
-                        if let Some(candle_lighting_time) = l {
-                            let mut hour_arr = [b'\0'; 2];
-                            let mut minute_arr = [b'\0'; 2];
-                            res += lock.write(b" ").unwrap();
-                            itoa::write(&mut hour_arr[..], candle_lighting_time.hour()).unwrap();
-                            let minute_write =
-                                itoa::write(&mut minute_arr[..], candle_lighting_time.minute())
-                                    .unwrap();
-                            res += lock.write(&hour_arr).unwrap();
-                            res += lock.write(b":").unwrap();
-                            if minute_write == 1 {
-                                res += lock.write(b"0").unwrap();
-                            }
-                            res += lock.write(&minute_arr).unwrap();
-                        };
-                    }
-                    Some(res)
-                }
-                Name::MinorDays(day) => lock
-                    .write(print::minor_holidays(day, args.language).as_bytes())
-                    .ok(),
-                Name::CustomHoliday(custom_holiday) => {
-                    lock.write(custom_holiday.printable.as_bytes()).ok()
-                }
-                Name::DailyStudy(daily_study) => match daily_study {
-                    DailyStudyOutput::Daf(d) => d.pretty_print(&mut lock, args.language),
-                    DailyStudyOutput::RambamThreeChapters(three_chapter) => {
-                        three_chapter.pretty_print(&mut lock, args.language)
-                    }
-                    DailyStudyOutput::RambamOneChapters(one_chapter) => {
-                        one_chapter.pretty_print(&mut lock, args.language)
-                    }
-                    DailyStudyOutput::YerushalmiYomi(yerushalmi_yomi) => {
-                        yerushalmi_yomi.pretty_print(&mut lock, args.language)
-                    }
-                },
-                Name::IsraeliHoliday(israeli_holidays) => {
-                    israeli_holidays.pretty_print(&mut lock, args.language)
-                }
-                Name::ChabadHoliday(chabad_holidays) => {
-                    chabad_holidays.pretty_print(&mut lock, args.language)
-                }
-                Name::ShabbosMevarchim(shabbos_mevarchim) => {
-                    shabbos_mevarchim.pretty_print(&mut lock, args.language)
-                }
-            };
+            write_title(&mut lock, d, args);
             lock.write(b"\n").unwrap();
         });
         Ok(())
     }
-    fn json_print(&self) -> Result<(), AppError> {
-        println!("{}", serde_json::to_string(&self).unwrap());
+    fn json_print(&self, args: &MainArgs) -> Result<(), AppError> {
+        let list: Vec<Titled<'_>> = self.list.iter().map(|day| Titled::new(day, args)).collect();
+        println!("{}", serde_json::to_string(&list).unwrap());
         Ok(())
     }
 }
 
+/// Writes what an event is, the way it's listed (without its date).
+pub(crate) fn write_title(lock: &mut BufWriter<StdoutLock<'_>>, d: &DayVal, args: &MainArgs) {
+    let title = custom_title(d, args);
+    match d.name.clone() {
+        Name::TorahReading(name) => {
+            let mut res = match title {
+                Some(title) => lock.write(title.as_bytes()),
+                None => lock.write(print::torah_reading(name, args.language).as_bytes()),
+            }
+            .unwrap();
+            if let Some(l) = d.candle_lighting {
+                res += lock.write(b". ").unwrap();
+                res += lock
+                    .write(args.language.tr("Candle lighting").as_bytes())
+                    .unwrap();
+                if let Some(candle_lighting_time) = l {
+                    let mut hour_arr = [b'\0'; 2];
+                    let mut minute_arr = [b'\0'; 2];
+                    res += lock.write(b" ").unwrap();
+                    itoa::write(&mut hour_arr[..], candle_lighting_time.hour()).unwrap();
+                    let minute_write =
+                        itoa::write(&mut minute_arr[..], candle_lighting_time.minute()).unwrap();
+                    res += lock.write(&hour_arr).unwrap();
+                    res += lock.write(b":").unwrap();
+                    if minute_write == 1 {
+                        res += lock.write(b"0").unwrap();
+                    }
+                    res += lock.write(&minute_arr).unwrap();
+                };
+            }
+            if let Some(reading) = &d.reading {
+                res += lock.write(b". ").unwrap();
+                res += lock
+                    .write(reading.pretty(args.language).as_bytes())
+                    .unwrap();
+            }
+            if let Some(haftarah) = &d.haftarah {
+                res += lock.write(b". ").unwrap();
+                res += lock.write(args.language.tr("Haftarah").as_bytes()).unwrap();
+                res += lock.write(b": ").unwrap();
+                res += lock
+                    .write(haftarah.pretty(args.language).as_bytes())
+                    .unwrap();
+            }
+            if let Some(other_location) = &d.other_location {
+                res += lock.write(b". ").unwrap();
+                res += lock
+                    .write(other_location.pretty(args.language).as_bytes())
+                    .unwrap();
+            }
+            if let Some(triennial) = &d.triennial {
+                res += lock.write(b". ").unwrap();
+                res += lock
+                    .write(triennial.pretty(args.language).as_bytes())
+                    .unwrap();
+            }
+            Some(res)
+        }
+        // Any other event with a title in the config file is printed as just its title.
+        _ if title.is_some() => lock.write(title.unwrap().as_bytes()).ok(),
+        Name::MinorDays(day) => lock
+            .write(print::minor_holidays(day, args.language).as_bytes())
+            .ok(),
+        Name::CustomHoliday(custom_holiday) => lock.write(custom_holiday.printable.as_bytes()).ok(),
+        Name::DailyStudy(daily_study) => match daily_study {
+            DailyStudyOutput::Daf(d) => d.pretty_print(lock, args.language),
+            DailyStudyOutput::RambamThreeChapters(three_chapter) => {
+                three_chapter.pretty_print(lock, args.language)
+            }
+            DailyStudyOutput::RambamOneChapters(one_chapter) => {
+                one_chapter.pretty_print(lock, args.language)
+            }
+            DailyStudyOutput::YerushalmiYomi(yerushalmi_yomi) => {
+                yerushalmi_yomi.pretty_print(lock, args.language)
+            }
+        },
+        Name::IsraeliHoliday(israeli_holidays) => {
+            israeli_holidays.pretty_print(lock, args.language)
+        }
+        Name::ChabadHoliday(chabad_holidays) => chabad_holidays.pretty_print(lock, args.language),
+        Name::ShabbosMevarchim(shabbos_mevarchim) => {
+            shabbos_mevarchim.pretty_print(lock, args.language)
+        }
+        Name::KiddushLevana(kiddush_levana) => kiddush_levana.pretty_print(lock, args.language),
+        Name::RareEvent(rare_event) => rare_event.pretty_print(lock, args.language),
+        Name::MourningPeriod(mourning_period) => mourning_period.pretty_print(lock, args.language),
+    };
+}
+
 impl Return {
     fn print(&self, args: &MainArgs) -> Result<(), AppError> {
         match args.output_type {
-            OutputType::JSON => self.json_print(),
+            OutputType::JSON => self.json_print(args),
             OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
         }
     }
@@ -161,27 +230,25 @@ impl GetDayVal for DailyStudyEvents {
                         let first_day_of_second_cycle = Utc.ymd(1975, 6, 23).and_hms(18, 0, 0);
                         if i >= first_day_of_second_cycle {
                             let diff = i - first_day_of_second_cycle;
-                            let d = DayVal {
-                                day: i,
-                                name: Name::DailyStudy(DailyStudyOutput::Daf(Daf::from_days(
+                            let d = DayVal::new(
+                                i,
+                                Name::DailyStudy(DailyStudyOutput::Daf(Daf::from_days(
                                     (diff.num_days() % 2711).try_into().unwrap(),
                                     &GEMARAS_SECOND_CYCLE,
                                 ))),
-                                candle_lighting: None,
-                            };
+                            );
                             return_val.push(d);
                         } else {
                             let first_day_of_first_cycle = Utc.ymd(1923, 9, 10).and_hms(18, 0, 0);
                             if i >= first_day_of_first_cycle {
                                 let diff = i - first_day_of_first_cycle;
-                                let d = DayVal {
-                                    day: i,
-                                    candle_lighting: None,
-                                    name: Name::DailyStudy(DailyStudyOutput::Daf(Daf::from_days(
+                                let d = DayVal::new(
+                                    i,
+                                    Name::DailyStudy(DailyStudyOutput::Daf(Daf::from_days(
                                         (diff.num_days() % 2702).try_into().unwrap(),
                                         &GEMARAS_FIRST_CYCLE,
                                     ))),
-                                };
+                                );
                                 return_val.push(d);
                             }
                         }
@@ -192,33 +259,25 @@ impl GetDayVal for DailyStudyEvents {
                         if i >= first_day {
                             match chapters {
                                 RambamChapters::One => {
-                                    let d = DayVal {
-                                        candle_lighting: None,
-                                        day: i,
-                                        name: Name::DailyStudy(
-                                            DailyStudyOutput::RambamOneChapters(
-                                                RambamChapter::from_days(
-                                                    (diff.num_days() % 1017).try_into().unwrap(),
-                                                ),
+                                    let d = DayVal::new(
+                                        i,
+                                        Name::DailyStudy(DailyStudyOutput::RambamOneChapters(
+                                            RambamChapter::from_days(
+                                                (diff.num_days() % 1017).try_into().unwrap(),
                                             ),
-                                        ),
-                                    };
+                                        )),
+                                    );
                                     return_val.push(d);
                                 }
                                 RambamChapters::Three => {
-                                    let d = DayVal {
-                                        candle_lighting: None,
-                                        day: i,
-                                        name: Name::DailyStudy(
-                                            DailyStudyOutput::RambamThreeChapters(
-                                                RambamThreeChapter::from_days(
-                                                    (diff.num_days() % (1017 / 3))
-                                                        .try_into()
-                                                        .unwrap(),
-                                                ),
+                                    let d = DayVal::new(
+                                        i,
+                                        Name::DailyStudy(DailyStudyOutput::RambamThreeChapters(
+                                            RambamThreeChapter::from_days(
+                                                (diff.num_days() % (1017 / 3)).try_into().unwrap(),
                                             ),
-                                        ),
-                                    };
+                                        )),
+                                    );
                                     return_val.push(d);
                                 }
                             }
@@ -275,9 +334,9 @@ impl GetDayVal for DailyStudyEvents {
                                     amnt_years + amnt_tisha_beav_this_year
                                 };
                                 if diff_days.num_days() > 0 {
-                                    let d = DayVal {
-                                        day: i,
-                                        name: Name::DailyStudy(DailyStudyOutput::YerushalmiYomi(
+                                    let d = DayVal::new(
+                                        i,
+                                        Name::DailyStudy(DailyStudyOutput::YerushalmiYomi(
                                             YerushalmiYomi::from_days(
                                                 ((diff_days.num_days() as u64
                                                     - amnt_tisha_beav
@@ -287,8 +346,7 @@ impl GetDayVal for DailyStudyEvents {
                                                     .unwrap(),
                                             ),
                                         )),
-                                        candle_lighting: None,
-                                    };
+                                    );
                                     return_val.push(d);
                                 }
                             }
@@ -303,8 +361,9 @@ impl GetDayVal for DailyStudyEvents {
     }
 }
 
-impl Runnable for ListArgs {
-    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+impl ListArgs {
+    /// Every event of the years that are listed, sorted unless `--no-sort` was passed.
+    pub(crate) fn get_days(&self) -> Result<Vec<DayVal>, AppError> {
         let main_events = self
             .events
             .iter()
@@ -344,14 +403,11 @@ impl Runnable for ListArgs {
                 HebrewYear::new(year)?;
                 HebrewYear::new(year + self.amnt_years)?;
                 let mut part1 = get_list(
+                    self,
                     year,
                     year + self.amnt_years,
-                    self.location,
-                    &self.events,
                     &main_events,
                     &custom_events,
-                    self.exact_days,
-                    &self.city,
                 )?;
                 part1.extend(daily_study_events.get_day_val(year, year + self.amnt_years - 1));
                 Ok(Return { list: part1 })
@@ -364,16 +420,7 @@ impl Runnable for ListArgs {
                     .and_hms(18, 0, 0);
                 let that_year = HebrewDate::try_from(orig_jan_1).unwrap().year();
                 let last_year = HebrewDate::try_from(last_jan_1).unwrap().year();
-                let mut part1 = get_list(
-                    that_year,
-                    last_year,
-                    self.location,
-                    &self.events,
-                    &main_events,
-                    &custom_events,
-                    self.exact_days,
-                    &self.city,
-                )?;
+                let mut part1 = get_list(self, that_year, last_year, &main_events, &custom_events)?;
                 part1.extend(daily_study_events.get_day_val(that_year, last_year));
                 let mut part2: Vec<DayVal> = Vec::with_capacity((self.amnt_years as usize) * 100);
                 part1
@@ -389,25 +436,45 @@ impl Runnable for ListArgs {
 
                 Ok(Return { list: part2 })
             }
+
+            YearType::Range(from, to) => {
+                let first_day: DateTime<Utc> = from.into();
+                let last_day: DateTime<Utc> = to.into();
+                let mut part1 = get_list(
+                    self,
+                    from.year(),
+                    to.year() + 1,
+                    &main_events,
+                    &custom_events,
+                )?;
+                part1.extend(daily_study_events.get_day_val(from.year(), to.year()));
+                part1.retain(|x| x.day >= first_day && x.day < last_day + Duration::days(1));
+                Ok(Return { list: part1 })
+            }
         };
         let mut result1 = result?;
         if !self.no_sort {
             result1.list.par_sort_unstable_by(|a, b| a.day.cmp(&b.day));
         }
-        result1.print(args)?;
-        Ok(())
+        Ok(result1.list)
+    }
+}
+
+impl Runnable for ListArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        Return {
+            list: self.get_days()?,
+        }
+        .print(args)
     }
 }
 
 fn get_list(
+    args: &ListArgs,
     year: u64,
     last_year: u64,
-    location: Location,
-    events: &[Event],
     main_events: &Vec<TorahReadingType>,
     custom_events: &Vec<CustomHoliday>,
-    exact_days: bool,
-    city: &Option<City>,
 ) -> Result<Vec<DayVal>, AppError> {
     let amnt_years = last_year - year;
     let mut part1: Vec<Vec<DayVal>> = Vec::with_capacity(amnt_years as usize);
@@ -419,10 +486,17 @@ fn get_list(
         .map(|x| {
             let mut ret: Vec<DayVal> = Vec::with_capacity(200);
             let year = HebrewYear::new(x as u64 + year).unwrap();
+            // Only the weekly parsha differs between Israel and Chutz La'aretz.
+            let other_location = if main_events.contains(&TorahReadingType::Shabbos) {
+                Some(divergence::get(&year, args.location))
+            } else {
+                None
+            };
 
             ret.extend(
-                year.get_holidays(location, &main_events)
+                year.get_holidays(args.location, &main_events)
                     .into_iter()
+                    .filter(|x| !args.walled_city || purim::kept_in_walled_city(&year, x.name()))
                     .map(|x| {
                         let day: DateTime<Utc> = x.day().into();
                         let mut light_on_time = false;
@@ -457,7 +531,7 @@ fn get_list(
                                     }
                                 }
 
-                                yt => match location {
+                                yt => match args.location {
                                     Location::Chul => {
                                         if yt == heca_lib::prelude::YomTov::Sukkos2
                                             || yt == heca_lib::prelude::YomTov::SimchasTorah
@@ -476,8 +550,32 @@ fn get_list(
                                 },
                             };
                         };
+                        let reading = if args.aliyot {
+                            Some(aliyot::get(
+                                x.name(),
+                                args.location,
+                                (day + Duration::days(1)).weekday(),
+                                args.minhag,
+                            ))
+                        } else {
+                            None
+                        };
+                        let haftarah = if args.haftarah {
+                            haftarah::get(x.name(), day, args.location, args.minhag)
+                        } else {
+                            None
+                        };
+                        let other_location = other_location
+                            .as_ref()
+                            .and_then(|other| divergence::compare(day, x.name(), other));
+                        let triennial = match x.name() {
+                            TorahReading::Shabbos(parsha) if args.triennial => {
+                                Some(aliyot::triennial(parsha, year.year()))
+                            }
+                            _ => None,
+                        };
                         if is_shabbos || is_yom_tov {
-                            let candle_lighting = if let Some(city) = city {
+                            let candle_lighting = if let Some(city) = &args.city {
                                 use zmanim::prelude::Zmanim;
                                 let date: NaiveDate = day.date().naive_local();
                                 if light_on_time {
@@ -503,51 +601,76 @@ fn get_list(
                                 None
                             };
                             DayVal {
-                                day,
-                                name: Name::TorahReading(x.name()),
                                 candle_lighting: Some(candle_lighting),
+                                reading,
+                                haftarah,
+                                other_location,
+                                triennial,
+                                ..DayVal::new(day, Name::TorahReading(x.name()))
                             }
                         } else {
                             DayVal {
-                                day,
-                                name: Name::TorahReading(x.name()),
-                                candle_lighting: None,
+                                reading,
+                                haftarah,
+                                other_location,
+                                triennial,
+                                ..DayVal::new(day, Name::TorahReading(x.name()))
                             }
                         }
                     }),
             );
 
-            if events.contains(&Event::MinorHoliday(MinorHoliday::Omer)) {
+            if args.walled_city && main_events.contains(&TorahReadingType::Chol) {
+                ret.extend(purim::get_walled_city(&year));
+            }
+            if args
+                .events
+                .contains(&Event::MinorHoliday(MinorHoliday::Omer))
+            {
                 ret.extend_from_slice(&get_omer(&year));
             }
-            if events.contains(&Event::IsraeliHolidays) {
-                ret.extend_from_slice(&israeli_holidays::get(&year, exact_days));
+            if args.events.contains(&Event::IsraeliHolidays) {
+                ret.extend_from_slice(&israeli_holidays::get(&year, args.exact_days));
             }
-            if events.contains(&Event::ChabadHolidays) {
+            if args.events.contains(&Event::ChabadHolidays) {
                 ret.extend_from_slice(&chabad_holidays::get(&year));
             }
-            if events.contains(&Event::ShabbosMevarchim) {
+            if args.events.contains(&Event::ShabbosMevarchim) {
                 ret.extend_from_slice(&shabbos_mevarchim::get(&year));
             }
-            if events.contains(&Event::MinorHoliday(MinorHoliday::Minor)) {
+            if args.events.contains(&Event::KiddushLevana) {
+                ret.extend(kiddush_levana::get(
+                    &year,
+                    args.kiddush_levana_start,
+                    args.kiddush_levana_end,
+                ));
+            }
+            if args.events.contains(&Event::MourningPeriods) {
+                ret.extend(mourning_periods::get(&year, args.minhag));
+            }
+            if args.events.contains(&Event::RareEvents) {
+                ret.extend(rare_events::get(&year));
+            }
+            if args
+                .events
+                .contains(&Event::MinorHoliday(MinorHoliday::Minor))
+            {
                 ret.extend(get_minor_holidays(&year));
             }
+            if args
+                .events
+                .contains(&Event::MinorHoliday(MinorHoliday::SpecialShabbosos))
+            {
+                ret.extend(special_shabbosos::get(&year, args.location));
+            }
             custom_events.iter().for_each(|x| {
                 if let Ok(day) = year.get_hebrew_date(x.date.month, x.date.day) {
-                    let d = DayVal {
-                        name: Name::CustomHoliday(x.clone()),
-                        day: day.try_into().unwrap(),
-                        candle_lighting: None,
-                    };
+                    let d = DayVal::new(day.try_into().unwrap(), Name::CustomHoliday(x.clone()));
                     ret.push(d);
                 } else if let Some(not_exists) = &x.if_not_exists {
                     not_exists.iter().for_each(|day_month| {
                         if let Ok(day) = year.get_hebrew_date(day_month.month, day_month.day) {
-                            let d = DayVal {
-                                name: Name::CustomHoliday(x.clone()),
-                                day: day.into(),
-                                candle_lighting: None,
-                            };
+                            let d = DayVal::new(day.into(), Name::CustomHoliday(x.clone()));
                             ret.push(d);
                         }
                     });
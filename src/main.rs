@@ -1,8 +1,12 @@
 mod algorithms;
 mod args;
 mod convert;
+mod day_info;
+mod interval;
 mod list;
 mod prelude;
+mod wedding;
+mod year_info;
 
 use crate::args::types;
 use crate::args::types::AppError;
@@ -78,6 +82,13 @@ where
     match args.command {
         Command::List(ref sub_args) => sub_args.run(&args)?,
         Command::Convert(ref sub_args) => sub_args.run(&args)?,
+        Command::ConvertBatch(ref sub_args) => sub_args.run(&args)?,
+        Command::DayInfo(ref sub_args) => sub_args.run(&args)?,
+        Command::YearInfo(ref sub_args) => sub_args.run(&args)?,
+        Command::WeddingCheck(ref sub_args) => sub_args.run(&args)?,
+        Command::WeddingDates(ref sub_args) => sub_args.run(&args)?,
+        Command::Diff(ref sub_args) => sub_args.run(&args)?,
+        Command::Add(ref sub_args) => sub_args.run(&args)?,
     };
 
     Ok(())
@@ -9,97 +9,75 @@ use std::num::NonZeroI8;
 
 pub fn get_minor_holidays(year: &HebrewYear) -> Vec<DayVal> {
     let mut holidays = vec![
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(9).unwrap())
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(9).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::ErevYomKippur),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(14).unwrap())
+            Name::MinorDays(MinorDays::ErevYomKippur),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(14).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::ErevSukkos),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(14).unwrap())
+            Name::MinorDays(MinorDays::ErevSukkos),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(14).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::ErevPesach),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(14).unwrap())
+            Name::MinorDays(MinorDays::ErevPesach),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(14).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::PesachSheni),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(18).unwrap())
+            Name::MinorDays(MinorDays::PesachSheni),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Iyar, NonZeroI8::new(18).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::LagBaOmer),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Sivan, NonZeroI8::new(5).unwrap())
+            Name::MinorDays(MinorDays::LagBaOmer),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Sivan, NonZeroI8::new(5).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::ErevShavuos),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Elul, NonZeroI8::new(29).unwrap())
+            Name::MinorDays(MinorDays::ErevShavuos),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Elul, NonZeroI8::new(29).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::ErevRoshHashanah),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Shvat, NonZeroI8::new(15).unwrap())
+            Name::MinorDays(MinorDays::ErevRoshHashanah),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Shvat, NonZeroI8::new(15).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::FifteenShvat),
-            candle_lighting: None,
-        },
-        DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Av, NonZeroI8::new(15).unwrap())
+            Name::MinorDays(MinorDays::FifteenShvat),
+        ),
+        DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Av, NonZeroI8::new(15).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::FifteenAv),
-            candle_lighting: None,
-        },
+            Name::MinorDays(MinorDays::FifteenAv),
+        ),
     ];
 
     if year.is_leap_year() {
-        holidays.push(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Adar1, NonZeroI8::new(14).unwrap())
+        holidays.push(DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Adar1, NonZeroI8::new(14).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::PurimKattan),
-            candle_lighting: None,
-        });
-        holidays.push(DayVal {
-            day: year
-                .get_hebrew_date(HebrewMonth::Adar1, NonZeroI8::new(15).unwrap())
+            Name::MinorDays(MinorDays::PurimKattan),
+        ));
+        holidays.push(DayVal::new(
+            year.get_hebrew_date(HebrewMonth::Adar1, NonZeroI8::new(15).unwrap())
                 .unwrap()
                 .into(),
-            name: Name::MinorDays(MinorDays::ShushanPurimKattan),
-            candle_lighting: None,
-        });
+            Name::MinorDays(MinorDays::ShushanPurimKattan),
+        ));
     }
     let first_day_of_pesach: DateTime<Utc> = year
         .get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(15).unwrap())
@@ -113,14 +91,12 @@ pub fn get_minor_holidays(year: &HebrewYear) -> Vec<DayVal> {
         Weekday::Fri => 8,
         _ => panic!("Pesach shouldn't fall out on a {}", first_day_of_pesach),
     };
-    holidays.push(DayVal {
-        day: year
-            .get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(day_in_nissan).unwrap())
+    holidays.push(DayVal::new(
+        year.get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(day_in_nissan).unwrap())
             .unwrap()
             .into(),
-        name: Name::MinorDays(MinorDays::ShabbosHaGadol),
-        candle_lighting: None,
-    });
+        Name::MinorDays(MinorDays::ShabbosHaGadol),
+    ));
 
     let day_of_taanis_bechoros = if first_day_of_pesach == Weekday::Sat {
         12
@@ -128,17 +104,15 @@ pub fn get_minor_holidays(year: &HebrewYear) -> Vec<DayVal> {
         14
     };
 
-    holidays.push(DayVal {
-        day: year
-            .get_hebrew_date(
-                HebrewMonth::Nissan,
-                NonZeroI8::new(day_of_taanis_bechoros).unwrap(),
-            )
-            .unwrap()
-            .into(),
-        name: Name::MinorDays(MinorDays::TaanisBechoros),
-        candle_lighting: None,
-    });
+    holidays.push(DayVal::new(
+        year.get_hebrew_date(
+            HebrewMonth::Nissan,
+            NonZeroI8::new(day_of_taanis_bechoros).unwrap(),
+        )
+        .unwrap()
+        .into(),
+        Name::MinorDays(MinorDays::TaanisBechoros),
+    ));
 
     let day_of_tisha_beav: DateTime<Utc> = year
         .get_hebrew_date(HebrewMonth::Av, NonZeroI8::new(9).unwrap())
@@ -151,29 +125,25 @@ pub fn get_minor_holidays(year: &HebrewYear) -> Vec<DayVal> {
         Weekday::Fri => 9,
         x => panic!("Tisha Beav shouldn't be on {}", x),
     };
-    holidays.push(DayVal {
-        day: year
-            .get_hebrew_date(
-                HebrewMonth::Av,
-                NonZeroI8::new(day_of_month_of_shabbos_chazon).unwrap(),
-            )
-            .unwrap()
-            .into(),
-        name: Name::MinorDays(MinorDays::ShabbosChazon),
-        candle_lighting: None,
-    });
+    holidays.push(DayVal::new(
+        year.get_hebrew_date(
+            HebrewMonth::Av,
+            NonZeroI8::new(day_of_month_of_shabbos_chazon).unwrap(),
+        )
+        .unwrap()
+        .into(),
+        Name::MinorDays(MinorDays::ShabbosChazon),
+    ));
 
-    holidays.push(DayVal {
-        day: year
-            .get_hebrew_date(
-                HebrewMonth::Av,
-                NonZeroI8::new(day_of_month_of_shabbos_chazon + 7).unwrap(),
-            )
-            .unwrap()
-            .into(),
-        name: Name::MinorDays(MinorDays::ShabbosNachamu),
-        candle_lighting: None,
-    });
+    holidays.push(DayVal::new(
+        year.get_hebrew_date(
+            HebrewMonth::Av,
+            NonZeroI8::new(day_of_month_of_shabbos_chazon + 7).unwrap(),
+        )
+        .unwrap()
+        .into(),
+        Name::MinorDays(MinorDays::ShabbosNachamu),
+    ));
 
     let day_of_rh: DateTime<Utc> = year
         .get_hebrew_date(HebrewMonth::Tishrei, NonZeroI8::new(1).unwrap())
@@ -200,72 +170,68 @@ pub fn get_minor_holidays(year: &HebrewYear) -> Vec<DayVal> {
         Weekday::Sat => 22,
         x => panic!("Leil Slichos shouldn't be on {}", x),
     };
-    holidays.push(DayVal {
-        day: year
-            .get_hebrew_date(
-                HebrewMonth::Elul,
-                NonZeroI8::new(day_of_month_of_leil_slichos).unwrap(),
-            )
-            .unwrap()
-            .into(),
-        name: Name::MinorDays(MinorDays::LeilSlichos),
-        candle_lighting: None,
-    });
-    holidays.push(DayVal {
-        day: year
-            .get_hebrew_date(
-                HebrewMonth::Tishrei,
-                NonZeroI8::new(day_of_month_of_shabbos_shuva).unwrap(),
-            )
-            .unwrap()
-            .into(),
-        name: Name::MinorDays(MinorDays::ShabbosShuva),
-        candle_lighting: None,
-    });
+    holidays.push(DayVal::new(
+        year.get_hebrew_date(
+            HebrewMonth::Elul,
+            NonZeroI8::new(day_of_month_of_leil_slichos).unwrap(),
+        )
+        .unwrap()
+        .into(),
+        Name::MinorDays(MinorDays::LeilSlichos),
+    ));
+    holidays.push(DayVal::new(
+        year.get_hebrew_date(
+            HebrewMonth::Tishrei,
+            NonZeroI8::new(day_of_month_of_shabbos_shuva).unwrap(),
+        )
+        .unwrap()
+        .into(),
+        Name::MinorDays(MinorDays::ShabbosShuva),
+    ));
 
     holidays
 }
 
 macro_rules! make_list {
     ($name:ident, $val:expr) => {
-        pub const $name: [(&str, &str, &str, u8); 37] = [
-            ("Berakhot", "ברכות", "Berakhot", 64),
-            ("Shabbat", "שבת", "Shabbat", 157),
-            ("Eruvin", "עירובין", "Eruvin", 105),
-            ("Pesachim", "פסחים", "Pesachim", 121),
-            ("Shekalim", "שקלים", "Shekalim", $val),
-            ("Yoma", "יומא", "Yoma", 88),
-            ("Sukkah", "סוכה", "Sukkah", 56),
-            ("Beitzah", "ביצה", "Beitzah", 40),
-            ("Rosh Hashanah", "ראש השנה", "RoshHashanah", 35),
-            ("Taanit", "תענית", "Taanit", 31),
-            ("Megillah", "מגילה", "Megillah", 32),
-            ("Moed Katan", "מועד קטן", "MoedKatan", 29),
-            ("Chagigah", "חגיגה", "Chagigah", 27),
-            ("Yevamot", "יבמות", "Yevamot", 122),
-            ("Ketubot", "כתובות", "Ketubot", 112),
-            ("Nedarim", "נדרים", "Nedarim", 91),
-            ("Nazir", "נזיר", "Nazir", 66),
-            ("Sotah", "סוטה", "Sotah", 49),
-            ("Gittin", "גיטין", "Gittin", 90),
-            ("Kiddushin", "קידושין", "Kiddushin", 82),
-            ("Bava Kamma", "בבא קמא", "BavaKamma", 119),
-            ("Bava Metzia", "בבא מציעא", "BavaMetzia", 119),
-            ("Bava Batra", "בבא בתרא", "BavaBatra", 176),
-            ("Sanhedrin", "סנהדרין", "Sanhedrin", 113),
-            ("Makkot", "מכות", "Makkot", 24),
-            ("Shevuot", "שבועות", "Shevuot", 49),
-            ("Avodah Zarah", "עבודה זרה", "AvodahZarah", 76),
-            ("Horayot", "הוריות", "Horayot", 14),
-            ("Zevachim", "זבחים", "Zevachim", 120),
-            ("Menachot", "מנחות", "Menachot", 110),
-            ("Chullin", "חולין", "Chullin", 142),
-            ("Bekhorot", "בכורות", "Bekhorot", 61),
-            ("Arakhin", "ערכין", "Arakhin", 34),
-            ("Temurah", "תמורה", "Temurah", 34),
-            ("Keritot", "כריתות", "Keritot", 28),
-            ("Meilah", "מעילה", "Meilah", 37),
-            ("Niddah", "נדה", "Niddah", 73),
+        pub const $name: [(&str, &str, u8); 37] = [
+            ("Berakhot", "Berakhot", 64),
+            ("Shabbat", "Shabbat", 157),
+            ("Eruvin", "Eruvin", 105),
+            ("Pesachim", "Pesachim", 121),
+            ("Shekalim", "Shekalim", $val),
+            ("Yoma", "Yoma", 88),
+            ("Sukkah", "Sukkah", 56),
+            ("Beitzah", "Beitzah", 40),
+            ("Rosh Hashanah", "RoshHashanah", 35),
+            ("Taanit", "Taanit", 31),
+            ("Megillah", "Megillah", 32),
+            ("Moed Katan", "MoedKatan", 29),
+            ("Chagigah", "Chagigah", 27),
+            ("Yevamot", "Yevamot", 122),
+            ("Ketubot", "Ketubot", 112),
+            ("Nedarim", "Nedarim", 91),
+            ("Nazir", "Nazir", 66),
+            ("Sotah", "Sotah", 49),
+            ("Gittin", "Gittin", 90),
+            ("Kiddushin", "Kiddushin", 82),
+            ("Bava Kamma", "BavaKamma", 119),
+            ("Bava Metzia", "BavaMetzia", 119),
+            ("Bava Batra", "BavaBatra", 176),
+            ("Sanhedrin", "Sanhedrin", 113),
+            ("Makkot", "Makkot", 24),
+            ("Shevuot", "Shevuot", 49),
+            ("Avodah Zarah", "AvodahZarah", 76),
+            ("Horayot", "Horayot", 14),
+            ("Zevachim", "Zevachim", 120),
+            ("Menachot", "Menachot", 110),
+            ("Chullin", "Chullin", 142),
+            ("Bekhorot", "Bekhorot", 61),
+            ("Arakhin", "Arakhin", 34),
+            ("Temurah", "Temurah", 34),
+            ("Keritot", "Keritot", 28),
+            ("Meilah", "Meilah", 37),
+            ("Niddah", "Niddah", 73),
         ];
     };
 }
@@ -280,7 +246,7 @@ fn check_rambam_json_title() {
             &g.0.split(|c| c == ' ' || c == ',' || c == '\'')
                 .collect::<String>()
                 .to_uppercase(),
-            &g.2.to_uppercase()
+            &g.1.to_uppercase()
         );
     }
 }
@@ -292,7 +258,7 @@ fn check_yerushalmi_json_title() {
             &g.0.split(|c| c == ' ' || c == ',' || c == '\'')
                 .collect::<String>()
                 .to_uppercase(),
-            &g.2.to_uppercase()
+            &g.1.to_uppercase()
         );
     }
 }
@@ -300,7 +266,7 @@ fn check_yerushalmi_json_title() {
 #[test]
 fn check_json_yerushalmi() {
     for g in YERUSHALMI.iter() {
-        for i in g.2.chars() {
+        for i in g.1.chars() {
             if !i.is_alphabetic() {
                 panic!("{:?} is an invalid json {}", g, i);
             }
@@ -311,7 +277,7 @@ fn check_json_yerushalmi() {
 #[test]
 fn check_json_gemara() {
     for g in GEMARAS_FIRST_CYCLE.iter() {
-        for i in g.2.chars() {
+        for i in g.1.chars() {
             if !i.is_alphabetic() {
                 panic!("{:?} is an invalid json {}", g, i);
             }
@@ -322,7 +288,7 @@ fn check_json_gemara() {
 #[test]
 fn check_json_rambam() {
     for g in RAMBAM.iter() {
-        for i in g.2.chars() {
+        for i in g.1.chars() {
             if !i.is_alphabetic() {
                 panic!("{:?} is an invalid json {}", g, i);
             }
@@ -330,568 +296,279 @@ fn check_json_rambam() {
     }
 }
 
-pub const RAMBAM: [(&str, &str, &str, u8); 88] = [
+pub const RAMBAM: [(&str, &str, u8); 88] = [
     (
         "Transmission of the Oral Law",
-        "מסירת תורה שבעל פה",
         "TransmissionOfTheOralLaw",
         3,
     ),
-    ("Positive Mitzvot", "מצוות עשה", "PositiveMitzvot", 3),
-    ("Negative Mitzvot", "מצוות לא תעשה", "NegativeMitzvot", 3),
+    ("Positive Mitzvot", "PositiveMitzvot", 3),
+    ("Negative Mitzvot", "NegativeMitzvot", 3),
     (
         "Overview of Mishneh Torah Contents",
-        "תוכן החיבור",
         "OverviewOfMishnehTorahContents",
         3,
     ),
-    (
-        "Foundations of the Torah",
-        "הלכות יסודי התורה",
-        "FoundationsOfTheTorah",
-        10,
-    ),
-    ("Human Dispositions", "הלכות דעות", "HumanDispositions", 7),
-    ("Torah Study", "הלכות תלמוד תורה", "TorahStudy", 7),
+    ("Foundations of the Torah", "FoundationsOfTheTorah", 10),
+    ("Human Dispositions", "HumanDispositions", 7),
+    ("Torah Study", "TorahStudy", 7),
     (
         "Foreign Worship and Customs of the Nations",
-        "הלכות עבודה זרה וחוקות הגויים",
         "ForeignWorshipAndCustomsOfTheNations",
         12,
     ),
-    ("Repentance", "הלכות תשובה", "Repentance", 10),
-    ("Reading the Shema", "הלכות קריאת שמע", "ReadingTheShema", 4),
+    ("Repentance", "Repentance", 10),
+    ("Reading the Shema", "ReadingTheShema", 4),
     (
         "Prayer and the Priestly Blessing",
-        "הלכות תפילה וברכת כהנים",
         "PrayerAndThePriestlyBlessing",
         15,
     ),
     (
         "Tefillin, Mezuzah and the Torah Scroll",
-        "הלכות תפילין ומזוזה וספר תורה",
         "TefillinMezuzahAndTheTorahScroll",
         10,
     ),
-    ("Fringes", "הלכות ציצית", "Fringes", 3),
-    ("Blessings", "הלכות ברכות", "Blessings", 11),
-    ("Circumcision", "הלכות מילה", "Circumcision", 3),
-    ("The Order of Prayer", "סדר התפילה", "TheOrderOfPrayer", 4),
-    ("Sabbath", "הלכות שבת", "Sabbath", 30),
-    ("Eruvin", "הלכות עירובין", "Eruvin", 8),
-    (
-        "Rest on the Tenth of Tishrei",
-        "הלכות שביתת עשור",
-        "RestOnTheTenthOfTishrei",
-        3,
-    ),
-    (
-        "Rest on a Holiday",
-        "הלכות שביתת יום טוב",
-        "RestOnAHoliday",
-        8,
-    ),
+    ("Fringes", "Fringes", 3),
+    ("Blessings", "Blessings", 11),
+    ("Circumcision", "Circumcision", 3),
+    ("The Order of Prayer", "TheOrderOfPrayer", 4),
+    ("Sabbath", "Sabbath", 30),
+    ("Eruvin", "Eruvin", 8),
+    ("Rest on the Tenth of Tishrei", "RestOnTheTenthOfTishrei", 3),
+    ("Rest on a Holiday", "RestOnAHoliday", 8),
     (
         "Leavened and Unleavened Bread",
-        "הלכות חמץ ומצה",
         "LeavenedAndUnleavenedBread",
         9,
     ),
-    (
-        "Shofar, Sukkah and Lulav",
-        "הלכות שופר וסוכה ולולב",
-        "ShofarSukkahAndLulav",
-        8,
-    ),
-    ("Sheqel Dues", "הלכות שקלים", "SheqelDues", 4),
+    ("Shofar, Sukkah and Lulav", "ShofarSukkahAndLulav", 8),
+    ("Sheqel Dues", "SheqelDues", 4),
     (
         "Sanctification of the New Month",
-        "הלכות קידוש החודש",
         "SanctificationOfTheNewMonth",
         19,
     ),
-    ("Fasts", "הלכות תעניות", "Fasts", 5),
+    ("Fasts", "Fasts", 5),
     (
         "Scroll of Esther and Hanukkah",
-        "הלכות מגילה וחנוכה",
         "ScrollOfEstherAndHanukkah",
         4,
     ),
-    ("Marriage", "הלכות אישות", "Marriage", 25),
-    ("Divorce", "הלכות גירושין", "Divorce", 13),
+    ("Marriage", "Marriage", 25),
+    ("Divorce", "Divorce", 13),
     (
         "Levirate Marriage and Release",
-        "הלכות יבום וחליצה",
         "LevirateMarriageAndRelease",
         8,
     ),
-    ("Virgin Maiden", "הלכות נערה בתולה", "VirginMaiden", 3),
+    ("Virgin Maiden", "VirginMaiden", 3),
     (
         "Woman Suspected of Infidelity",
-        "הלכות סוטה",
         "WomanSuspectedOfInfidelity",
         4,
     ),
-    (
-        "Forbidden Intercourse",
-        "הלכות איסורי ביאה",
-        "ForbiddenIntercourse",
-        22,
-    ),
-    (
-        "Forbidden Foods",
-        "הלכות מאכלות אסורות",
-        "ForbiddenFoods",
-        17,
-    ),
-    ("Ritual Slaughter", "הלכות שחיטה", "RitualSlaughter", 14),
-    ("Oaths", "הלכות שבועות", "Oaths", 12),
-    ("Vows", "הלכות נדרים", "Vows", 13),
-    ("Nazariteship", "הלכות נזירות", "Nazariteship", 10),
+    ("Forbidden Intercourse", "ForbiddenIntercourse", 22),
+    ("Forbidden Foods", "ForbiddenFoods", 17),
+    ("Ritual Slaughter", "RitualSlaughter", 14),
+    ("Oaths", "Oaths", 12),
+    ("Vows", "Vows", 13),
+    ("Nazariteship", "Nazariteship", 10),
     (
         "Appraisals and Devoted Property",
-        "הלכות ערכים וחרמין",
         "AppraisalsAndDevotedProperty",
         8,
     ),
-    ("Diverse Species", "הלכות כלאים", "DiverseSpecies", 10),
-    (
-        "Gifts to the Poor",
-        "הלכות מתנות עניים",
-        "GiftsToThePoor",
-        10,
-    ),
-    ("Heave Offerings", "הלכות תרומות", "HeaveOfferings", 15),
-    ("Tithes", "הלכות מעשרות", "Tithes", 14),
+    ("Diverse Species", "DiverseSpecies", 10),
+    ("Gifts to the Poor", "GiftsToThePoor", 10),
+    ("Heave Offerings", "HeaveOfferings", 15),
+    ("Tithes", "Tithes", 14),
     (
         "Second Tithes and Fourth Year's Fruit",
-        "הלכות מעשר שני ונטע רבעי",
         "SecondTithesAndFourthYearsFruit",
         11,
     ),
     (
         "First Fruits and other Gifts to Priests Outside the Sanctuary",
-        "הלכות ביכורים ושאר מתנות כהונה שבגבולין",
         "FirstFruitsAndOtherGiftsToPriestsOutsideTheSanctuary",
         12,
     ),
     (
         "Sabbatical Year and the Jubilee",
-        "הלכות שמיטה ויובל",
         "SabbaticalYearAndTheJubilee",
         13,
     ),
-    (
-        "The Chosen Temple",
-        "הלכות בית הבחירה",
-        "TheChosenTemple",
-        8,
-    ),
+    ("The Chosen Temple", "TheChosenTemple", 8),
     (
         "Vessels of the Sanctuary and Those who Serve Therein",
-        "הלכות כלי המקדש והעובדין בו",
         "VesselsOfTheSanctuaryAndThoseWhoServeTherein",
         10,
     ),
     (
         "Admission into the Sanctuary",
-        "הלכות ביאת מקדש",
         "AdmissionIntoTheSanctuary",
         9,
     ),
     (
         "Things Forbidden on the Altar",
-        "הלכות איסורי המזבח",
         "ThingsForbiddenOnTheAltar",
         7,
     ),
-    (
-        "Sacrificial Procedure",
-        "הלכות מעשה הקרבנות",
-        "SacrificialProcedure",
-        19,
-    ),
+    ("Sacrificial Procedure", "SacrificialProcedure", 19),
     (
         "Daily Offerings and Additional Offerings",
-        "הלכות תמידים ומוספין",
         "DailyOfferingsAndAdditionalOfferings",
         10,
     ),
-    (
-        "Sacrifices Rendered Unfit",
-        "הלכות פסולי המוקדשין",
-        "SacrificesRenderedUnfit",
-        19,
-    ),
+    ("Sacrifices Rendered Unfit", "SacrificesRenderedUnfit", 19),
     (
         "Service on the Day of Atonement",
-        "הלכות עבודת יום הכפורים",
         "ServiceOnTheDayOfAtonement",
         5,
     ),
-    ("Trespass", "הלכות מעילה", "Trespass", 8),
-    ("Paschal Offering", "הלכות קרבן פסח", "PaschalOffering", 10),
-    ("Festival Offering", "הלכות חגיגה", "FestivalOffering", 3),
-    ("Firstlings", "הלכות בכורות", "Firstlings", 8),
+    ("Trespass", "Trespass", 8),
+    ("Paschal Offering", "PaschalOffering", 10),
+    ("Festival Offering", "FestivalOffering", 3),
+    ("Firstlings", "Firstlings", 8),
     (
         "Offerings for Unintentional Transgressions",
-        "הלכות שגגות",
         "OfferingsForUnintentionalTransgressions",
         15,
     ),
     (
         "Offerings for Those with Incomplete Atonement",
-        "הלכות מחוסרי כפרה",
         "OfferingsForThoseWithIncompleteAtonement",
         5,
     ),
-    ("Substitution", "הלכות תמורה", "Substitution", 4),
-    (
-        "Defilement by a Corpse",
-        "הלכות טומאת מת",
-        "DefilementByACorpse",
-        25,
-    ),
-    ("Red Heifer", "הלכות פרה אדומה", "RedHeifer", 15),
-    (
-        "Defilement by Leprosy",
-        "הלכות טומאת צרעת",
-        "DefilementByLeprosy",
-        16,
-    ),
+    ("Substitution", "Substitution", 4),
+    ("Defilement by a Corpse", "DefilementByACorpse", 25),
+    ("Red Heifer", "RedHeifer", 15),
+    ("Defilement by Leprosy", "DefilementByLeprosy", 16),
     (
         "Those Who Defile Bed or Seat",
-        "הלכות מטמאי משכב ומושב",
         "ThoseWhoDefileBedOrSeat",
         13,
     ),
     (
         "Other Sources of Defilement",
-        "הלכות שאר אבות הטומאות",
         "OtherSourcesOfDefilement",
         20,
     ),
-    (
-        "Defilement of Foods",
-        "הלכות טומאת אוכלים",
-        "DefilementOfFoods",
-        16,
-    ),
-    ("Vessels", "הלכות כלים", "Vessels", 28),
-    ("Immersion Pools", "הלכות מקואות", "ImmersionPools", 11),
-    (
-        "Damages to Property",
-        "הלכות נזקי ממון",
-        "DamagesToProperty",
-        14,
-    ),
-    ("Theft", "הלכות גניבה", "Theft", 9),
-    (
-        "Robbery and Lost Property",
-        "הלכות גזילה ואבידה",
-        "RobberyAndLostProperty",
-        18,
-    ),
+    ("Defilement of Foods", "DefilementOfFoods", 16),
+    ("Vessels", "Vessels", 28),
+    ("Immersion Pools", "ImmersionPools", 11),
+    ("Damages to Property", "DamagesToProperty", 14),
+    ("Theft", "Theft", 9),
+    ("Robbery and Lost Property", "RobberyAndLostProperty", 18),
     (
         "One Who Injures a Person or Property",
-        "הלכות חובל ומזיק",
         "OneWhoInjuresAPersonOrProperty",
         8,
     ),
     (
         "Murderer and the Preservation of Life",
-        "הלכות רוצח ושמירת נפש",
         "MurdererAndThePreservationOfLife",
         13,
     ),
-    ("Sales", "הלכות מכירה", "Sales", 30),
+    ("Sales", "Sales", 30),
     (
         "Ownerless Property and Gifts",
-        "הלכות זכייה ומתנה",
         "OwnerlessPropertyAndGifts",
         12,
     ),
-    ("Neighbors", "הלכות שכנים", "Neighbors", 14),
-    (
-        "Agents and Partners",
-        "הלכות שלוחין ושותפין",
-        "AgentsAndPartners",
-        10,
-    ),
-    ("Slaves", "הלכות עבדים", "Slaves", 9),
-    ("Hiring", "הלכות שכירות", "Hiring", 13),
-    (
-        "Borrowing and Deposit",
-        "הלכות שאלה ופיקדון",
-        "BorrowingAndDeposit",
-        8,
-    ),
-    (
-        "Creditor and Debtor",
-        "הלכות מלווה ולווה",
-        "CreditorAndDebtor",
-        27,
-    ),
-    (
-        "Plaintiff and Defendant",
-        "הלכות טוען ונטען",
-        "PlaintiffAndDefendant",
-        16,
-    ),
-    ("Inheritances", "הלכות נחלות", "Inheritances", 11),
+    ("Neighbors", "Neighbors", 14),
+    ("Agents and Partners", "AgentsAndPartners", 10),
+    ("Slaves", "Slaves", 9),
+    ("Hiring", "Hiring", 13),
+    ("Borrowing and Deposit", "BorrowingAndDeposit", 8),
+    ("Creditor and Debtor", "CreditorAndDebtor", 27),
+    ("Plaintiff and Defendant", "PlaintiffAndDefendant", 16),
+    ("Inheritances", "Inheritances", 11),
     (
         "The Sanhedrin and the Penalties within their Jurisdiction",
-        "הלכות סנהדרין והעונשין המסורין להם",
         "TheSanhedrinAndThePenaltiesWithinTheirJurisdiction",
         26,
     ),
-    ("Testimony", "הלכות עדות", "Testimony", 22),
-    ("Rebels", "הלכות ממרים", "Rebels", 7),
-    ("Mourning", "הלכות אבל", "Mourning", 14),
-    ("Kings and Wars", "הלכות מלכים ומלחמות", "KingsAndWars", 12),
+    ("Testimony", "Testimony", 22),
+    ("Rebels", "Rebels", 7),
+    ("Mourning", "Mourning", 14),
+    ("Kings and Wars", "KingsAndWars", 12),
 ];
 
-pub const YERUSHALMI: [(&str, &str, &str, u8); 39] = [
-    (
-        "Jerusalem Talmud Berakhot",
-        "תלמוד ירושלמי ברכות",
-        "JerusalemTalmudBerakhot",
-        68,
-    ),
-    (
-        "Jerusalem Talmud Peah",
-        "תלמוד ירושלמי פאה",
-        "JerusalemTalmudPeah",
-        37,
-    ),
-    (
-        "Jerusalem Talmud Demai",
-        "תלמוד ירושלמי דמאי",
-        "JerusalemTalmudDemai",
-        34,
-    ),
-    (
-        "Jerusalem Talmud Kilayim",
-        "תלמוד ירושלמי כלאים",
-        "JerusalemTalmudKilayim",
-        44,
-    ),
-    (
-        "Jerusalem Talmud Shevi'it",
-        "תלמוד ירושלמי שביעית",
-        "JerusalemTalmudSheviit",
-        31,
-    ),
-    (
-        "Jerusalem Talmud Terumot",
-        "תלמוד ירושלמי תרומות",
-        "JerusalemTalmudTerumot",
-        59,
-    ),
-    (
-        "Jerusalem Talmud Ma'asrot",
-        "תלמוד ירושלמי מעשרות",
-        "JerusalemTalmudMaasrot",
-        26,
-    ),
+pub const YERUSHALMI: [(&str, &str, u8); 39] = [
+    ("Jerusalem Talmud Berakhot", "JerusalemTalmudBerakhot", 68),
+    ("Jerusalem Talmud Peah", "JerusalemTalmudPeah", 37),
+    ("Jerusalem Talmud Demai", "JerusalemTalmudDemai", 34),
+    ("Jerusalem Talmud Kilayim", "JerusalemTalmudKilayim", 44),
+    ("Jerusalem Talmud Shevi'it", "JerusalemTalmudSheviit", 31),
+    ("Jerusalem Talmud Terumot", "JerusalemTalmudTerumot", 59),
+    ("Jerusalem Talmud Ma'asrot", "JerusalemTalmudMaasrot", 26),
     (
         "Jerusalem Talmud Ma'aser Sheni",
-        "תלמוד ירושלמי מעשר שני",
         "JerusalemTalmudMaaserSheni",
         33,
     ),
-    (
-        "Jerusalem Talmud Hallah",
-        "תלמוד ירושלמי חלה",
-        "JerusalemTalmudHallah",
-        28,
-    ),
-    (
-        "Jerusalem Talmud Orlah",
-        "תלמוד ירושלמי ערלה",
-        "JerusalemTalmudOrlah",
-        20,
-    ),
-    (
-        "Jerusalem Talmud Bikkurim",
-        "תלמוד ירושלמי בכורים",
-        "JerusalemTalmudBikkurim",
-        13,
-    ),
-    (
-        "Jerusalem Talmud Shabbat",
-        "תלמוד ירושלמי שבת",
-        "JerusalemTalmudShabbat",
-        92,
-    ),
-    (
-        "Jerusalem Talmud Eruvin",
-        "תלמוד ירושלמי עירובין",
-        "JerusalemTalmudEruvin",
-        65,
-    ),
-    (
-        "Jerusalem Talmud Pesachim",
-        "תלמוד ירושלמי פסחים",
-        "JerusalemTalmudPesachim",
-        71,
-    ),
-    (
-        "Jerusalem Talmud Beitzah",
-        "תלמוד ירושלמי ביצה",
-        "JerusalemTalmudBeitzah",
-        22,
-    ),
+    ("Jerusalem Talmud Hallah", "JerusalemTalmudHallah", 28),
+    ("Jerusalem Talmud Orlah", "JerusalemTalmudOrlah", 20),
+    ("Jerusalem Talmud Bikkurim", "JerusalemTalmudBikkurim", 13),
+    ("Jerusalem Talmud Shabbat", "JerusalemTalmudShabbat", 92),
+    ("Jerusalem Talmud Eruvin", "JerusalemTalmudEruvin", 65),
+    ("Jerusalem Talmud Pesachim", "JerusalemTalmudPesachim", 71),
+    ("Jerusalem Talmud Beitzah", "JerusalemTalmudBeitzah", 22),
     (
         "Jerusalem Talmud Rosh Hashanah",
-        "תלמוד ירושלמי ראש השנה",
         "JerusalemTalmudRoshHashanah",
         22,
     ),
-    (
-        "Jerusalem Talmud Yoma",
-        "תלמוד ירושלמי יומא",
-        "JerusalemTalmudYoma",
-        42,
-    ),
-    (
-        "Jerusalem Talmud Sukkah",
-        "תלמוד ירושלמי סוכה",
-        "JerusalemTalmudSukkah",
-        26,
-    ),
-    (
-        "Jerusalem Talmud Ta'anit",
-        "תלמוד ירושלמי תענית",
-        "JerusalemTalmudTaanit",
-        26,
-    ),
-    (
-        "Jerusalem Talmud Shekalim",
-        "תלמוד ירושלמי שקלים",
-        "JerusalemTalmudShekalim",
-        33,
-    ),
-    (
-        "Jerusalem Talmud Megillah",
-        "תלמוד ירושלמי מגילה",
-        "JerusalemTalmudMegillah",
-        34,
-    ),
-    (
-        "Jerusalem Talmud Chagigah",
-        "תלמוד ירושלמי חגיגה",
-        "JerusalemTalmudChagigah",
-        22,
-    ),
+    ("Jerusalem Talmud Yoma", "JerusalemTalmudYoma", 42),
+    ("Jerusalem Talmud Sukkah", "JerusalemTalmudSukkah", 26),
+    ("Jerusalem Talmud Ta'anit", "JerusalemTalmudTaanit", 26),
+    ("Jerusalem Talmud Shekalim", "JerusalemTalmudShekalim", 33),
+    ("Jerusalem Talmud Megillah", "JerusalemTalmudMegillah", 34),
+    ("Jerusalem Talmud Chagigah", "JerusalemTalmudChagigah", 22),
     (
         "Jerusalem Talmud Moed Kattan",
-        "תלמוד ירושלמי מועד קטן",
         "JerusalemTalmudMoedKattan",
         19,
     ),
-    (
-        "Jerusalem Talmud Yevamot",
-        "תלמוד ירושלמי יבמות",
-        "JerusalemTalmudYevamot",
-        85,
-    ),
-    (
-        "Jerusalem Talmud Ketubot",
-        "תלמוד ירושלמי כתובות",
-        "JerusalemTalmudKetubot",
-        72,
-    ),
-    (
-        "Jerusalem Talmud Sotah",
-        "תלמוד ירושלמי סוטה",
-        "JerusalemTalmudSotah",
-        47,
-    ),
-    (
-        "Jerusalem Talmud Nedarim",
-        "תלמוד ירושלמי נדרים",
-        "JerusalemTalmudNedarim",
-        40,
-    ),
-    (
-        "Jerusalem Talmud Nazir",
-        "תלמוד ירושלמי נזיר",
-        "JerusalemTalmudNazir",
-        47,
-    ),
-    (
-        "Jerusalem Talmud Gittin",
-        "תלמוד ירושלמי גיטין",
-        "JerusalemTalmudGittin",
-        54,
-    ),
-    (
-        "Jerusalem Talmud Kiddushin",
-        "תלמוד ירושלמי קידושין",
-        "JerusalemTalmudKiddushin",
-        48,
-    ),
+    ("Jerusalem Talmud Yevamot", "JerusalemTalmudYevamot", 85),
+    ("Jerusalem Talmud Ketubot", "JerusalemTalmudKetubot", 72),
+    ("Jerusalem Talmud Sotah", "JerusalemTalmudSotah", 47),
+    ("Jerusalem Talmud Nedarim", "JerusalemTalmudNedarim", 40),
+    ("Jerusalem Talmud Nazir", "JerusalemTalmudNazir", 47),
+    ("Jerusalem Talmud Gittin", "JerusalemTalmudGittin", 54),
+    ("Jerusalem Talmud Kiddushin", "JerusalemTalmudKiddushin", 48),
     (
         "Jerusalem Talmud Bava Kamma",
-        "תלמוד ירושלמי בבא קמא",
         "JerusalemTalmudBavaKamma",
         44,
     ),
     (
         "Jerusalem Talmud Bava Metsia",
-        "תלמוד ירושלמי בבא מציעא",
         "JerusalemTalmudBavaMetsia",
         37,
     ),
     (
         "Jerusalem Talmud Bava Batra",
-        "תלמוד ירושלמי בבא בתרא",
         "JerusalemTalmudBavaBatra",
         34,
     ),
-    (
-        "Jerusalem Talmud Shevuot",
-        "תלמוד ירושלמי שבועות",
-        "JerusalemTalmudShevuot",
-        44,
-    ),
-    (
-        "Jerusalem Talmud Makkot",
-        "תלמוד ירושלמי מכות",
-        "JerusalemTalmudMakkot",
-        9,
-    ),
-    (
-        "Jerusalem Talmud Sanhedrin",
-        "תלמוד ירושלמי סנהדרין",
-        "JerusalemTalmudSanhedrin",
-        57,
-    ),
+    ("Jerusalem Talmud Shevuot", "JerusalemTalmudShevuot", 44),
+    ("Jerusalem Talmud Makkot", "JerusalemTalmudMakkot", 9),
+    ("Jerusalem Talmud Sanhedrin", "JerusalemTalmudSanhedrin", 57),
     (
         "Jerusalem Talmud Avodah Zarah",
-        "תלמוד ירושלמי עבודה זרה",
         "JerusalemTalmudAvodahZarah",
         37,
     ),
-    (
-        "Jerusalem Talmud Horayot",
-        "תלמוד ירושלמי הוריות",
-        "JerusalemTalmudHorayot",
-        19,
-    ),
-    (
-        "Jerusalem Talmud Niddah",
-        "תלמוד ירושלמי נדה",
-        "JerusalemTalmudNiddah",
-        13,
-    ),
+    ("Jerusalem Talmud Horayot", "JerusalemTalmudHorayot", 19),
+    ("Jerusalem Talmud Niddah", "JerusalemTalmudNiddah", 13),
 ];
 
 #[test]
 fn ensure_right_amount_of_yerushalmi_daf() {
     assert_eq!(
-        YERUSHALMI.iter().map(|x| x.3 as u64).sum::<u64>(),
+        YERUSHALMI.iter().map(|x| x.2 as u64).sum::<u64>(),
         1563 /*Days Between 23 Av 5778 and 19th Cheshvan 5783*/ - 5 /* Yom Kippur*/ - 4 /* Tisha BeAv*/
     );
 }
@@ -901,7 +578,7 @@ fn ensure_right_amount_of_daf_first_cycle() {
     assert_eq!(
         GEMARAS_FIRST_CYCLE
             .iter()
-            .fold(0, |old, new| { old + new.3 as u64 - 1 }),
+            .fold(0, |old, new| { old + new.2 as u64 - 1 }),
         2702
     );
 }
@@ -911,7 +588,7 @@ fn ensure_right_amount_of_daf_second_cycle() {
     assert_eq!(
         GEMARAS_SECOND_CYCLE
             .iter()
-            .fold(0, |old, new| { old + new.3 as u64 - 1 }),
+            .fold(0, |old, new| { old + new.2 as u64 - 1 }),
         2711
     );
 }
@@ -929,7 +606,7 @@ fn ensure_right_amount_of_perakim() {
     assert_eq!(
         RAMBAM
             .iter()
-            .fold(0, |old, rambam| { old + rambam.3 as i64 }),
+            .fold(0, |old, rambam| { old + rambam.2 as i64 }),
         ((last_day_of_rambam_5780 - day_one_of_rambam_5779).num_days() + 1) * 3
     );
 }
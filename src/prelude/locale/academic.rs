@@ -0,0 +1,169 @@
+//! Academic spellings of the English names (Ḥanukkah, Lekh Lekha, Rosh ha-Shanah). Everything it
+//! doesn't have is in the Sephardi catalog.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("In Chutz La'aretz", "In Ḥuts La'aretz"),
+    // Hebrew months
+    ("Tishrei", "Tishri"),
+    ("Cheshvan", "Ḥeshvan"),
+    ("Nissan", "Nisan"),
+    ("Iyar", "Iyyar"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "1st day of Rosh ha-Shanah"),
+    ("2nd day of Rosh Hashanah", "2nd day of Rosh ha-Shanah"),
+    ("Shmini Atzeres", "Shemini Atseret"),
+    ("Simchas Torah", "Simḥat Torah"),
+    ("1st day of Pesach", "1st day of Pesaḥ"),
+    ("2nd day of Pesach", "2nd day of Pesaḥ"),
+    ("3rd day of Pesach", "3rd day of Pesaḥ"),
+    ("4th day of Pesach", "4th day of Pesaḥ"),
+    ("5th day of Pesach", "5th day of Pesaḥ"),
+    ("6th day of Pesach", "6th day of Pesaḥ"),
+    ("7th day of Pesach", "7th day of Pesaḥ"),
+    ("8th day of Pesach", "8th day of Pesaḥ"),
+    ("1st day of Shavuos", "1st day of Shavu'ot"),
+    ("2nd day of Shavuos", "2nd day of Shavu'ot"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "1st day of Rosh Ḥodesh Ḥeshvan"),
+    ("2nd day of Rosh Chodesh Cheshvan", "2nd day of Rosh Ḥodesh Ḥeshvan"),
+    ("Rosh Chodesh Kislev", "Rosh Ḥodesh Kislev"),
+    ("1st day of Rosh Chodesh Kislev", "1st day of Rosh Ḥodesh Kislev"),
+    ("2nd day of Rosh Chodesh Kislev", "2nd day of Rosh Ḥodesh Kislev"),
+    ("Rosh Chodesh Teves", "Rosh Ḥodesh Tevet"),
+    ("1st day of Rosh Chodesh Teves", "1st day of Rosh Ḥodesh Tevet"),
+    ("2nd day of Rosh Chodesh Teves", "2nd day of Rosh Ḥodesh Tevet"),
+    ("Rosh Chodesh Shvat", "Rosh Ḥodesh Shevat"),
+    ("1st day of Rosh Chodesh Adar", "1st day of Rosh Ḥodesh Adar"),
+    ("2nd day of Rosh Chodesh Adar", "2nd day of Rosh Ḥodesh Adar"),
+    ("1st day of Rosh Chodesh Adar Rishon", "1st day of Rosh Ḥodesh Adar Rishon"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "2nd day of Rosh Ḥodesh Adar Rishon"),
+    ("1st day of Rosh Chodesh Adar Sheni", "1st day of Rosh Ḥodesh Adar Sheni"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "2nd day of Rosh Ḥodesh Adar Sheni"),
+    ("Rosh Chodesh Nissan", "Rosh Ḥodesh Nisan"),
+    ("1st day of Rosh Chodesh Iyar", "1st day of Rosh Ḥodesh Iyyar"),
+    ("2nd day of Rosh Chodesh Iyar", "2nd day of Rosh Ḥodesh Iyyar"),
+    ("Rosh Chodesh Sivan", "Rosh Ḥodesh Sivan"),
+    ("1st day of Rosh Chodesh Tammuz", "1st day of Rosh Ḥodesh Tammuz"),
+    ("2nd day of Rosh Chodesh Tammuz", "2nd day of Rosh Ḥodesh Tammuz"),
+    ("Rosh Chodesh Av", "Rosh Ḥodesh Av"),
+    ("1st day of Rosh Chodesh Elul", "1st day of Rosh Ḥodesh Elul"),
+    ("2nd day of Rosh Chodesh Elul", "2nd day of Rosh Ḥodesh Elul"),
+    ("1st day of Chanukah", "1st day of Ḥanukkah"),
+    ("2nd day of Chanukah", "2nd day of Ḥanukkah"),
+    ("3rd day of Chanukah", "3rd day of Ḥanukkah"),
+    ("4rd day of Chanukah", "4rd day of Ḥanukkah"),
+    ("5rd day of Chanukah", "5rd day of Ḥanukkah"),
+    ("6rd day of Chanukah", "6rd day of Ḥanukkah"),
+    ("7rd day of Chanukah", "7rd day of Ḥanukkah"),
+    ("8rd day of Chanukah", "8rd day of Ḥanukkah"),
+    ("Tzom Gedalia", "Tsom Gedalyah"),
+    ("Taanis Esther", "Ta'anit Esther"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Haazinu", "Ha'azinu"),
+    ("Vayelech", "Vayelekh"),
+    ("Noach", "Noaḥ"),
+    ("Lech Lecha", "Lekh Lekha"),
+    ("Chayei Sarah", "Ḥayyei Sarah"),
+    ("Vayetzei", "Vayetse"),
+    ("Vayishlach", "Vayishlaḥ"),
+    ("Miketz", "Mikets"),
+    ("Vayechi", "Vayeḥi"),
+    ("Beshalach", "Beshallaḥ"),
+    ("Tetzaveh", "Tetsavveh"),
+    ("Tzav", "Tsav"),
+    ("Tazriya/Metzorah", "Tazria/Metsora"),
+    ("Metzorah", "Metsora"),
+    ("Acharei Mos/Kedoshim", "Aḥarei Mot/Kedoshim"),
+    ("Acharei Mos", "Aḥarei Mot"),
+    ("Behar/Bechukosai", "Behar/Beḥukkotai"),
+    ("Bechukosai", "Beḥukkotai"),
+    ("Bamidbar", "Ba-midbar"),
+    ("Behaaloscha", "Beha'alotekha"),
+    ("Shlach", "Shelaḥ"),
+    ("Korach", "Koraḥ"),
+    ("Chukas/Balak", "Ḥukkat/Balak"),
+    ("Chukas", "Ḥukkat"),
+    ("Pinchas", "Pinḥas"),
+    ("Matos/Maasei", "Matot/Mas'ei"),
+    ("Maasei", "Mas'ei"),
+    ("Vaeschanan", "Va'etḥannan"),
+    ("Shoftim", "Shofetim"),
+    ("Ki Seitzei", "Ki Tetse"),
+    ("Nitzavim/Vayelech", "Nitsavim/Vayelekh"),
+    ("Nitzavim", "Nitsavim"),
+    // Special parshiyos
+    ("Parshas Zachor", "Parashat Zakhor"),
+    ("Parshas HaChodesh", "Parashat ha-Ḥodesh"),
+    // Books of Navi (Shoftim is with the parshiyos)
+    ("Melachim I", "Melakhim I"),
+    ("Melachim II", "Melakhim II"),
+    ("Yechezkel", "Yeḥezkel"),
+    ("Micha", "Mikhah"),
+    ("Chavakuk", "Ḥavakkuk"),
+    ("Zecharia", "Zekharyah"),
+    ("Malachi", "Malakhi"),
+    // Minor holidays
+    ("Erev Pesach", "Erev Pesaḥ"),
+    ("Erev Shavuos", "Erev Shavu'ot"),
+    ("Erev Rosh Hashana", "Erev Rosh ha-Shanah"),
+    ("Pesach Sheni", "Pesaḥ Sheni"),
+    ("Lag BaOmer", "Lag ba-Omer"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "Purim Meshulash: Al ha-Nissim and the Torah reading of Purim"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "Purim Meshulash: Purim feast and mishloaḥ manot"),
+    ("Shabbos HaGadol", "Shabbat ha-Gadol"),
+    ("Taanis Bechoros", "Ta'anit Bekhorot"),
+    ("Shabbos Chazon", "Shabbat Ḥazon"),
+    ("Shabbos Nachamu", "Shabbat Naḥamu"),
+    ("Leil Slichos", "Leil Seliḥot"),
+    ("Shabbos Rosh Chodesh", "Shabbat Rosh Ḥodesh"),
+    ("Shabbos Machar Chodesh", "Shabbat Maḥar Ḥodesh"),
+    ("Shabbos Chanukah", "Shabbat Ḥanukkah"),
+    ("Second Shabbos Chanukah", "Second Shabbat Ḥanukkah"),
+    ("Shabbos Chol HaMoed Sukkos", "Shabbat Ḥol ha-Mo'ed Sukkot"),
+    ("Shabbos Chol HaMoed Pesach", "Shabbat Ḥol ha-Mo'ed Pesaḥ"),
+    ("Shabbos Zachor", "Shabbat Zakhor"),
+    ("Shabbos HaChodesh", "Shabbat ha-Ḥodesh"),
+    ("Shabbos Mevarchim", "Shabbat Mevarekhim"),
+    // Modern Israeli holidays
+    ("Yom HaAtzmaut", "Yom ha-Atsma'ut"),
+    ("Yom HaZikaron", "Yom ha-Zikkaron"),
+    ("Yom HaShoah", "Yom ha-Shoah"),
+    ("Yom HaAliyah", "Yom ha-Aliyah"),
+    ("Yom HaAliyah (10 Nissan)", "Yom ha-Aliyah (10 Nisan)"),
+    ("Yitzhak Rabin Memorial Day", "Yitsḥak Rabin Memorial Day"),
+    // Chabad holidays
+    ("Vav Tishrei", "Vav Tishri"),
+    ("Chof Kislev", "Kaf Kislev"),
+    ("Hei Teves", "He Tevet"),
+    ("Chof Daled Teves", "Kaf Dalet Tevet"),
+    ("Chof Beis Shvat", "Kaf Bet Shevat"),
+    ("Yud Aleph Nissan", "Yud Aleph Nisan"),
+    ("Gimmel Tammuz", "Gimel Tammuz"),
+    ("Yud Gimmel Tammuz", "Yud Gimel Tammuz"),
+    ("Chof Av", "Kaf Av"),
+    ("Chai Elul", "Ḥai Elul"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "Shabbat Mevarekhim {0}: Molad is on {1} {2} {3}, {4} and {5} Ḥalakim ({6} Jerusalem standard time). {7}"),
+    ("Rosh Chodesh {} will be on {}", "Rosh Ḥodesh {} will be on {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "Rosh Ḥodesh {} will be on {} and {}"),
+    // Rare events
+    ("Birkas HaChama", "Birkat ha-Ḥammah"),
+    ("Erev Pesach on Shabbos", "Erev Pesaḥ on Shabbat"),
+    // day-info
+    ("Tachanun is said at Shacharis and Mincha.", "Taḥanun is said at Shaḥarit and Minḥah."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "Taḥanun is said at Shaḥarit but not at Minḥah."),
+    ("Tachanun is not said.", "Taḥanun is not said."),
+    ("Yaaleh VeYavo", "Ya'aleh ve-Yavo"),
+    ("Al HaNissim", "Al ha-Nissim"),
+    ("Mashiv HaRuach", "Mashiv ha-Ruaḥ"),
+    ("Morid HaTal", "Morid ha-Tal"),
+    ("V'sen Tal uMatar", "V'ten Tal u-Matar"),
+    // year-info
+    (" Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.", " Keviah {0} - Rosh ha-Shanah on {2}, {3}, Pesaḥ on {4}."),
+    ("Maaser Rishon and Maaser Sheni", "Ma'aser Rishon and Ma'aser Sheni"),
+    ("Maaser Rishon and Maaser Ani", "Ma'aser Rishon and Ma'aser Ani"),
+    ("Biur Maaser on Erev Pesach ({})", "Biur Ma'aser on Erev Pesaḥ ({})"),
+    // wedding-check and wedding-dates
+    ("Chol HaMoed", "Ḥol ha-Mo'ed"),
+];
@@ -0,0 +1,541 @@
+//! The Spanish catalog.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("Night of ", "Noche del "),
+    ("Candle lighting", "Encendido de velas"),
+    ("Haftarah", "Haftará"),
+    ("Maftir", "Maftir"),
+    ("In Israel", "En Israel"),
+    ("In Chutz La'aretz", "Fuera de Israel"),
+    ("Triennial year", "Año del ciclo trienal"),
+    // Gregorian dates
+    ("%A %B %-d %Y", "%A %-d de %B de %Y"),
+    ("%A %B %-d %H:%M", "%A %-d de %B %H:%M"),
+    ("Sunday", "domingo"),
+    ("Monday", "lunes"),
+    ("Tuesday", "martes"),
+    ("Wednesday", "miércoles"),
+    ("Thursday", "jueves"),
+    ("Friday", "viernes"),
+    ("Saturday", "sábado"),
+    ("January", "enero"),
+    ("February", "febrero"),
+    ("March", "marzo"),
+    ("April", "abril"),
+    ("May", "mayo"),
+    ("June", "junio"),
+    ("July", "julio"),
+    ("August", "agosto"),
+    ("September", "septiembre"),
+    ("October", "octubre"),
+    ("November", "noviembre"),
+    ("December", "diciembre"),
+    // Days of the week in the Hebrew calendar
+    ("weekday|Sunday", "domingo"),
+    ("weekday|Monday", "lunes"),
+    ("weekday|Tuesday", "martes"),
+    ("weekday|Wednesday", "miércoles"),
+    ("weekday|Thursday", "jueves"),
+    ("weekday|Friday", "viernes"),
+    ("weekday|Shabbos", "Shabat"),
+    ("Tishrei", "Tishrei"),
+    // Hebrew months
+    ("Cheshvan", "Jeshván"),
+    ("Kislev", "Kislev"),
+    ("Teves", "Tevet"),
+    ("Shvat", "Shevat"),
+    ("Adar", "Adar"),
+    ("Adar Rishon", "Adar Rishón"),
+    ("Adar Sheni", "Adar Sheni"),
+    ("Nissan", "Nisán"),
+    ("Iyar", "Iyar"),
+    ("Sivan", "Siván"),
+    ("Tammuz", "Tamuz"),
+    ("Av", "Av"),
+    ("Elul", "Elul"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "1º día de Rosh Hashaná"),
+    ("2nd day of Rosh Hashanah", "2º día de Rosh Hashaná"),
+    ("Yom Kippur", "Yom Kipur"),
+    ("1st day of Sukkos", "1º día de Sucot"),
+    ("2nd day of Sukkos", "2º día de Sucot"),
+    ("3rd day of Sukkos", "3º día de Sucot"),
+    ("4th day of Sukkos", "4º día de Sucot"),
+    ("5th day of Sukkos", "5º día de Sucot"),
+    ("6th day of Sukkos", "6º día de Sucot"),
+    ("7th day of Sukkos", "7º día de Sucot"),
+    ("Shmini Atzeres", "Sheminí Atséret"),
+    ("Simchas Torah", "Simjat Torá"),
+    ("1st day of Pesach", "1º día de Pésaj"),
+    ("2nd day of Pesach", "2º día de Pésaj"),
+    ("3rd day of Pesach", "3º día de Pésaj"),
+    ("4th day of Pesach", "4º día de Pésaj"),
+    ("5th day of Pesach", "5º día de Pésaj"),
+    ("6th day of Pesach", "6º día de Pésaj"),
+    ("7th day of Pesach", "7º día de Pésaj"),
+    ("8th day of Pesach", "8º día de Pésaj"),
+    ("1st day of Shavuos", "1º día de Shavuot"),
+    ("2nd day of Shavuos", "2º día de Shavuot"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "1º día de Rosh Jódesh Jeshván"),
+    ("2nd day of Rosh Chodesh Cheshvan", "2º día de Rosh Jódesh Jeshván"),
+    ("Rosh Chodesh Kislev", "Rosh Jódesh Kislev"),
+    ("1st day of Rosh Chodesh Kislev", "1º día de Rosh Jódesh Kislev"),
+    ("2nd day of Rosh Chodesh Kislev", "2º día de Rosh Jódesh Kislev"),
+    ("Rosh Chodesh Teves", "Rosh Jódesh Tevet"),
+    ("1st day of Rosh Chodesh Teves", "1º día de Rosh Jódesh Tevet"),
+    ("2nd day of Rosh Chodesh Teves", "2º día de Rosh Jódesh Tevet"),
+    ("Rosh Chodesh Shvat", "Rosh Jódesh Shevat"),
+    ("1st day of Rosh Chodesh Adar", "1º día de Rosh Jódesh Adar"),
+    ("2nd day of Rosh Chodesh Adar", "2º día de Rosh Jódesh Adar"),
+    ("1st day of Rosh Chodesh Adar Rishon", "1º día de Rosh Jódesh Adar Rishón"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "2º día de Rosh Jódesh Adar Rishón"),
+    ("1st day of Rosh Chodesh Adar Sheni", "1º día de Rosh Jódesh Adar Sheni"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "2º día de Rosh Jódesh Adar Sheni"),
+    ("Rosh Chodesh Nissan", "Rosh Jódesh Nisán"),
+    ("1st day of Rosh Chodesh Iyar", "1º día de Rosh Jódesh Iyar"),
+    ("2nd day of Rosh Chodesh Iyar", "2º día de Rosh Jódesh Iyar"),
+    ("Rosh Chodesh Sivan", "Rosh Jódesh Siván"),
+    ("1st day of Rosh Chodesh Tammuz", "1º día de Rosh Jódesh Tamuz"),
+    ("2nd day of Rosh Chodesh Tammuz", "2º día de Rosh Jódesh Tamuz"),
+    ("Rosh Chodesh Av", "Rosh Jódesh Av"),
+    ("1st day of Rosh Chodesh Elul", "1º día de Rosh Jódesh Elul"),
+    ("2nd day of Rosh Chodesh Elul", "2º día de Rosh Jódesh Elul"),
+    ("1st day of Chanukah", "1º día de Janucá"),
+    ("2nd day of Chanukah", "2º día de Janucá"),
+    ("3rd day of Chanukah", "3º día de Janucá"),
+    ("4rd day of Chanukah", "4º día de Janucá"),
+    ("5rd day of Chanukah", "5º día de Janucá"),
+    ("6rd day of Chanukah", "6º día de Janucá"),
+    ("7rd day of Chanukah", "7º día de Janucá"),
+    ("8rd day of Chanukah", "8º día de Janucá"),
+    ("Tzom Gedalia", "Ayuno de Guedalía"),
+    ("Taanis Esther", "Ayuno de Ester"),
+    ("Tenth of Teves", "10 de Tevet"),
+    ("Purim", "Purim"),
+    ("Shushan Purim", "Shushán Purim"),
+    ("Seventeenth of Tammuz", "17 de Tamuz"),
+    ("Ninth of Av", "9 de Av"),
+    ("Haazinu", "Haazinu"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Vayelech", "Vayélej"),
+    ("Bereishis", "Bereshit"),
+    ("Noach", "Noaj"),
+    ("Lech Lecha", "Lej Lejá"),
+    ("Vayeira", "Vayerá"),
+    ("Chayei Sarah", "Jayé Sará"),
+    ("Toldos", "Toledot"),
+    ("Vayetzei", "Vayetsé"),
+    ("Vayishlach", "Vayishlaj"),
+    ("Vayeshev", "Vayeshev"),
+    ("Miketz", "Mikets"),
+    ("Vayigash", "Vayigash"),
+    ("Vayechi", "Vayejí"),
+    ("Shemos", "Shemot"),
+    ("Vaeira", "Vaerá"),
+    ("Bo", "Bo"),
+    ("Beshalach", "Beshalaj"),
+    ("Yisro", "Itró"),
+    ("Mishpatim", "Mishpatim"),
+    ("Terumah", "Terumá"),
+    ("Tetzaveh", "Tetsavé"),
+    ("Ki Sisa", "Ki Tisá"),
+    ("Vayakhel/Pikudei", "Vayakhel/Pekudé"),
+    ("Vayakhel", "Vayakhel"),
+    ("Pikudei", "Pekudé"),
+    ("Vayikra", "Vayikrá"),
+    ("Tzav", "Tsav"),
+    ("Shemini", "Sheminí"),
+    ("Tazriya/Metzorah", "Tazría/Metsorá"),
+    ("Tazriya", "Tazría"),
+    ("Metzorah", "Metsorá"),
+    ("Acharei Mos/Kedoshim", "Ajaré Mot/Kedoshim"),
+    ("Acharei Mos", "Ajaré Mot"),
+    ("Kedoshim", "Kedoshim"),
+    ("Emor", "Emor"),
+    ("Behar/Bechukosai", "Behar/Bejukotai"),
+    ("Behar", "Behar"),
+    ("Bechukosai", "Bejukotai"),
+    ("Bamidbar", "Bamidbar"),
+    ("Naso", "Nasó"),
+    ("Behaaloscha", "Behaalotjá"),
+    ("Shlach", "Shelaj"),
+    ("Korach", "Kóraj"),
+    ("Chukas/Balak", "Jukat/Balak"),
+    ("Chukas", "Jukat"),
+    ("Balak", "Balak"),
+    ("Pinchas", "Pinjás"),
+    ("Matos/Maasei", "Matot/Masé"),
+    ("Matos", "Matot"),
+    ("Maasei", "Masé"),
+    ("Devarim", "Devarim"),
+    ("Vaeschanan", "Vaetjanán"),
+    ("Eikev", "Ékev"),
+    ("Re'eh", "Reé"),
+    ("Shoftim", "Shoftim"),
+    ("Ki Seitzei", "Ki Tetsé"),
+    ("Ki Savo", "Ki Tavó"),
+    ("Nitzavim/Vayelech", "Nitsavim/Vayélej"),
+    ("Nitzavim", "Nitsavim"),
+    // Special parshiyos
+    ("Parshas Zachor", "Parashat Zajor"),
+    ("Parshas HaChodesh", "Parashat HaJódesh"),
+    ("Parshas Parah", "Parashat Pará"),
+    ("Parshas Shekalim", "Parashat Shekalim"),
+    // Books of Navi (Shoftim is with the parshiyos)
+    ("Yehoshua", "Yehoshúa"),
+    ("Shmuel I", "Shmuel I"),
+    ("Shmuel II", "Shmuel II"),
+    ("Melachim I", "Melajim I"),
+    ("Melachim II", "Melajim II"),
+    ("Yeshayahu", "Yeshayahu"),
+    ("Yirmiyahu", "Irmiyahu"),
+    ("Yechezkel", "Yejezkel"),
+    ("Hoshea", "Hoshea"),
+    ("Yoel", "Yoel"),
+    ("Amos", "Amós"),
+    ("Ovadia", "Ovadiá"),
+    ("Micha", "Mijá"),
+    ("Chavakuk", "Javakuk"),
+    ("Zecharia", "Zejariá"),
+    ("Malachi", "Malají"),
+    // Minor holidays
+    ("1st day of the Omer", "1º día del Ómer"),
+    ("2nd day of the Omer", "2º día del Ómer"),
+    ("3rd day of the Omer", "3º día del Ómer"),
+    ("4th day of the Omer", "4º día del Ómer"),
+    ("5th day of the Omer", "5º día del Ómer"),
+    ("6th day of the Omer", "6º día del Ómer"),
+    ("7th day of the Omer", "7º día del Ómer"),
+    ("8th day of the Omer", "8º día del Ómer"),
+    ("9th day of the Omer", "9º día del Ómer"),
+    ("10th day of the Omer", "10º día del Ómer"),
+    ("11th day of the Omer", "11º día del Ómer"),
+    ("12th day of the Omer", "12º día del Ómer"),
+    ("13th day of the Omer", "13º día del Ómer"),
+    ("14th day of the Omer", "14º día del Ómer"),
+    ("15th day of the Omer", "15º día del Ómer"),
+    ("16th day of the Omer", "16º día del Ómer"),
+    ("17th day of the Omer", "17º día del Ómer"),
+    ("18th day of the Omer", "18º día del Ómer"),
+    ("19th day of the Omer", "19º día del Ómer"),
+    ("20th day of the Omer", "20º día del Ómer"),
+    ("21st day of the Omer", "21º día del Ómer"),
+    ("22nd day of the Omer", "22º día del Ómer"),
+    ("23rd day of the Omer", "23º día del Ómer"),
+    ("24th day of the Omer", "24º día del Ómer"),
+    ("25th day of the Omer", "25º día del Ómer"),
+    ("26th day of the Omer", "26º día del Ómer"),
+    ("27th day of the Omer", "27º día del Ómer"),
+    ("28th day of the Omer", "28º día del Ómer"),
+    ("29th day of the Omer", "29º día del Ómer"),
+    ("30th day of the Omer", "30º día del Ómer"),
+    ("31st day of the Omer", "31º día del Ómer"),
+    ("32nd day of the Omer", "32º día del Ómer"),
+    ("33rd day of the Omer", "33º día del Ómer"),
+    ("34th day of the Omer", "34º día del Ómer"),
+    ("35th day of the Omer", "35º día del Ómer"),
+    ("36th day of the Omer", "36º día del Ómer"),
+    ("37th day of the Omer", "37º día del Ómer"),
+    ("38th day of the Omer", "38º día del Ómer"),
+    ("39th day of the Omer", "39º día del Ómer"),
+    ("40th day of the Omer", "40º día del Ómer"),
+    ("41st day of the Omer", "41º día del Ómer"),
+    ("42nd day of the Omer", "42º día del Ómer"),
+    ("43rd day of the Omer", "43º día del Ómer"),
+    ("44th day of the Omer", "44º día del Ómer"),
+    ("45th day of the Omer", "45º día del Ómer"),
+    ("46th day of the Omer", "46º día del Ómer"),
+    ("47th day of the Omer", "47º día del Ómer"),
+    ("48th day of the Omer", "48º día del Ómer"),
+    ("49th day of the Omer", "49º día del Ómer"),
+    ("Erev Pesach", "Víspera de Pésaj"),
+    ("Erev Sukkos", "Víspera de Sucot"),
+    ("Erev Shavuos", "Víspera de Shavuot"),
+    ("Erev Yom Kippur", "Víspera de Yom Kipur"),
+    ("Erev Rosh Hashana", "Víspera de Rosh Hashaná"),
+    ("Pesach Sheni", "Pésaj Sheni"),
+    ("Lag BaOmer", "Lag BaÓmer"),
+    ("15th of Av", "Tu BeAv"),
+    ("15th of Shevat", "Tu BiShvat"),
+    ("Purim Kattan", "Purim Katán"),
+    ("Shushan Purim Kattan", "Shushán Purim Katán"),
+    ("Purim Meshulash: Megillah reading and gifts to the poor", "Purim Meshulash: lectura de la Meguilá y regalos a los pobres"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "Purim Meshulash: Al HaNisim y la lectura de la Torá de Purim"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "Purim Meshulash: banquete de Purim y mishlóaj manot"),
+    ("Shabbos HaGadol", "Shabat HaGadol"),
+    ("Taanis Bechoros", "Ayuno de los primogénitos"),
+    ("Shabbos Chazon", "Shabat Jazón"),
+    ("Shabbos Nachamu", "Shabat Najamú"),
+    ("Leil Slichos", "Noche de Selijot"),
+    ("Shabbos Shuva", "Shabat Shuvá"),
+    ("Shabbos Rosh Chodesh", "Shabat Rosh Jódesh"),
+    ("Shabbos Machar Chodesh", "Shabat Majar Jódesh"),
+    ("Shabbos Shira", "Shabat Shirá"),
+    ("Shabbos Chanukah", "Shabat Janucá"),
+    ("Second Shabbos Chanukah", "Segundo Shabat Janucá"),
+    ("Shabbos Chol HaMoed Sukkos", "Shabat Jol HaMoed Sucot"),
+    ("Shabbos Chol HaMoed Pesach", "Shabat Jol HaMoed Pésaj"),
+    ("Shabbos Shekalim", "Shabat Shekalim"),
+    ("Shabbos Zachor", "Shabat Zajor"),
+    ("Shabbos Parah", "Shabat Pará"),
+    ("Shabbos HaChodesh", "Shabat HaJódesh"),
+    ("Shabbos Mevarchim", "Shabat Mevarjim"),
+    // Modern Israeli holidays
+    ("Yom HaAtzmaut", "Yom HaAtsmaut"),
+    ("Yom HaZikaron", "Yom HaZikarón"),
+    ("Yom Yerushalayim", "Yom Yerushaláyim"),
+    ("Yom HaShoah", "Yom HaShoá"),
+    ("Yom HaAliyah", "Yom HaAliá"),
+    ("Yom HaAliyah (10 Nissan)", "Yom HaAliá (10 de Nisán)"),
+    ("Sigd", "Sigd"),
+    ("Yitzhak Rabin Memorial Day", "Día en memoria de Isaac Rabin"),
+    ("Ben-Gurion Day", "Día de Ben-Gurión"),
+    ("Hebrew Language Day", "Día del idioma hebreo"),
+    ("Family Day", "Día de la Familia"),
+    ("Herzl Day", "Día de Herzl"),
+    ("Jabotinsky Day", "Día de Jabotinsky"),
+    // Chabad holidays
+    ("Vav Tishrei", "Vav Tishrei"),
+    ("Yud Kislev", "Yud Kislev"),
+    ("Yud Tes Kislev", "Yud Tet Kislev"),
+    ("Chof Kislev", "Jof Kislev"),
+    ("Hei Teves", "Hei Tevet"),
+    ("Chof Daled Teves", "Jof Dalet Tevet"),
+    ("Yud Shvat", "Yud Shevat"),
+    ("Chof Beis Shvat", "Jof Beit Shevat"),
+    ("Yud Aleph Nissan", "Yud Alef Nisán"),
+    ("Gimmel Tammuz", "Guimel Tamuz"),
+    ("Yud Beis Tammuz", "Yud Beit Tamuz"),
+    ("Yud Gimmel Tammuz", "Yud Guimel Tamuz"),
+    ("Chof Av", "Jof Av"),
+    ("Chai Elul", "Jai Elul"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "Shabat Mevarjim {0}: el molad es el {1} {3} de {2}, {4} y {5} jalakim ({6} hora estándar de Jerusalén). {7}"),
+    ("Rosh Chodesh {} will be on {}", "Rosh Jódesh {} será el {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "Rosh Jódesh {} será el {} y el {}"),
+    ("Kiddush Levana {}: From {} until {}", "Kidush Levaná {}: desde el {} hasta el {}"),
+    // Rare events
+    ("Tekufas {}: {}", "Tekufat {}: {}"),
+    ("Birkas HaChama", "Birkat HaJamá"),
+    ("Hakhel", "Hakhel"),
+    ("Erev Pesach on Shabbos", "Víspera de Pésaj en Shabat"),
+    // Mourning periods
+    ("{}, through {}", "{}, hasta el {} inclusive"),
+    ("{}, until the morning of {}", "{}, hasta la mañana del {}"),
+    ("Sefirah", "Sefirá"),
+    ("The Three Weeks", "Las Tres Semanas"),
+    ("The Nine Days", "Los Nueve Días"),
+    // convert
+    ("{}: From {} to {}.", "{}: desde el {} hasta el {}."),
+    ("{} -> From sunset {} to sunset {}.", "{} -> desde la puesta del sol del {} hasta la puesta del sol del {}."),
+    ("{} at {} in {}: {}.", "{} a las {} ({}): {}."),
+    ("{}: {}. This week's parsha: {}.", "{}: {}. Parashá de la semana: {}."),
+    // day-info
+    ("{}: {} {} Said in the Amidah: {}.", "{}: {} {} En la Amidá se dice: {}."),
+    ("Tachanun is said at Shacharis and Mincha.", "Se dice Tajanún en Shajarit y en Minjá."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "Se dice Tajanún en Shajarit pero no en Minjá."),
+    ("Tachanun is not said.", "No se dice Tajanún."),
+    ("Full Hallel is said.", "Se dice el Halel completo."),
+    ("Half Hallel is said.", "Se dice medio Halel."),
+    ("Hallel is not said.", "No se dice Halel."),
+    ("Yaaleh VeYavo", "Yaalé VeYavó"),
+    ("Al HaNissim", "Al HaNisim"),
+    ("Mashiv HaRuach", "Mashiv HaRúaj"),
+    ("Morid HaTal", "Morid HaTal"),
+    ("V'sen Tal uMatar", "VeTen Tal UMatar"),
+    // year-info
+    ("{}: A leap year, year {} of the Shmita cycle.", "{}: año bisiesto, año {} del ciclo de Shmitá."),
+    ("{}: Year {} of the Shmita cycle.", "{}: año {} del ciclo de Shmitá."),
+    (" Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.", " Keviá {0} - Rosh Hashaná en {2}, {3}, Pésaj en {4}."),
+    ("a deficient year", "año defectivo"),
+    ("a regular year", "año regular"),
+    ("a full year", "año completo"),
+    ("Shmita year", "Año de Shmitá"),
+    ("Hakhel on Sukkos", "Hakhel en Sucot"),
+    ("Maaser Rishon and Maaser Sheni", "Maaser Rishón y Maaser Sheni"),
+    ("Maaser Rishon and Maaser Ani", "Maaser Rishón y Maaser Aní"),
+    ("Biur Maaser on Erev Pesach ({})", "Biur Maaser en la víspera de Pésaj ({})"),
+    // diff
+    ("Hebrew calendar: years: {}, months: {}, days: {}.", "Calendario hebreo: años: {}, meses: {}, días: {}."),
+    ("Gregorian calendar: years: {}, months: {}, days: {}.", "Calendario gregoriano: años: {}, meses: {}, días: {}."),
+    ("Days in all: {}.", "Días en total: {}."),
+    // wedding-check and wedding-dates
+    ("A wedding may be held.", "Se puede celebrar una boda."),
+    ("A wedding isn't held because of {}.", "No se celebran bodas por {}."),
+    ("Shabbos", "Shabat"),
+    ("Yom Tov", "Yom Tov"),
+    ("Chol HaMoed", "Jol HaMoed"),
+    ("a fast day", "un día de ayuno"),
+    ("the Three Weeks", "las Tres Semanas"),
+    ("the Nine Days", "los Nueve Días"),
+    ("the Ten Days of Repentance", "los Diez Días de Arrepentimiento"),
+    ("Berakhot", "Berajot"),
+    // Daf Yomi
+    ("Shabbat", "Shabat"),
+    ("Eruvin", "Eruvin"),
+    ("Pesachim", "Pesajim"),
+    ("Shekalim", "Shekalim"),
+    ("Yoma", "Yoma"),
+    ("Sukkah", "Sucá"),
+    ("Beitzah", "Beitsá"),
+    ("Rosh Hashanah", "Rosh Hashaná"),
+    ("Taanit", "Taanit"),
+    ("Megillah", "Meguilá"),
+    ("Moed Katan", "Moed Katan"),
+    ("Chagigah", "Jaguigá"),
+    ("Yevamot", "Yevamot"),
+    ("Ketubot", "Ketubot"),
+    ("Nedarim", "Nedarim"),
+    ("Nazir", "Nazir"),
+    ("Sotah", "Sotá"),
+    ("Gittin", "Gittin"),
+    ("Kiddushin", "Kidushín"),
+    ("Bava Kamma", "Bava Kamma"),
+    ("Bava Metzia", "Bava Metsiá"),
+    ("Bava Batra", "Bava Batra"),
+    ("Sanhedrin", "Sanhedrin"),
+    ("Makkot", "Makkot"),
+    ("Shevuot", "Shevuot"),
+    ("Avodah Zarah", "Avodá Zará"),
+    ("Horayot", "Horayot"),
+    ("Zevachim", "Zevajim"),
+    ("Menachot", "Menajot"),
+    ("Chullin", "Julín"),
+    ("Bekhorot", "Bejorot"),
+    ("Arakhin", "Arajin"),
+    ("Temurah", "Temurá"),
+    ("Keritot", "Keritot"),
+    ("Meilah", "Meilá"),
+    ("Niddah", "Nidá"),
+    // Yerushalmi Yomi
+    ("Jerusalem Talmud Berakhot", "Talmud de Jerusalén Berakhot"),
+    ("Jerusalem Talmud Peah", "Talmud de Jerusalén Peá"),
+    ("Jerusalem Talmud Demai", "Talmud de Jerusalén Demai"),
+    ("Jerusalem Talmud Kilayim", "Talmud de Jerusalén Kilayim"),
+    ("Jerusalem Talmud Shevi'it", "Talmud de Jerusalén Shevi'it"),
+    ("Jerusalem Talmud Terumot", "Talmud de Jerusalén Terumot"),
+    ("Jerusalem Talmud Ma'asrot", "Talmud de Jerusalén Ma'asrot"),
+    ("Jerusalem Talmud Ma'aser Sheni", "Talmud de Jerusalén Ma'aser Sheni"),
+    ("Jerusalem Talmud Hallah", "Talmud de Jerusalén Jalá"),
+    ("Jerusalem Talmud Orlah", "Talmud de Jerusalén Orlá"),
+    ("Jerusalem Talmud Bikkurim", "Talmud de Jerusalén Bikkurim"),
+    ("Jerusalem Talmud Shabbat", "Talmud de Jerusalén Shabat"),
+    ("Jerusalem Talmud Eruvin", "Talmud de Jerusalén Eruvin"),
+    ("Jerusalem Talmud Pesachim", "Talmud de Jerusalén Pesajim"),
+    ("Jerusalem Talmud Beitzah", "Talmud de Jerusalén Beitsá"),
+    ("Jerusalem Talmud Rosh Hashanah", "Talmud de Jerusalén Rosh Hashaná"),
+    ("Jerusalem Talmud Yoma", "Talmud de Jerusalén Yoma"),
+    ("Jerusalem Talmud Sukkah", "Talmud de Jerusalén Sucá"),
+    ("Jerusalem Talmud Ta'anit", "Talmud de Jerusalén Taanit"),
+    ("Jerusalem Talmud Shekalim", "Talmud de Jerusalén Shekalim"),
+    ("Jerusalem Talmud Megillah", "Talmud de Jerusalén Meguilá"),
+    ("Jerusalem Talmud Chagigah", "Talmud de Jerusalén Jaguigá"),
+    ("Jerusalem Talmud Moed Kattan", "Talmud de Jerusalén Moed Katan"),
+    ("Jerusalem Talmud Yevamot", "Talmud de Jerusalén Yevamot"),
+    ("Jerusalem Talmud Ketubot", "Talmud de Jerusalén Ketubot"),
+    ("Jerusalem Talmud Sotah", "Talmud de Jerusalén Sotá"),
+    ("Jerusalem Talmud Nedarim", "Talmud de Jerusalén Nedarim"),
+    ("Jerusalem Talmud Nazir", "Talmud de Jerusalén Nazir"),
+    ("Jerusalem Talmud Gittin", "Talmud de Jerusalén Gittin"),
+    ("Jerusalem Talmud Kiddushin", "Talmud de Jerusalén Kidushín"),
+    ("Jerusalem Talmud Bava Kamma", "Talmud de Jerusalén Bava Kamma"),
+    ("Jerusalem Talmud Bava Metsia", "Talmud de Jerusalén Bava Metsiá"),
+    ("Jerusalem Talmud Bava Batra", "Talmud de Jerusalén Bava Batra"),
+    ("Jerusalem Talmud Shevuot", "Talmud de Jerusalén Shevuot"),
+    ("Jerusalem Talmud Makkot", "Talmud de Jerusalén Makkot"),
+    ("Jerusalem Talmud Sanhedrin", "Talmud de Jerusalén Sanhedrin"),
+    ("Jerusalem Talmud Avodah Zarah", "Talmud de Jerusalén Avodá Zará"),
+    ("Jerusalem Talmud Horayot", "Talmud de Jerusalén Horayot"),
+    ("Jerusalem Talmud Niddah", "Talmud de Jerusalén Nidá"),
+    // Rambam
+    ("Rambam|Transmission of the Oral Law", "Transmisión de la Ley Oral"),
+    ("Rambam|Positive Mitzvot", "Preceptos positivos"),
+    ("Rambam|Negative Mitzvot", "Preceptos negativos"),
+    ("Rambam|Overview of Mishneh Torah Contents", "Índice de la Mishné Torá"),
+    ("Rambam|Foundations of the Torah", "Fundamentos de la Torá"),
+    ("Rambam|Human Dispositions", "Rasgos de carácter"),
+    ("Rambam|Torah Study", "Estudio de la Torá"),
+    ("Rambam|Foreign Worship and Customs of the Nations", "Idolatría y costumbres de las naciones"),
+    ("Rambam|Repentance", "Arrepentimiento"),
+    ("Rambam|Reading the Shema", "Lectura del Shemá"),
+    ("Rambam|Prayer and the Priestly Blessing", "Oración y bendición sacerdotal"),
+    ("Rambam|Tefillin, Mezuzah and the Torah Scroll", "Tefilín, mezuzá y rollo de la Torá"),
+    ("Rambam|Fringes", "Tsitsit"),
+    ("Rambam|Blessings", "Bendiciones"),
+    ("Rambam|Circumcision", "Circuncisión"),
+    ("Rambam|The Order of Prayer", "Orden de las oraciones"),
+    ("Rambam|Sabbath", "Shabat"),
+    ("Rambam|Eruvin", "Eruvín"),
+    ("Rambam|Rest on the Tenth of Tishrei", "Descanso del diez de Tishrei"),
+    ("Rambam|Rest on a Holiday", "Descanso en los días festivos"),
+    ("Rambam|Leavened and Unleavened Bread", "Jamets y matsá"),
+    ("Rambam|Shofar, Sukkah and Lulav", "Shofar, sucá y lulav"),
+    ("Rambam|Sheqel Dues", "Medio siclo"),
+    ("Rambam|Sanctification of the New Month", "Santificación de la luna nueva"),
+    ("Rambam|Fasts", "Ayunos"),
+    ("Rambam|Scroll of Esther and Hanukkah", "Meguilá y Janucá"),
+    ("Rambam|Marriage", "Matrimonio"),
+    ("Rambam|Divorce", "Divorcio"),
+    ("Rambam|Levirate Marriage and Release", "Levirato y jalitsá"),
+    ("Rambam|Virgin Maiden", "Doncella virgen"),
+    ("Rambam|Woman Suspected of Infidelity", "Mujer sospechosa de infidelidad"),
+    ("Rambam|Forbidden Intercourse", "Relaciones prohibidas"),
+    ("Rambam|Forbidden Foods", "Alimentos prohibidos"),
+    ("Rambam|Ritual Slaughter", "Sacrificio ritual"),
+    ("Rambam|Oaths", "Juramentos"),
+    ("Rambam|Vows", "Votos"),
+    ("Rambam|Nazariteship", "Nazareato"),
+    ("Rambam|Appraisals and Devoted Property", "Valoraciones y consagraciones"),
+    ("Rambam|Diverse Species", "Mezclas prohibidas"),
+    ("Rambam|Gifts to the Poor", "Dádivas a los pobres"),
+    ("Rambam|Heave Offerings", "Ofrendas elevadas"),
+    ("Rambam|Tithes", "Diezmos"),
+    ("Rambam|Second Tithes and Fourth Year's Fruit", "Segundo diezmo y frutos del cuarto año"),
+    ("Rambam|First Fruits and other Gifts to Priests Outside the Sanctuary", "Primicias y otras dádivas a los sacerdotes"),
+    ("Rambam|Sabbatical Year and the Jubilee", "Año sabático y jubileo"),
+    ("Rambam|The Chosen Temple", "El Templo elegido"),
+    ("Rambam|Vessels of the Sanctuary and Those who Serve Therein", "Utensilios del Templo y sus servidores"),
+    ("Rambam|Admission into the Sanctuary", "Entrada al Templo"),
+    ("Rambam|Things Forbidden on the Altar", "Lo prohibido en el altar"),
+    ("Rambam|Sacrificial Procedure", "Procedimiento de los sacrificios"),
+    ("Rambam|Daily Offerings and Additional Offerings", "Ofrendas diarias y adicionales"),
+    ("Rambam|Sacrifices Rendered Unfit", "Sacrificios invalidados"),
+    ("Rambam|Service on the Day of Atonement", "Servicio de Yom Kipur"),
+    ("Rambam|Trespass", "Malversación de lo sagrado"),
+    ("Rambam|Paschal Offering", "Ofrenda pascual"),
+    ("Rambam|Festival Offering", "Ofrenda festiva"),
+    ("Rambam|Firstlings", "Primogénitos"),
+    ("Rambam|Offerings for Unintentional Transgressions", "Ofrendas por transgresiones involuntarias"),
+    ("Rambam|Offerings for Those with Incomplete Atonement", "Ofrendas por expiación incompleta"),
+    ("Rambam|Substitution", "Sustitución"),
+    ("Rambam|Defilement by a Corpse", "Impureza del cadáver"),
+    ("Rambam|Red Heifer", "Vaca roja"),
+    ("Rambam|Defilement by Leprosy", "Impureza de la lepra"),
+    ("Rambam|Those Who Defile Bed or Seat", "Impureza del lecho y el asiento"),
+    ("Rambam|Other Sources of Defilement", "Otras fuentes de impureza"),
+    ("Rambam|Defilement of Foods", "Impureza de los alimentos"),
+    ("Rambam|Vessels", "Utensilios"),
+    ("Rambam|Immersion Pools", "Baños rituales"),
+    ("Rambam|Damages to Property", "Daños a la propiedad"),
+    ("Rambam|Theft", "Hurto"),
+    ("Rambam|Robbery and Lost Property", "Robo y objetos perdidos"),
+    ("Rambam|One Who Injures a Person or Property", "Lesiones y daños"),
+    ("Rambam|Murderer and the Preservation of Life", "Homicida y preservación de la vida"),
+    ("Rambam|Sales", "Compraventa"),
+    ("Rambam|Ownerless Property and Gifts", "Bienes sin dueño y donaciones"),
+    ("Rambam|Neighbors", "Vecinos"),
+    ("Rambam|Agents and Partners", "Agentes y socios"),
+    ("Rambam|Slaves", "Esclavos"),
+    ("Rambam|Hiring", "Alquiler"),
+    ("Rambam|Borrowing and Deposit", "Préstamo y depósito"),
+    ("Rambam|Creditor and Debtor", "Acreedor y deudor"),
+    ("Rambam|Plaintiff and Defendant", "Demandante y demandado"),
+    ("Rambam|Inheritances", "Herencias"),
+    ("Rambam|The Sanhedrin and the Penalties within their Jurisdiction", "El Sanedrín y las penas de su jurisdicción"),
+    ("Rambam|Testimony", "Testimonio"),
+    ("Rambam|Rebels", "Rebeldes"),
+    ("Rambam|Mourning", "Duelo"),
+    ("Rambam|Kings and Wars", "Reyes y guerras"),
+];
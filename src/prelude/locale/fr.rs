@@ -0,0 +1,541 @@
+//! The French catalog.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("Night of ", "Soir du "),
+    ("Candle lighting", "Allumage des bougies"),
+    ("Haftarah", "Haftara"),
+    ("Maftir", "Maftir"),
+    ("In Israel", "En Israël"),
+    ("In Chutz La'aretz", "Hors d'Israël"),
+    ("Triennial year", "Année du cycle triennal"),
+    // Gregorian dates
+    ("%A %B %-d %Y", "%A %-d %B %Y"),
+    ("%A %B %-d %H:%M", "%A %-d %B %H:%M"),
+    ("Sunday", "dimanche"),
+    ("Monday", "lundi"),
+    ("Tuesday", "mardi"),
+    ("Wednesday", "mercredi"),
+    ("Thursday", "jeudi"),
+    ("Friday", "vendredi"),
+    ("Saturday", "samedi"),
+    ("January", "janvier"),
+    ("February", "février"),
+    ("March", "mars"),
+    ("April", "avril"),
+    ("May", "mai"),
+    ("June", "juin"),
+    ("July", "juillet"),
+    ("August", "août"),
+    ("September", "septembre"),
+    ("October", "octobre"),
+    ("November", "novembre"),
+    ("December", "décembre"),
+    // Days of the week in the Hebrew calendar
+    ("weekday|Sunday", "dimanche"),
+    ("weekday|Monday", "lundi"),
+    ("weekday|Tuesday", "mardi"),
+    ("weekday|Wednesday", "mercredi"),
+    ("weekday|Thursday", "jeudi"),
+    ("weekday|Friday", "vendredi"),
+    ("weekday|Shabbos", "Chabbat"),
+    // Hebrew months
+    ("Tishrei", "Tichri"),
+    ("Cheshvan", "Hechvan"),
+    ("Kislev", "Kislev"),
+    ("Teves", "Tévet"),
+    ("Shvat", "Chevat"),
+    ("Adar", "Adar"),
+    ("Adar Rishon", "Adar Richon"),
+    ("Adar Sheni", "Adar Chéni"),
+    ("Nissan", "Nissan"),
+    ("Iyar", "Iyar"),
+    ("Sivan", "Sivan"),
+    ("Tammuz", "Tamouz"),
+    ("Av", "Av"),
+    ("Elul", "Eloul"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "1er jour de Roch Hachana"),
+    ("2nd day of Rosh Hashanah", "2e jour de Roch Hachana"),
+    ("Yom Kippur", "Yom Kippour"),
+    ("1st day of Sukkos", "1er jour de Souccot"),
+    ("2nd day of Sukkos", "2e jour de Souccot"),
+    ("3rd day of Sukkos", "3e jour de Souccot"),
+    ("4th day of Sukkos", "4e jour de Souccot"),
+    ("5th day of Sukkos", "5e jour de Souccot"),
+    ("6th day of Sukkos", "6e jour de Souccot"),
+    ("7th day of Sukkos", "7e jour de Souccot"),
+    ("Shmini Atzeres", "Chemini Atseret"),
+    ("Simchas Torah", "Simhat Torah"),
+    ("1st day of Pesach", "1er jour de Pessah"),
+    ("2nd day of Pesach", "2e jour de Pessah"),
+    ("3rd day of Pesach", "3e jour de Pessah"),
+    ("4th day of Pesach", "4e jour de Pessah"),
+    ("5th day of Pesach", "5e jour de Pessah"),
+    ("6th day of Pesach", "6e jour de Pessah"),
+    ("7th day of Pesach", "7e jour de Pessah"),
+    ("8th day of Pesach", "8e jour de Pessah"),
+    ("1st day of Shavuos", "1er jour de Chavouot"),
+    ("2nd day of Shavuos", "2e jour de Chavouot"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "1er jour de Roch Hodech Hechvan"),
+    ("2nd day of Rosh Chodesh Cheshvan", "2e jour de Roch Hodech Hechvan"),
+    ("Rosh Chodesh Kislev", "Roch Hodech Kislev"),
+    ("1st day of Rosh Chodesh Kislev", "1er jour de Roch Hodech Kislev"),
+    ("2nd day of Rosh Chodesh Kislev", "2e jour de Roch Hodech Kislev"),
+    ("Rosh Chodesh Teves", "Roch Hodech Tévet"),
+    ("1st day of Rosh Chodesh Teves", "1er jour de Roch Hodech Tévet"),
+    ("2nd day of Rosh Chodesh Teves", "2e jour de Roch Hodech Tévet"),
+    ("Rosh Chodesh Shvat", "Roch Hodech Chevat"),
+    ("1st day of Rosh Chodesh Adar", "1er jour de Roch Hodech Adar"),
+    ("2nd day of Rosh Chodesh Adar", "2e jour de Roch Hodech Adar"),
+    ("1st day of Rosh Chodesh Adar Rishon", "1er jour de Roch Hodech Adar Richon"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "2e jour de Roch Hodech Adar Richon"),
+    ("1st day of Rosh Chodesh Adar Sheni", "1er jour de Roch Hodech Adar Chéni"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "2e jour de Roch Hodech Adar Chéni"),
+    ("Rosh Chodesh Nissan", "Roch Hodech Nissan"),
+    ("1st day of Rosh Chodesh Iyar", "1er jour de Roch Hodech Iyar"),
+    ("2nd day of Rosh Chodesh Iyar", "2e jour de Roch Hodech Iyar"),
+    ("Rosh Chodesh Sivan", "Roch Hodech Sivan"),
+    ("1st day of Rosh Chodesh Tammuz", "1er jour de Roch Hodech Tamouz"),
+    ("2nd day of Rosh Chodesh Tammuz", "2e jour de Roch Hodech Tamouz"),
+    ("Rosh Chodesh Av", "Roch Hodech Av"),
+    ("1st day of Rosh Chodesh Elul", "1er jour de Roch Hodech Eloul"),
+    ("2nd day of Rosh Chodesh Elul", "2e jour de Roch Hodech Eloul"),
+    ("1st day of Chanukah", "1er jour de Hanouka"),
+    ("2nd day of Chanukah", "2e jour de Hanouka"),
+    ("3rd day of Chanukah", "3e jour de Hanouka"),
+    ("4rd day of Chanukah", "4e jour de Hanouka"),
+    ("5rd day of Chanukah", "5e jour de Hanouka"),
+    ("6rd day of Chanukah", "6e jour de Hanouka"),
+    ("7rd day of Chanukah", "7e jour de Hanouka"),
+    ("8rd day of Chanukah", "8e jour de Hanouka"),
+    ("Tzom Gedalia", "Jeûne de Guedalia"),
+    ("Taanis Esther", "Jeûne d'Esther"),
+    ("Tenth of Teves", "10 Tévet"),
+    ("Purim", "Pourim"),
+    ("Shushan Purim", "Chouchan Pourim"),
+    ("Seventeenth of Tammuz", "17 Tamouz"),
+    ("Ninth of Av", "9 Av"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Haazinu", "Haazinou"),
+    ("Vayelech", "Vayélekh"),
+    ("Bereishis", "Béréchit"),
+    ("Noach", "Noah"),
+    ("Lech Lecha", "Lekh Lekha"),
+    ("Vayeira", "Vayéra"),
+    ("Chayei Sarah", "Hayé Sarah"),
+    ("Toldos", "Toledot"),
+    ("Vayetzei", "Vayétsé"),
+    ("Vayishlach", "Vayichlah"),
+    ("Vayeshev", "Vayéchev"),
+    ("Miketz", "Mikets"),
+    ("Vayigash", "Vayigach"),
+    ("Vayechi", "Vayehi"),
+    ("Shemos", "Chemot"),
+    ("Vaeira", "Vaéra"),
+    ("Bo", "Bo"),
+    ("Beshalach", "Bechalah"),
+    ("Yisro", "Yitro"),
+    ("Mishpatim", "Michpatim"),
+    ("Terumah", "Terouma"),
+    ("Tetzaveh", "Tetsavé"),
+    ("Ki Sisa", "Ki Tissa"),
+    ("Vayakhel/Pikudei", "Vayakhel/Pekoudé"),
+    ("Vayakhel", "Vayakhel"),
+    ("Pikudei", "Pekoudé"),
+    ("Vayikra", "Vayikra"),
+    ("Tzav", "Tsav"),
+    ("Shemini", "Chemini"),
+    ("Tazriya/Metzorah", "Tazria/Metsora"),
+    ("Tazriya", "Tazria"),
+    ("Metzorah", "Metsora"),
+    ("Acharei Mos/Kedoshim", "Aharé Mot/Kedochim"),
+    ("Acharei Mos", "Aharé Mot"),
+    ("Kedoshim", "Kedochim"),
+    ("Emor", "Emor"),
+    ("Behar/Bechukosai", "Behar/Behoukotaï"),
+    ("Behar", "Behar"),
+    ("Bechukosai", "Behoukotaï"),
+    ("Bamidbar", "Bamidbar"),
+    ("Naso", "Nasso"),
+    ("Behaaloscha", "Behaalotekha"),
+    ("Shlach", "Chelah"),
+    ("Korach", "Korah"),
+    ("Chukas/Balak", "Houkat/Balak"),
+    ("Chukas", "Houkat"),
+    ("Balak", "Balak"),
+    ("Pinchas", "Pinhas"),
+    ("Matos/Maasei", "Matot/Masseï"),
+    ("Matos", "Matot"),
+    ("Maasei", "Masseï"),
+    ("Devarim", "Devarim"),
+    ("Vaeschanan", "Vaèt'hanane"),
+    ("Eikev", "Ekev"),
+    ("Re'eh", "Reé"),
+    ("Shoftim", "Choftim"),
+    ("Ki Seitzei", "Ki Tétsé"),
+    ("Ki Savo", "Ki Tavo"),
+    ("Nitzavim/Vayelech", "Nitsavim/Vayélekh"),
+    ("Nitzavim", "Nitsavim"),
+    // Special parshiyos
+    ("Parshas Zachor", "Parachat Zakhor"),
+    ("Parshas HaChodesh", "Parachat Hahodech"),
+    ("Parshas Parah", "Parachat Para"),
+    ("Parshas Shekalim", "Parachat Chekalim"),
+    // Books of Navi (Shoftim is with the parshiyos)
+    ("Yehoshua", "Yehochoua"),
+    ("Shmuel I", "Chemouel I"),
+    ("Shmuel II", "Chemouel II"),
+    ("Melachim I", "Melakhim I"),
+    ("Melachim II", "Melakhim II"),
+    ("Yeshayahu", "Yechayahou"),
+    ("Yirmiyahu", "Yirmeyahou"),
+    ("Yechezkel", "Yehezkel"),
+    ("Hoshea", "Hochéa"),
+    ("Yoel", "Yoël"),
+    ("Amos", "Amos"),
+    ("Ovadia", "Ovadia"),
+    ("Micha", "Mikha"),
+    ("Chavakuk", "Havakouk"),
+    ("Zecharia", "Zekharia"),
+    ("Malachi", "Malakhi"),
+    // Minor holidays
+    ("1st day of the Omer", "1er jour de l'Omer"),
+    ("2nd day of the Omer", "2e jour de l'Omer"),
+    ("3rd day of the Omer", "3e jour de l'Omer"),
+    ("4th day of the Omer", "4e jour de l'Omer"),
+    ("5th day of the Omer", "5e jour de l'Omer"),
+    ("6th day of the Omer", "6e jour de l'Omer"),
+    ("7th day of the Omer", "7e jour de l'Omer"),
+    ("8th day of the Omer", "8e jour de l'Omer"),
+    ("9th day of the Omer", "9e jour de l'Omer"),
+    ("10th day of the Omer", "10e jour de l'Omer"),
+    ("11th day of the Omer", "11e jour de l'Omer"),
+    ("12th day of the Omer", "12e jour de l'Omer"),
+    ("13th day of the Omer", "13e jour de l'Omer"),
+    ("14th day of the Omer", "14e jour de l'Omer"),
+    ("15th day of the Omer", "15e jour de l'Omer"),
+    ("16th day of the Omer", "16e jour de l'Omer"),
+    ("17th day of the Omer", "17e jour de l'Omer"),
+    ("18th day of the Omer", "18e jour de l'Omer"),
+    ("19th day of the Omer", "19e jour de l'Omer"),
+    ("20th day of the Omer", "20e jour de l'Omer"),
+    ("21st day of the Omer", "21e jour de l'Omer"),
+    ("22nd day of the Omer", "22e jour de l'Omer"),
+    ("23rd day of the Omer", "23e jour de l'Omer"),
+    ("24th day of the Omer", "24e jour de l'Omer"),
+    ("25th day of the Omer", "25e jour de l'Omer"),
+    ("26th day of the Omer", "26e jour de l'Omer"),
+    ("27th day of the Omer", "27e jour de l'Omer"),
+    ("28th day of the Omer", "28e jour de l'Omer"),
+    ("29th day of the Omer", "29e jour de l'Omer"),
+    ("30th day of the Omer", "30e jour de l'Omer"),
+    ("31st day of the Omer", "31e jour de l'Omer"),
+    ("32nd day of the Omer", "32e jour de l'Omer"),
+    ("33rd day of the Omer", "33e jour de l'Omer"),
+    ("34th day of the Omer", "34e jour de l'Omer"),
+    ("35th day of the Omer", "35e jour de l'Omer"),
+    ("36th day of the Omer", "36e jour de l'Omer"),
+    ("37th day of the Omer", "37e jour de l'Omer"),
+    ("38th day of the Omer", "38e jour de l'Omer"),
+    ("39th day of the Omer", "39e jour de l'Omer"),
+    ("40th day of the Omer", "40e jour de l'Omer"),
+    ("41st day of the Omer", "41e jour de l'Omer"),
+    ("42nd day of the Omer", "42e jour de l'Omer"),
+    ("43rd day of the Omer", "43e jour de l'Omer"),
+    ("44th day of the Omer", "44e jour de l'Omer"),
+    ("45th day of the Omer", "45e jour de l'Omer"),
+    ("46th day of the Omer", "46e jour de l'Omer"),
+    ("47th day of the Omer", "47e jour de l'Omer"),
+    ("48th day of the Omer", "48e jour de l'Omer"),
+    ("49th day of the Omer", "49e jour de l'Omer"),
+    ("Erev Pesach", "Veille de Pessah"),
+    ("Erev Sukkos", "Veille de Souccot"),
+    ("Erev Shavuos", "Veille de Chavouot"),
+    ("Erev Yom Kippur", "Veille de Yom Kippour"),
+    ("Erev Rosh Hashana", "Veille de Roch Hachana"),
+    ("Pesach Sheni", "Pessah Chéni"),
+    ("Lag BaOmer", "Lag Baomer"),
+    ("15th of Av", "Tou Beav"),
+    ("15th of Shevat", "Tou Bichvat"),
+    ("Purim Kattan", "Pourim Katan"),
+    ("Shushan Purim Kattan", "Chouchan Pourim Katan"),
+    ("Purim Meshulash: Megillah reading and gifts to the poor", "Pourim Mechoulach: lecture de la Méguila et dons aux pauvres"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "Pourim Mechoulach: Al Hanissim et lecture de la Torah de Pourim"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "Pourim Mechoulach: repas de Pourim et michloah manot"),
+    ("Shabbos HaGadol", "Chabbat Hagadol"),
+    ("Taanis Bechoros", "Jeûne des premiers-nés"),
+    ("Shabbos Chazon", "Chabbat Hazon"),
+    ("Shabbos Nachamu", "Chabbat Nahamou"),
+    ("Leil Slichos", "Nuit des Selihot"),
+    ("Shabbos Shuva", "Chabbat Chouva"),
+    ("Shabbos Rosh Chodesh", "Chabbat Roch Hodech"),
+    ("Shabbos Machar Chodesh", "Chabbat Mahar Hodech"),
+    ("Shabbos Shira", "Chabbat Chira"),
+    ("Shabbos Chanukah", "Chabbat Hanouka"),
+    ("Second Shabbos Chanukah", "Deuxième Chabbat Hanouka"),
+    ("Shabbos Chol HaMoed Sukkos", "Chabbat Hol Hamoed Souccot"),
+    ("Shabbos Chol HaMoed Pesach", "Chabbat Hol Hamoed Pessah"),
+    ("Shabbos Shekalim", "Chabbat Chekalim"),
+    ("Shabbos Zachor", "Chabbat Zakhor"),
+    ("Shabbos Parah", "Chabbat Para"),
+    ("Shabbos HaChodesh", "Chabbat Hahodech"),
+    ("Shabbos Mevarchim", "Chabbat Mevarkhim"),
+    // Modern Israeli holidays
+    ("Yom HaAtzmaut", "Yom Haatsmaout"),
+    ("Yom HaZikaron", "Yom Hazikaron"),
+    ("Yom Yerushalayim", "Yom Yerouchalayim"),
+    ("Yom HaShoah", "Yom Hachoah"),
+    ("Yom HaAliyah", "Yom Haaliya"),
+    ("Yom HaAliyah (10 Nissan)", "Yom Haaliya (10 Nissan)"),
+    ("Sigd", "Sigd"),
+    ("Yitzhak Rabin Memorial Day", "Journée du souvenir d'Yitzhak Rabin"),
+    ("Ben-Gurion Day", "Journée Ben Gourion"),
+    ("Hebrew Language Day", "Journée de la langue hébraïque"),
+    ("Family Day", "Journée de la famille"),
+    ("Herzl Day", "Journée Herzl"),
+    ("Jabotinsky Day", "Journée Jabotinsky"),
+    // Chabad holidays
+    ("Vav Tishrei", "Vav Tichri"),
+    ("Yud Kislev", "Youd Kislev"),
+    ("Yud Tes Kislev", "Youd Tèt Kislev"),
+    ("Chof Kislev", "Kaf Kislev"),
+    ("Hei Teves", "Hé Tévet"),
+    ("Chof Daled Teves", "Kaf Daleth Tévet"),
+    ("Yud Shvat", "Youd Chevat"),
+    ("Chof Beis Shvat", "Kaf Beth Chevat"),
+    ("Yud Aleph Nissan", "Youd Aleph Nissan"),
+    ("Gimmel Tammuz", "Guimel Tamouz"),
+    ("Yud Beis Tammuz", "Youd Beth Tamouz"),
+    ("Yud Gimmel Tammuz", "Youd Guimel Tamouz"),
+    ("Chof Av", "Kaf Av"),
+    ("Chai Elul", "Haï Eloul"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "Chabbat Mevarkhim {0}: le molad est {1} {3} {2}, {4} et {5} halakim ({6} heure normale de Jérusalem). {7}"),
+    ("Rosh Chodesh {} will be on {}", "Roch Hodech {} sera {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "Roch Hodech {} sera {} et {}"),
+    ("Kiddush Levana {}: From {} until {}", "Kiddouch Levana {}: du {} au {}"),
+    // Rare events
+    ("Tekufas {}: {}", "Tekoufat {}: {}"),
+    ("Birkas HaChama", "Birkat Hahama"),
+    ("Hakhel", "Hakhel"),
+    ("Erev Pesach on Shabbos", "Veille de Pessah un Chabbat"),
+    // Mourning periods
+    ("{}, through {}", "{}, jusqu'au {} inclus"),
+    ("{}, until the morning of {}", "{}, jusqu'au matin du {}"),
+    ("Sefirah", "Séfira"),
+    ("The Three Weeks", "Les Trois Semaines"),
+    ("The Nine Days", "Les Neuf Jours"),
+    // convert
+    ("{}: From {} to {}.", "{}: du {} au {}."),
+    ("{} -> From sunset {} to sunset {}.", "{} -> du coucher du soleil du {} au coucher du soleil du {}."),
+    ("{} at {} in {}: {}.", "{} à {} ({}): {}."),
+    ("{}: {}. This week's parsha: {}.", "{}: {}. Paracha de la semaine: {}."),
+    // day-info
+    ("{}: {} {} Said in the Amidah: {}.", "{}: {} {} Dans la Amida, on dit: {}."),
+    ("Tachanun is said at Shacharis and Mincha.", "On dit Tahanoun à Chaharit et à Minha."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "On dit Tahanoun à Chaharit mais pas à Minha."),
+    ("Tachanun is not said.", "On ne dit pas Tahanoun."),
+    ("Full Hallel is said.", "On dit le Hallel complet."),
+    ("Half Hallel is said.", "On dit le demi-Hallel."),
+    ("Hallel is not said.", "On ne dit pas le Hallel."),
+    ("Yaaleh VeYavo", "Yaalé Veyavo"),
+    ("Al HaNissim", "Al Hanissim"),
+    ("Mashiv HaRuach", "Machiv Harouah"),
+    ("Morid HaTal", "Morid Hatal"),
+    ("V'sen Tal uMatar", "Vetèn Tal Oumatar"),
+    // year-info
+    ("{}: A leap year, year {} of the Shmita cycle.", "{}: année embolismique, année {} du cycle de la Chemita."),
+    ("{}: Year {} of the Shmita cycle.", "{}: année {} du cycle de la Chemita."),
+    (" Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.", " Keviah {0} - Roch Hachana le {2}, {3}, Pessah le {4}."),
+    ("a deficient year", "année défective"),
+    ("a regular year", "année régulière"),
+    ("a full year", "année pleine"),
+    ("Shmita year", "Année de Chemita"),
+    ("Hakhel on Sukkos", "Hakhel à Souccot"),
+    ("Maaser Rishon and Maaser Sheni", "Maasser Richon et Maasser Chéni"),
+    ("Maaser Rishon and Maaser Ani", "Maasser Richon et Maasser Ani"),
+    ("Biur Maaser on Erev Pesach ({})", "Biour Maasser la veille de Pessah ({})"),
+    // diff
+    ("Hebrew calendar: years: {}, months: {}, days: {}.", "Calendrier hébreu : années : {}, mois : {}, jours : {}."),
+    ("Gregorian calendar: years: {}, months: {}, days: {}.", "Calendrier grégorien : années : {}, mois : {}, jours : {}."),
+    ("Days in all: {}.", "Jours au total : {}."),
+    // wedding-check and wedding-dates
+    ("A wedding may be held.", "Un mariage peut avoir lieu."),
+    ("A wedding isn't held because of {}.", "On ne célèbre pas de mariage à cause de: {}."),
+    ("Shabbos", "Chabbat"),
+    ("Yom Tov", "Yom Tov"),
+    ("Chol HaMoed", "Hol Hamoed"),
+    ("a fast day", "jour de jeûne"),
+    ("the Three Weeks", "les Trois Semaines"),
+    ("the Nine Days", "les Neuf Jours"),
+    ("the Ten Days of Repentance", "les Dix Jours de pénitence"),
+    ("Berakhot", "Berakhot"),
+    // Daf Yomi
+    ("Shabbat", "Chabbat"),
+    ("Eruvin", "Erouvin"),
+    ("Pesachim", "Pessahim"),
+    ("Shekalim", "Chekalim"),
+    ("Yoma", "Yoma"),
+    ("Sukkah", "Souka"),
+    ("Beitzah", "Beitsa"),
+    ("Rosh Hashanah", "Roch Hachana"),
+    ("Taanit", "Taanit"),
+    ("Megillah", "Méguila"),
+    ("Moed Katan", "Moed Katan"),
+    ("Chagigah", "Haguiga"),
+    ("Yevamot", "Yevamot"),
+    ("Ketubot", "Ketoubot"),
+    ("Nedarim", "Nedarim"),
+    ("Nazir", "Nazir"),
+    ("Sotah", "Sota"),
+    ("Gittin", "Guittin"),
+    ("Kiddushin", "Kiddouchin"),
+    ("Bava Kamma", "Baba Kama"),
+    ("Bava Metzia", "Baba Metsia"),
+    ("Bava Batra", "Baba Batra"),
+    ("Sanhedrin", "Sanhédrin"),
+    ("Makkot", "Makot"),
+    ("Shevuot", "Chevouot"),
+    ("Avodah Zarah", "Avoda Zara"),
+    ("Horayot", "Horayot"),
+    ("Zevachim", "Zevahim"),
+    ("Menachot", "Menahot"),
+    ("Chullin", "Houlin"),
+    ("Bekhorot", "Bekhorot"),
+    ("Arakhin", "Arakhin"),
+    ("Temurah", "Temoura"),
+    ("Keritot", "Keritot"),
+    ("Meilah", "Meïla"),
+    ("Niddah", "Nida"),
+    // Yerushalmi Yomi
+    ("Jerusalem Talmud Berakhot", "Talmud de Jérusalem Berakhot"),
+    ("Jerusalem Talmud Peah", "Talmud de Jérusalem Péa"),
+    ("Jerusalem Talmud Demai", "Talmud de Jérusalem Demaï"),
+    ("Jerusalem Talmud Kilayim", "Talmud de Jérusalem Kilaïm"),
+    ("Jerusalem Talmud Shevi'it", "Talmud de Jérusalem Chevi'it"),
+    ("Jerusalem Talmud Terumot", "Talmud de Jérusalem Teroumot"),
+    ("Jerusalem Talmud Ma'asrot", "Talmud de Jérusalem Maasserot"),
+    ("Jerusalem Talmud Ma'aser Sheni", "Talmud de Jérusalem Maasser Chéni"),
+    ("Jerusalem Talmud Hallah", "Talmud de Jérusalem Hala"),
+    ("Jerusalem Talmud Orlah", "Talmud de Jérusalem Orla"),
+    ("Jerusalem Talmud Bikkurim", "Talmud de Jérusalem Bikourim"),
+    ("Jerusalem Talmud Shabbat", "Talmud de Jérusalem Chabbat"),
+    ("Jerusalem Talmud Eruvin", "Talmud de Jérusalem Erouvin"),
+    ("Jerusalem Talmud Pesachim", "Talmud de Jérusalem Pessahim"),
+    ("Jerusalem Talmud Beitzah", "Talmud de Jérusalem Beitsa"),
+    ("Jerusalem Talmud Rosh Hashanah", "Talmud de Jérusalem Roch Hachana"),
+    ("Jerusalem Talmud Yoma", "Talmud de Jérusalem Yoma"),
+    ("Jerusalem Talmud Sukkah", "Talmud de Jérusalem Souka"),
+    ("Jerusalem Talmud Ta'anit", "Talmud de Jérusalem Taanit"),
+    ("Jerusalem Talmud Shekalim", "Talmud de Jérusalem Chekalim"),
+    ("Jerusalem Talmud Megillah", "Talmud de Jérusalem Méguila"),
+    ("Jerusalem Talmud Chagigah", "Talmud de Jérusalem Haguiga"),
+    ("Jerusalem Talmud Moed Kattan", "Talmud de Jérusalem Moed Katan"),
+    ("Jerusalem Talmud Yevamot", "Talmud de Jérusalem Yevamot"),
+    ("Jerusalem Talmud Ketubot", "Talmud de Jérusalem Ketoubot"),
+    ("Jerusalem Talmud Sotah", "Talmud de Jérusalem Sota"),
+    ("Jerusalem Talmud Nedarim", "Talmud de Jérusalem Nedarim"),
+    ("Jerusalem Talmud Nazir", "Talmud de Jérusalem Nazir"),
+    ("Jerusalem Talmud Gittin", "Talmud de Jérusalem Guittin"),
+    ("Jerusalem Talmud Kiddushin", "Talmud de Jérusalem Kiddouchin"),
+    ("Jerusalem Talmud Bava Kamma", "Talmud de Jérusalem Baba Kama"),
+    ("Jerusalem Talmud Bava Metsia", "Talmud de Jérusalem Baba Metsia"),
+    ("Jerusalem Talmud Bava Batra", "Talmud de Jérusalem Baba Batra"),
+    ("Jerusalem Talmud Shevuot", "Talmud de Jérusalem Chevouot"),
+    ("Jerusalem Talmud Makkot", "Talmud de Jérusalem Makot"),
+    ("Jerusalem Talmud Sanhedrin", "Talmud de Jérusalem Sanhédrin"),
+    ("Jerusalem Talmud Avodah Zarah", "Talmud de Jérusalem Avoda Zara"),
+    ("Jerusalem Talmud Horayot", "Talmud de Jérusalem Horayot"),
+    ("Jerusalem Talmud Niddah", "Talmud de Jérusalem Nida"),
+    // Rambam
+    ("Rambam|Transmission of the Oral Law", "Transmission de la Loi orale"),
+    ("Rambam|Positive Mitzvot", "Commandements positifs"),
+    ("Rambam|Negative Mitzvot", "Commandements négatifs"),
+    ("Rambam|Overview of Mishneh Torah Contents", "Sommaire du Michné Torah"),
+    ("Rambam|Foundations of the Torah", "Fondements de la Torah"),
+    ("Rambam|Human Dispositions", "Traits de caractère"),
+    ("Rambam|Torah Study", "Étude de la Torah"),
+    ("Rambam|Foreign Worship and Customs of the Nations", "Idolâtrie et coutumes des nations"),
+    ("Rambam|Repentance", "Repentir"),
+    ("Rambam|Reading the Shema", "Lecture du Chema"),
+    ("Rambam|Prayer and the Priestly Blessing", "Prière et bénédiction des Cohanim"),
+    ("Rambam|Tefillin, Mezuzah and the Torah Scroll", "Tefilin, mezouza et Sefer Torah"),
+    ("Rambam|Fringes", "Tsitsit"),
+    ("Rambam|Blessings", "Bénédictions"),
+    ("Rambam|Circumcision", "Circoncision"),
+    ("Rambam|The Order of Prayer", "Ordre des prières"),
+    ("Rambam|Sabbath", "Chabbat"),
+    ("Rambam|Eruvin", "Érouvin"),
+    ("Rambam|Rest on the Tenth of Tishrei", "Repos du dix Tichri"),
+    ("Rambam|Rest on a Holiday", "Repos des jours de fête"),
+    ("Rambam|Leavened and Unleavened Bread", "Hamets et matsa"),
+    ("Rambam|Shofar, Sukkah and Lulav", "Chofar, souka et loulav"),
+    ("Rambam|Sheqel Dues", "Sicles"),
+    ("Rambam|Sanctification of the New Month", "Sanctification du nouveau mois"),
+    ("Rambam|Fasts", "Jeûnes"),
+    ("Rambam|Scroll of Esther and Hanukkah", "Méguila et Hanouka"),
+    ("Rambam|Marriage", "Mariage"),
+    ("Rambam|Divorce", "Divorce"),
+    ("Rambam|Levirate Marriage and Release", "Lévirat et halitsa"),
+    ("Rambam|Virgin Maiden", "Jeune fille vierge"),
+    ("Rambam|Woman Suspected of Infidelity", "Femme soupçonnée d'infidélité"),
+    ("Rambam|Forbidden Intercourse", "Relations interdites"),
+    ("Rambam|Forbidden Foods", "Aliments interdits"),
+    ("Rambam|Ritual Slaughter", "Abattage rituel"),
+    ("Rambam|Oaths", "Serments"),
+    ("Rambam|Vows", "Vœux"),
+    ("Rambam|Nazariteship", "Naziréat"),
+    ("Rambam|Appraisals and Devoted Property", "Estimations et biens voués"),
+    ("Rambam|Diverse Species", "Mélanges"),
+    ("Rambam|Gifts to the Poor", "Dons aux pauvres"),
+    ("Rambam|Heave Offerings", "Prélèvements"),
+    ("Rambam|Tithes", "Dîmes"),
+    ("Rambam|Second Tithes and Fourth Year's Fruit", "Deuxième dîme et fruits de la quatrième année"),
+    ("Rambam|First Fruits and other Gifts to Priests Outside the Sanctuary", "Prémices et autres dons aux Cohanim"),
+    ("Rambam|Sabbatical Year and the Jubilee", "Année sabbatique et jubilé"),
+    ("Rambam|The Chosen Temple", "Temple élu"),
+    ("Rambam|Vessels of the Sanctuary and Those who Serve Therein", "Ustensiles du Temple et ceux qui y servent"),
+    ("Rambam|Admission into the Sanctuary", "Entrée dans le Temple"),
+    ("Rambam|Things Forbidden on the Altar", "Interdits de l'autel"),
+    ("Rambam|Sacrificial Procedure", "Procédure des sacrifices"),
+    ("Rambam|Daily Offerings and Additional Offerings", "Sacrifices quotidiens et additionnels"),
+    ("Rambam|Sacrifices Rendered Unfit", "Sacrifices invalidés"),
+    ("Rambam|Service on the Day of Atonement", "Service de Yom Kippour"),
+    ("Rambam|Trespass", "Détournement des choses saintes"),
+    ("Rambam|Paschal Offering", "Sacrifice pascal"),
+    ("Rambam|Festival Offering", "Sacrifice de fête"),
+    ("Rambam|Firstlings", "Premiers-nés"),
+    ("Rambam|Offerings for Unintentional Transgressions", "Sacrifices pour les fautes involontaires"),
+    ("Rambam|Offerings for Those with Incomplete Atonement", "Sacrifices pour une expiation incomplète"),
+    ("Rambam|Substitution", "Substitution"),
+    ("Rambam|Defilement by a Corpse", "Impureté du mort"),
+    ("Rambam|Red Heifer", "Vache rousse"),
+    ("Rambam|Defilement by Leprosy", "Impureté de la lèpre"),
+    ("Rambam|Those Who Defile Bed or Seat", "Impureté du lit et du siège"),
+    ("Rambam|Other Sources of Defilement", "Autres sources d'impureté"),
+    ("Rambam|Defilement of Foods", "Impureté des aliments"),
+    ("Rambam|Vessels", "Ustensiles"),
+    ("Rambam|Immersion Pools", "Bains rituels"),
+    ("Rambam|Damages to Property", "Dommages aux biens"),
+    ("Rambam|Theft", "Vol"),
+    ("Rambam|Robbery and Lost Property", "Brigandage et objets perdus"),
+    ("Rambam|One Who Injures a Person or Property", "Coups et blessures"),
+    ("Rambam|Murderer and the Preservation of Life", "Meurtrier et préservation de la vie"),
+    ("Rambam|Sales", "Ventes"),
+    ("Rambam|Ownerless Property and Gifts", "Biens sans propriétaire et dons"),
+    ("Rambam|Neighbors", "Voisins"),
+    ("Rambam|Agents and Partners", "Mandataires et associés"),
+    ("Rambam|Slaves", "Esclaves"),
+    ("Rambam|Hiring", "Louage"),
+    ("Rambam|Borrowing and Deposit", "Emprunt et dépôt"),
+    ("Rambam|Creditor and Debtor", "Créancier et débiteur"),
+    ("Rambam|Plaintiff and Defendant", "Demandeur et défendeur"),
+    ("Rambam|Inheritances", "Héritages"),
+    ("Rambam|The Sanhedrin and the Penalties within their Jurisdiction", "Le Sanhédrin et les peines de son ressort"),
+    ("Rambam|Testimony", "Témoignage"),
+    ("Rambam|Rebels", "Rebelles"),
+    ("Rambam|Mourning", "Deuil"),
+    ("Rambam|Kings and Wars", "Rois et guerres"),
+];
@@ -0,0 +1,519 @@
+//! The Hebrew catalog.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("Night of ", "ליל "),
+    ("Candle lighting", "הדלקת נרות"),
+    ("Haftarah", "הפטרה"),
+    ("Maftir", "מפטיר"),
+    ("In Israel", "בארץ ישראל"),
+    ("In Chutz La'aretz", "בחוץ לארץ"),
+    ("Triennial year", "שנה במחזור התלת-שנתי"),
+    // Gregorian dates
+    ("%A %B %-d %H:%M", "%d/%m %H:%M"),
+    // Days of the week in the Hebrew calendar
+    ("weekday|Sunday", "ראשון"),
+    ("weekday|Monday", "שני"),
+    ("weekday|Tuesday", "שלישי"),
+    ("weekday|Wednesday", "רביעי"),
+    ("weekday|Thursday", "חמישי"),
+    ("weekday|Friday", "שישי"),
+    ("weekday|Shabbos", "שבת קודש"),
+    // Hebrew months
+    ("Tishrei", "תשרי"),
+    ("Cheshvan", "חשוון"),
+    ("Kislev", "כסלו"),
+    ("Teves", "טבת"),
+    ("Shvat", "שבט"),
+    ("Adar", "אדר"),
+    ("Adar Rishon", "אדר א׳"),
+    ("Adar Sheni", "אדר ב׳"),
+    ("Nissan", "ניסן"),
+    ("Iyar", "אייר"),
+    ("Sivan", "סיוון"),
+    ("Tammuz", "תמוז"),
+    ("Av", "אב"),
+    ("Elul", "אלול"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "יום א של ראש השנה"),
+    ("2nd day of Rosh Hashanah", "יום ב של ראש השנה"),
+    ("Yom Kippur", "יום כיפור"),
+    ("1st day of Sukkos", "יום א של חג הסוכות"),
+    ("2nd day of Sukkos", "יום ב של חג הסוכות"),
+    ("3rd day of Sukkos", "יום ג של חג הסוכות"),
+    ("4th day of Sukkos", "יום ד של חג הסוכות"),
+    ("5th day of Sukkos", "יום ה של חג הסוכות"),
+    ("6th day of Sukkos", "יום ו של חג הסוכות"),
+    ("7th day of Sukkos", "יום ז של חג הסוכות"),
+    ("Shmini Atzeres", "שמיני עצרת"),
+    ("Simchas Torah", "שמחת תורה"),
+    ("1st day of Pesach", "יום א של חג הפסח"),
+    ("2nd day of Pesach", "יום ב של חג הפסח"),
+    ("3rd day of Pesach", "יום ג של חג הפסח"),
+    ("4th day of Pesach", "יום ד של חג הפסח"),
+    ("5th day of Pesach", "יום ה של חג הפסח"),
+    ("6th day of Pesach", "יום ו של חג הפסח"),
+    ("7th day of Pesach", "יום ז של חג הפסח"),
+    ("8th day of Pesach", "יום ח של חג הפסח"),
+    ("1st day of Shavuos", "יום א של חג השבועות"),
+    ("2nd day of Shavuos", "יום ב של חג השבועות"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "יום א של ראש חודש חשון"),
+    ("2nd day of Rosh Chodesh Cheshvan", "יום ב של ראש חודש חשון"),
+    ("Rosh Chodesh Kislev", "ראש חודש כסלו"),
+    ("1st day of Rosh Chodesh Kislev", "יום א של ראש חודש כסלו"),
+    ("2nd day of Rosh Chodesh Kislev", "יום ב של ראש חודש כסלו"),
+    ("Rosh Chodesh Teves", "ראש חודש טבת"),
+    ("1st day of Rosh Chodesh Teves", "יום א של ראש חודש טבת"),
+    ("2nd day of Rosh Chodesh Teves", "יום ב של ראש חודש טבת"),
+    ("Rosh Chodesh Shvat", "ראש חודש שבט"),
+    ("1st day of Rosh Chodesh Adar", "יום א של ראש חודש אדר"),
+    ("2nd day of Rosh Chodesh Adar", "יום ב של ראש חודש אדר"),
+    ("1st day of Rosh Chodesh Adar Rishon", "יום א של ראש חודש אדר ראשון"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "יום ב של ראש חודש אדר ראשון"),
+    ("1st day of Rosh Chodesh Adar Sheni", "יום א של ראש חודש אדר שני"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "יום ב של ראש חודש אדר שני"),
+    ("Rosh Chodesh Nissan", "ראש חדש ניסן"),
+    ("1st day of Rosh Chodesh Iyar", "יום א של ראש חודש אייר"),
+    ("2nd day of Rosh Chodesh Iyar", "יום ב של ראש חודש אייר"),
+    ("Rosh Chodesh Sivan", "ראש חדש סיון"),
+    ("1st day of Rosh Chodesh Tammuz", "יום א של ראש חודש תמוז"),
+    ("2nd day of Rosh Chodesh Tammuz", "יום ב של ראש חודש תמוז"),
+    ("Rosh Chodesh Av", "ראש חודש אב"),
+    ("1st day of Rosh Chodesh Elul", "יום א של ראש חודש אלול"),
+    ("2nd day of Rosh Chodesh Elul", "יום ב של ראש חודש אלול"),
+    ("1st day of Chanukah", "יום א של חנוכה"),
+    ("2nd day of Chanukah", "יום ב של חנוכה"),
+    ("3rd day of Chanukah", "יום ג של חנוכה"),
+    ("4rd day of Chanukah", "יום ד של חנוכה"),
+    ("5rd day of Chanukah", "יום ה של חנוכה"),
+    ("6rd day of Chanukah", "יום ו של חנוכה"),
+    ("7rd day of Chanukah", "יום ז של חנוכה"),
+    ("8rd day of Chanukah", "יום ח של חנוכה"),
+    ("Tzom Gedalia", "צום גדליה"),
+    ("Taanis Esther", "תענית אסתר"),
+    ("Tenth of Teves", "י' טבת"),
+    ("Purim", "פורים"),
+    ("Shushan Purim", "שושן פורים"),
+    ("Seventeenth of Tammuz", "שבעה עשר בתמוז"),
+    ("Ninth of Av", "תשעה באב"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Haazinu", "האזינו"),
+    ("Vayelech", "וילך"),
+    ("Bereishis", "בראשית"),
+    ("Noach", "נח"),
+    ("Lech Lecha", "לך לך"),
+    ("Vayeira", "וירא"),
+    ("Chayei Sarah", "חיי שרה"),
+    ("Toldos", "תולדות"),
+    ("Vayetzei", "ויצא"),
+    ("Vayishlach", "וישלח"),
+    ("Vayeshev", "וישב"),
+    ("Miketz", "מיקץ"),
+    ("Vayigash", "ויגש"),
+    ("Vayechi", "ויחי"),
+    ("Shemos", "שמות"),
+    ("Vaeira", "וארא"),
+    ("Bo", "בא"),
+    ("Beshalach", "בשלח"),
+    ("Yisro", "יתרו"),
+    ("Mishpatim", "משפטים"),
+    ("Terumah", "תרומה"),
+    ("Tetzaveh", "תצוה"),
+    ("Ki Sisa", "כי תשא"),
+    ("Vayakhel/Pikudei", "ויקהל/פקודי"),
+    ("Vayakhel", "ויקהל"),
+    ("Pikudei", "פקודי"),
+    ("Vayikra", "ויקרא"),
+    ("Tzav", "צו"),
+    ("Shemini", "שמיני"),
+    ("Tazriya/Metzorah", "תזריע/מצורע"),
+    ("Tazriya", "תזריע"),
+    ("Metzorah", "מצורע"),
+    ("Acharei Mos/Kedoshim", "אחרי מות/קדושים"),
+    ("Acharei Mos", "אחרי מות"),
+    ("Kedoshim", "קדושים"),
+    ("Emor", "אמור"),
+    ("Behar/Bechukosai", "בהר/בחוקותי"),
+    ("Behar", "בהר"),
+    ("Bechukosai", "בחוקותי"),
+    ("Bamidbar", "במדבר"),
+    ("Naso", "נשא"),
+    ("Behaaloscha", "בהעלותך"),
+    ("Shlach", "שלח"),
+    ("Korach", "קרח"),
+    ("Chukas/Balak", "חקת/בלק"),
+    ("Chukas", "חקת"),
+    ("Balak", "בלק"),
+    ("Pinchas", "פינחס"),
+    ("Matos/Maasei", "מטות/מסעי"),
+    ("Matos", "מטות"),
+    ("Maasei", "מסעי"),
+    ("Devarim", "דברים"),
+    ("Vaeschanan", "ואתחנן"),
+    ("Eikev", "עקב"),
+    ("Re'eh", "ראה"),
+    ("Shoftim", "שופטים"),
+    ("Ki Seitzei", "כי תצא"),
+    ("Ki Savo", "כי תבוא"),
+    ("Nitzavim/Vayelech", "ניצבים/וילך"),
+    ("Nitzavim", "ניצבים"),
+    // Special parshiyos
+    ("Parshas Zachor", "פרשת זכור"),
+    ("Parshas HaChodesh", "פרשת החודש"),
+    ("Parshas Parah", "פרשת פרה"),
+    ("Parshas Shekalim", "פרשת שקלים"),
+    // Books of Navi (Shoftim is with the parshiyos)
+    ("Yehoshua", "יהושע"),
+    ("Shmuel I", "שמואל א"),
+    ("Shmuel II", "שמואל ב"),
+    ("Melachim I", "מלכים א"),
+    ("Melachim II", "מלכים ב"),
+    ("Yeshayahu", "ישעיהו"),
+    ("Yirmiyahu", "ירמיהו"),
+    ("Yechezkel", "יחזקאל"),
+    ("Hoshea", "הושע"),
+    ("Yoel", "יואל"),
+    ("Amos", "עמוס"),
+    ("Ovadia", "עובדיה"),
+    ("Micha", "מיכה"),
+    ("Chavakuk", "חבקוק"),
+    ("Zecharia", "זכריה"),
+    ("Malachi", "מלאכי"),
+    // Minor holidays
+    // The days of the Omer were generated from https://play.golang.org/p/LH0qQmYxZsP
+    ("1st day of the Omer", "היום יום 1 לעומר"),
+    ("2nd day of the Omer", "היום יום 2 לעומר"),
+    ("3rd day of the Omer", "היום יום 3 לעומר"),
+    ("4th day of the Omer", "היום יום 4 לעומר"),
+    ("5th day of the Omer", "היום יום 5 לעומר"),
+    ("6th day of the Omer", "היום יום 6 לעומר"),
+    ("7th day of the Omer", "היום יום 7 לעומר"),
+    ("8th day of the Omer", "היום יום 8 לעומר"),
+    ("9th day of the Omer", "היום יום 9 לעומר"),
+    ("10th day of the Omer", "היום יום 10 לעומר"),
+    ("11th day of the Omer", "היום יום 11 לעומר"),
+    ("12th day of the Omer", "היום יום 12 לעומר"),
+    ("13th day of the Omer", "היום יום 13 לעומר"),
+    ("14th day of the Omer", "היום יום 14 לעומר"),
+    ("15th day of the Omer", "היום יום 15 לעומר"),
+    ("16th day of the Omer", "היום יום 16 לעומר"),
+    ("17th day of the Omer", "היום יום 17 לעומר"),
+    ("18th day of the Omer", "היום יום 18 לעומר"),
+    ("19th day of the Omer", "היום יום 19 לעומר"),
+    ("20th day of the Omer", "היום יום 20 לעומר"),
+    ("21st day of the Omer", "היום יום 21 לעומר"),
+    ("22nd day of the Omer", "היום יום 22 לעומר"),
+    ("23rd day of the Omer", "היום יום 23 לעומר"),
+    ("24th day of the Omer", "היום יום 24 לעומר"),
+    ("25th day of the Omer", "היום יום 25 לעומר"),
+    ("26th day of the Omer", "היום יום 26 לעומר"),
+    ("27th day of the Omer", "היום יום 27 לעומר"),
+    ("28th day of the Omer", "היום יום 28 לעומר"),
+    ("29th day of the Omer", "היום יום 29 לעומר"),
+    ("30th day of the Omer", "היום יום 30 לעומר"),
+    ("31st day of the Omer", "היום יום 31 לעומר"),
+    ("32nd day of the Omer", "היום יום 32 לעומר"),
+    ("33rd day of the Omer", "היום יום 33 לעומר"),
+    ("34th day of the Omer", "היום יום 34 לעומר"),
+    ("35th day of the Omer", "היום יום 35 לעומר"),
+    ("36th day of the Omer", "היום יום 36 לעומר"),
+    ("37th day of the Omer", "היום יום 37 לעומר"),
+    ("38th day of the Omer", "היום יום 38 לעומר"),
+    ("39th day of the Omer", "היום יום 39 לעומר"),
+    ("40th day of the Omer", "היום יום 40 לעומר"),
+    ("41st day of the Omer", "היום יום 41 לעומר"),
+    ("42nd day of the Omer", "היום יום 42 לעומר"),
+    ("43rd day of the Omer", "היום יום 43 לעומר"),
+    ("44th day of the Omer", "היום יום 44 לעומר"),
+    ("45th day of the Omer", "היום יום 45 לעומר"),
+    ("46th day of the Omer", "היום יום 46 לעומר"),
+    ("47th day of the Omer", "היום יום 47 לעומר"),
+    ("48th day of the Omer", "היום יום 48 לעומר"),
+    ("49th day of the Omer", "היום יום 49 לעומר"),
+    ("Erev Pesach", "ערב פסח"),
+    ("Erev Sukkos", "ערב סוכות"),
+    ("Erev Shavuos", "ערב שבועות"),
+    ("Erev Yom Kippur", "ערב יום כיפור"),
+    ("Erev Rosh Hashana", "ערב ראש השנה"),
+    ("Pesach Sheni", "ערב פסח שני"),
+    ("Lag BaOmer", "ל\"ג בעומר"),
+    ("15th of Av", "ט\"ו באב"),
+    ("15th of Shevat", "ט\"ו בשבט"),
+    ("Purim Kattan", "פורים קטן"),
+    ("Shushan Purim Kattan", "שושן פורים קטן"),
+    ("Purim Meshulash: Megillah reading and gifts to the poor", "פורים המשולש: קריאת המגילה ומתנות לאביונים"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "פורים המשולש: על הניסים וקריאת התורה של פורים"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "פורים המשולש: סעודת פורים ומשלוח מנות"),
+    ("Shabbos HaGadol", "שבת הגדול"),
+    ("Taanis Bechoros", "תענית בכורות"),
+    ("Shabbos Chazon", "שבת חזון"),
+    ("Shabbos Nachamu", "שבת נחמו"),
+    ("Leil Slichos", "ליל סליחות"),
+    ("Shabbos Shuva", "שבת שובה"),
+    ("Shabbos Rosh Chodesh", "שבת ראש חודש"),
+    ("Shabbos Machar Chodesh", "שבת מחר חודש"),
+    ("Shabbos Shira", "שבת שירה"),
+    ("Shabbos Chanukah", "שבת חנוכה"),
+    ("Second Shabbos Chanukah", "שבת חנוכה שנייה"),
+    ("Shabbos Chol HaMoed Sukkos", "שבת חול המועד סוכות"),
+    ("Shabbos Chol HaMoed Pesach", "שבת חול המועד פסח"),
+    ("Shabbos Shekalim", "שבת שקלים"),
+    ("Shabbos Zachor", "שבת זכור"),
+    ("Shabbos Parah", "שבת פרה"),
+    ("Shabbos HaChodesh", "שבת החודש"),
+    ("Shabbos Mevarchim", "שבת מברכים"),
+    // Modern Israeli holidays
+    ("Yom HaAtzmaut", "יום העצמאות"),
+    ("Yom HaZikaron", "יום הזיכרון"),
+    ("Yom Yerushalayim", "יום ירושלים"),
+    ("Yom HaShoah", "יום השואה"),
+    ("Yom HaAliyah", "יום העלייה"),
+    ("Yom HaAliyah (10 Nissan)", "יום העלייה (י' ניסן)"),
+    ("Sigd", "סיגד"),
+    ("Yitzhak Rabin Memorial Day", "יום הזיכרון ליצחק רבין"),
+    ("Ben-Gurion Day", "יום בן-גוריון"),
+    ("Hebrew Language Day", "יום השפה העברית"),
+    ("Family Day", "יום המשפחה"),
+    ("Herzl Day", "יום הרצל"),
+    ("Jabotinsky Day", "יום ז'בוטינסקי"),
+    // Chabad holidays
+    ("Vav Tishrei", "ו תשרי"),
+    ("Yud Kislev", "י בכסלו"),
+    ("Yud Tes Kislev", "י\"ט כסלו"),
+    ("Chof Kislev", "כ כסלו"),
+    ("Hei Teves", "ה טבת"),
+    ("Chof Daled Teves", "כ\"ד טבת"),
+    ("Yud Shvat", "י שבט"),
+    ("Chof Beis Shvat", "כ\"ב שבט"),
+    ("Yud Aleph Nissan", "י\"א ניסן"),
+    ("Gimmel Tammuz", "ג תמוז"),
+    ("Yud Beis Tammuz", "י\"ב תמוז"),
+    ("Yud Gimmel Tammuz", "י\"ג תמוז"),
+    ("Chof Av", "כ אב"),
+    ("Chai Elul", "ח\"י אלול"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "שבת מברכים {0} זמן המולד: יום {1} {3}, {4} ו {5} חלקים ({6} לפי שעון החורף בישראל). {7}"),
+    ("Rosh Chodesh {} will be on {}", "ראש חודש {} יהיה ביום {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "ראש חודש {} יהיה ביום {} ולמחרתו ביום {}"),
+    ("Kiddush Levana {}: From {} until {}", "קידוש לבנה {}: מ{} עד {}"),
+    // Rare events
+    ("Tekufas {}: {}", "תקופת {}: {}"),
+    ("Birkas HaChama", "ברכת החמה"),
+    ("Hakhel", "הקהל"),
+    ("Erev Pesach on Shabbos", "ערב פסח שחל בשבת"),
+    // Mourning periods
+    ("{}, through {}", "{}, עד {} (כולל)"),
+    ("{}, until the morning of {}", "{}, עד הבוקר של {}"),
+    ("Sefirah", "ימי הספירה"),
+    ("The Three Weeks", "בין המצרים"),
+    ("The Nine Days", "תשעת הימים"),
+    // convert
+    ("{}: From {} to {}.", "{}: {} - {}."),
+    ("{} -> From sunset {} to sunset {}.", "{}: {} - {}."),
+    ("{} at {} in {}: {}.", "{} בשעה {} ({}): {}."),
+    ("{}: {}. This week's parsha: {}.", "{}: יום {}. פרשת השבוע: {}."),
+    // day-info
+    ("{}: {} {} Said in the Amidah: {}.", "{}: {} {} אומרים בתפילה: {}."),
+    ("Tachanun is said at Shacharis and Mincha.", "אומרים תחנון בשחרית ובמנחה."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "אומרים תחנון בשחרית אך לא במנחה."),
+    ("Tachanun is not said.", "אין אומרים תחנון."),
+    ("Full Hallel is said.", "אומרים הלל שלם."),
+    ("Half Hallel is said.", "אומרים חצי הלל."),
+    ("Hallel is not said.", "אין אומרים הלל."),
+    ("Yaaleh VeYavo", "יעלה ויבוא"),
+    ("Al HaNissim", "על הניסים"),
+    ("Mashiv HaRuach", "משיב הרוח"),
+    ("Morid HaTal", "מוריד הטל"),
+    ("V'sen Tal uMatar", "ותן טל ומטר"),
+    // year-info
+    ("{}: A leap year, year {} of the Shmita cycle.", "{}: שנה מעוברת, שנה {} במחזור השמיטה."),
+    ("{}: Year {} of the Shmita cycle.", "{}: שנה {} במחזור השמיטה."),
+    (" Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.", " קביעות {1}."),
+    ("Shmita year", "שנת שמיטה"),
+    ("Hakhel on Sukkos", "הקהל בסוכות"),
+    ("Maaser Rishon and Maaser Sheni", "מעשר ראשון ומעשר שני"),
+    ("Maaser Rishon and Maaser Ani", "מעשר ראשון ומעשר עני"),
+    ("Biur Maaser on Erev Pesach ({})", "ביעור מעשרות בערב פסח ({})"),
+    // diff
+    ("Hebrew calendar: years: {}, months: {}, days: {}.", "לוח עברי: שנים: {}, חודשים: {}, ימים: {}."),
+    ("Gregorian calendar: years: {}, months: {}, days: {}.", "לוח לועזי: שנים: {}, חודשים: {}, ימים: {}."),
+    ("Days in all: {}.", "סך הכל ימים: {}."),
+    // wedding-check and wedding-dates
+    ("A wedding may be held.", "אפשר לערוך חתונה."),
+    ("A wedding isn't held because of {}.", "אין עורכים חתונה בגלל {}."),
+    ("Shabbos", "שבת"),
+    ("Yom Tov", "יום טוב"),
+    ("Chol HaMoed", "חול המועד"),
+    ("a fast day", "תענית"),
+    ("the Three Weeks", "בין המצרים"),
+    ("the Nine Days", "תשעת הימים"),
+    ("the Ten Days of Repentance", "עשרת ימי תשובה"),
+    // Daf Yomi
+    ("Berakhot", "ברכות"),
+    ("Shabbat", "שבת"),
+    ("Eruvin", "עירובין"),
+    ("Pesachim", "פסחים"),
+    ("Shekalim", "שקלים"),
+    ("Yoma", "יומא"),
+    ("Sukkah", "סוכה"),
+    ("Beitzah", "ביצה"),
+    ("Rosh Hashanah", "ראש השנה"),
+    ("Taanit", "תענית"),
+    ("Megillah", "מגילה"),
+    ("Moed Katan", "מועד קטן"),
+    ("Chagigah", "חגיגה"),
+    ("Yevamot", "יבמות"),
+    ("Ketubot", "כתובות"),
+    ("Nedarim", "נדרים"),
+    ("Nazir", "נזיר"),
+    ("Sotah", "סוטה"),
+    ("Gittin", "גיטין"),
+    ("Kiddushin", "קידושין"),
+    ("Bava Kamma", "בבא קמא"),
+    ("Bava Metzia", "בבא מציעא"),
+    ("Bava Batra", "בבא בתרא"),
+    ("Sanhedrin", "סנהדרין"),
+    ("Makkot", "מכות"),
+    ("Shevuot", "שבועות"),
+    ("Avodah Zarah", "עבודה זרה"),
+    ("Horayot", "הוריות"),
+    ("Zevachim", "זבחים"),
+    ("Menachot", "מנחות"),
+    ("Chullin", "חולין"),
+    ("Bekhorot", "בכורות"),
+    ("Arakhin", "ערכין"),
+    ("Temurah", "תמורה"),
+    ("Keritot", "כריתות"),
+    ("Meilah", "מעילה"),
+    ("Niddah", "נדה"),
+    // Yerushalmi Yomi
+    ("Jerusalem Talmud Berakhot", "תלמוד ירושלמי ברכות"),
+    ("Jerusalem Talmud Peah", "תלמוד ירושלמי פאה"),
+    ("Jerusalem Talmud Demai", "תלמוד ירושלמי דמאי"),
+    ("Jerusalem Talmud Kilayim", "תלמוד ירושלמי כלאים"),
+    ("Jerusalem Talmud Shevi'it", "תלמוד ירושלמי שביעית"),
+    ("Jerusalem Talmud Terumot", "תלמוד ירושלמי תרומות"),
+    ("Jerusalem Talmud Ma'asrot", "תלמוד ירושלמי מעשרות"),
+    ("Jerusalem Talmud Ma'aser Sheni", "תלמוד ירושלמי מעשר שני"),
+    ("Jerusalem Talmud Hallah", "תלמוד ירושלמי חלה"),
+    ("Jerusalem Talmud Orlah", "תלמוד ירושלמי ערלה"),
+    ("Jerusalem Talmud Bikkurim", "תלמוד ירושלמי בכורים"),
+    ("Jerusalem Talmud Shabbat", "תלמוד ירושלמי שבת"),
+    ("Jerusalem Talmud Eruvin", "תלמוד ירושלמי עירובין"),
+    ("Jerusalem Talmud Pesachim", "תלמוד ירושלמי פסחים"),
+    ("Jerusalem Talmud Beitzah", "תלמוד ירושלמי ביצה"),
+    ("Jerusalem Talmud Rosh Hashanah", "תלמוד ירושלמי ראש השנה"),
+    ("Jerusalem Talmud Yoma", "תלמוד ירושלמי יומא"),
+    ("Jerusalem Talmud Sukkah", "תלמוד ירושלמי סוכה"),
+    ("Jerusalem Talmud Ta'anit", "תלמוד ירושלמי תענית"),
+    ("Jerusalem Talmud Shekalim", "תלמוד ירושלמי שקלים"),
+    ("Jerusalem Talmud Megillah", "תלמוד ירושלמי מגילה"),
+    ("Jerusalem Talmud Chagigah", "תלמוד ירושלמי חגיגה"),
+    ("Jerusalem Talmud Moed Kattan", "תלמוד ירושלמי מועד קטן"),
+    ("Jerusalem Talmud Yevamot", "תלמוד ירושלמי יבמות"),
+    ("Jerusalem Talmud Ketubot", "תלמוד ירושלמי כתובות"),
+    ("Jerusalem Talmud Sotah", "תלמוד ירושלמי סוטה"),
+    ("Jerusalem Talmud Nedarim", "תלמוד ירושלמי נדרים"),
+    ("Jerusalem Talmud Nazir", "תלמוד ירושלמי נזיר"),
+    ("Jerusalem Talmud Gittin", "תלמוד ירושלמי גיטין"),
+    ("Jerusalem Talmud Kiddushin", "תלמוד ירושלמי קידושין"),
+    ("Jerusalem Talmud Bava Kamma", "תלמוד ירושלמי בבא קמא"),
+    ("Jerusalem Talmud Bava Metsia", "תלמוד ירושלמי בבא מציעא"),
+    ("Jerusalem Talmud Bava Batra", "תלמוד ירושלמי בבא בתרא"),
+    ("Jerusalem Talmud Shevuot", "תלמוד ירושלמי שבועות"),
+    ("Jerusalem Talmud Makkot", "תלמוד ירושלמי מכות"),
+    ("Jerusalem Talmud Sanhedrin", "תלמוד ירושלמי סנהדרין"),
+    ("Jerusalem Talmud Avodah Zarah", "תלמוד ירושלמי עבודה זרה"),
+    ("Jerusalem Talmud Horayot", "תלמוד ירושלמי הוריות"),
+    ("Jerusalem Talmud Niddah", "תלמוד ירושלמי נדה"),
+    // Rambam
+    ("Rambam|Transmission of the Oral Law", "מסירת תורה שבעל פה"),
+    ("Rambam|Positive Mitzvot", "מצוות עשה"),
+    ("Rambam|Negative Mitzvot", "מצוות לא תעשה"),
+    ("Rambam|Overview of Mishneh Torah Contents", "תוכן החיבור"),
+    ("Rambam|Foundations of the Torah", "הלכות יסודי התורה"),
+    ("Rambam|Human Dispositions", "הלכות דעות"),
+    ("Rambam|Torah Study", "הלכות תלמוד תורה"),
+    ("Rambam|Foreign Worship and Customs of the Nations", "הלכות עבודה זרה וחוקות הגויים"),
+    ("Rambam|Repentance", "הלכות תשובה"),
+    ("Rambam|Reading the Shema", "הלכות קריאת שמע"),
+    ("Rambam|Prayer and the Priestly Blessing", "הלכות תפילה וברכת כהנים"),
+    ("Rambam|Tefillin, Mezuzah and the Torah Scroll", "הלכות תפילין ומזוזה וספר תורה"),
+    ("Rambam|Fringes", "הלכות ציצית"),
+    ("Rambam|Blessings", "הלכות ברכות"),
+    ("Rambam|Circumcision", "הלכות מילה"),
+    ("Rambam|The Order of Prayer", "סדר התפילה"),
+    ("Rambam|Sabbath", "הלכות שבת"),
+    ("Rambam|Eruvin", "הלכות עירובין"),
+    ("Rambam|Rest on the Tenth of Tishrei", "הלכות שביתת עשור"),
+    ("Rambam|Rest on a Holiday", "הלכות שביתת יום טוב"),
+    ("Rambam|Leavened and Unleavened Bread", "הלכות חמץ ומצה"),
+    ("Rambam|Shofar, Sukkah and Lulav", "הלכות שופר וסוכה ולולב"),
+    ("Rambam|Sheqel Dues", "הלכות שקלים"),
+    ("Rambam|Sanctification of the New Month", "הלכות קידוש החודש"),
+    ("Rambam|Fasts", "הלכות תעניות"),
+    ("Rambam|Scroll of Esther and Hanukkah", "הלכות מגילה וחנוכה"),
+    ("Rambam|Marriage", "הלכות אישות"),
+    ("Rambam|Divorce", "הלכות גירושין"),
+    ("Rambam|Levirate Marriage and Release", "הלכות יבום וחליצה"),
+    ("Rambam|Virgin Maiden", "הלכות נערה בתולה"),
+    ("Rambam|Woman Suspected of Infidelity", "הלכות סוטה"),
+    ("Rambam|Forbidden Intercourse", "הלכות איסורי ביאה"),
+    ("Rambam|Forbidden Foods", "הלכות מאכלות אסורות"),
+    ("Rambam|Ritual Slaughter", "הלכות שחיטה"),
+    ("Rambam|Oaths", "הלכות שבועות"),
+    ("Rambam|Vows", "הלכות נדרים"),
+    ("Rambam|Nazariteship", "הלכות נזירות"),
+    ("Rambam|Appraisals and Devoted Property", "הלכות ערכים וחרמין"),
+    ("Rambam|Diverse Species", "הלכות כלאים"),
+    ("Rambam|Gifts to the Poor", "הלכות מתנות עניים"),
+    ("Rambam|Heave Offerings", "הלכות תרומות"),
+    ("Rambam|Tithes", "הלכות מעשרות"),
+    ("Rambam|Second Tithes and Fourth Year's Fruit", "הלכות מעשר שני ונטע רבעי"),
+    ("Rambam|First Fruits and other Gifts to Priests Outside the Sanctuary", "הלכות ביכורים ושאר מתנות כהונה שבגבולין"),
+    ("Rambam|Sabbatical Year and the Jubilee", "הלכות שמיטה ויובל"),
+    ("Rambam|The Chosen Temple", "הלכות בית הבחירה"),
+    ("Rambam|Vessels of the Sanctuary and Those who Serve Therein", "הלכות כלי המקדש והעובדין בו"),
+    ("Rambam|Admission into the Sanctuary", "הלכות ביאת מקדש"),
+    ("Rambam|Things Forbidden on the Altar", "הלכות איסורי המזבח"),
+    ("Rambam|Sacrificial Procedure", "הלכות מעשה הקרבנות"),
+    ("Rambam|Daily Offerings and Additional Offerings", "הלכות תמידים ומוספין"),
+    ("Rambam|Sacrifices Rendered Unfit", "הלכות פסולי המוקדשין"),
+    ("Rambam|Service on the Day of Atonement", "הלכות עבודת יום הכפורים"),
+    ("Rambam|Trespass", "הלכות מעילה"),
+    ("Rambam|Paschal Offering", "הלכות קרבן פסח"),
+    ("Rambam|Festival Offering", "הלכות חגיגה"),
+    ("Rambam|Firstlings", "הלכות בכורות"),
+    ("Rambam|Offerings for Unintentional Transgressions", "הלכות שגגות"),
+    ("Rambam|Offerings for Those with Incomplete Atonement", "הלכות מחוסרי כפרה"),
+    ("Rambam|Substitution", "הלכות תמורה"),
+    ("Rambam|Defilement by a Corpse", "הלכות טומאת מת"),
+    ("Rambam|Red Heifer", "הלכות פרה אדומה"),
+    ("Rambam|Defilement by Leprosy", "הלכות טומאת צרעת"),
+    ("Rambam|Those Who Defile Bed or Seat", "הלכות מטמאי משכב ומושב"),
+    ("Rambam|Other Sources of Defilement", "הלכות שאר אבות הטומאות"),
+    ("Rambam|Defilement of Foods", "הלכות טומאת אוכלים"),
+    ("Rambam|Vessels", "הלכות כלים"),
+    ("Rambam|Immersion Pools", "הלכות מקואות"),
+    ("Rambam|Damages to Property", "הלכות נזקי ממון"),
+    ("Rambam|Theft", "הלכות גניבה"),
+    ("Rambam|Robbery and Lost Property", "הלכות גזילה ואבידה"),
+    ("Rambam|One Who Injures a Person or Property", "הלכות חובל ומזיק"),
+    ("Rambam|Murderer and the Preservation of Life", "הלכות רוצח ושמירת נפש"),
+    ("Rambam|Sales", "הלכות מכירה"),
+    ("Rambam|Ownerless Property and Gifts", "הלכות זכייה ומתנה"),
+    ("Rambam|Neighbors", "הלכות שכנים"),
+    ("Rambam|Agents and Partners", "הלכות שלוחין ושותפין"),
+    ("Rambam|Slaves", "הלכות עבדים"),
+    ("Rambam|Hiring", "הלכות שכירות"),
+    ("Rambam|Borrowing and Deposit", "הלכות שאלה ופיקדון"),
+    ("Rambam|Creditor and Debtor", "הלכות מלווה ולווה"),
+    ("Rambam|Plaintiff and Defendant", "הלכות טוען ונטען"),
+    ("Rambam|Inheritances", "הלכות נחלות"),
+    ("Rambam|The Sanhedrin and the Penalties within their Jurisdiction", "הלכות סנהדרין והעונשין המסורין להם"),
+    ("Rambam|Testimony", "הלכות עדות"),
+    ("Rambam|Rebels", "הלכות ממרים"),
+    ("Rambam|Mourning", "הלכות אבל"),
+    ("Rambam|Kings and Wars", "הלכות מלכים ומלחמות"),
+];
@@ -0,0 +1,154 @@
+//! Everything that's printed for people (as opposed to JSON) is written in English in the code,
+//! and looked up in the catalog of the chosen language when it's printed. A message that a catalog
+//! doesn't have is looked up in its fallback language, and in the end printed in English.
+//!
+//! Some words are translated differently depending on where they're used (Shabbos the day of the
+//! week is שבת קודש, but the tractate is שבת). Their messages start with a context and a `|`, like
+//! `weekday|Shabbos`, and the context isn't printed in English.
+//!
+//! The English keys are spelled the Ashkenazi way, and the other ways of spelling them in English
+//! are catalogs of their own.
+//!
+//! In messages with arguments, `{}` is the next argument and `{0}`, `{1}`... are a given one, so a
+//! translation can reorder the arguments or leave some out.
+
+use crate::args::types::{Language, Transliteration};
+use crate::prelude::print::gematria;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+
+mod academic;
+mod es;
+mod fr;
+mod he;
+mod pt;
+mod ru;
+mod sephardi;
+mod yi;
+
+type Messages = HashMap<&'static str, &'static str>;
+
+/// The catalogs are indexed the first time they're used, so looking up a message doesn't scan them.
+fn index(messages: &'static [(&'static str, &'static str)]) -> Messages {
+    messages.iter().copied().collect()
+}
+
+static ENGLISH: Lazy<Messages> = Lazy::new(Messages::new);
+static SEPHARDI: Lazy<Messages> = Lazy::new(|| index(sephardi::MESSAGES));
+static ACADEMIC: Lazy<Messages> = Lazy::new(|| index(academic::MESSAGES));
+static HEBREW: Lazy<Messages> = Lazy::new(|| index(he::MESSAGES));
+static FRENCH: Lazy<Messages> = Lazy::new(|| index(fr::MESSAGES));
+static RUSSIAN: Lazy<Messages> = Lazy::new(|| index(ru::MESSAGES));
+static SPANISH: Lazy<Messages> = Lazy::new(|| index(es::MESSAGES));
+static YIDDISH: Lazy<Messages> = Lazy::new(|| index(yi::MESSAGES));
+static PORTUGUESE: Lazy<Messages> = Lazy::new(|| index(pt::MESSAGES));
+
+struct Catalog {
+    messages: &'static Messages,
+    fallback: Option<Language>,
+    /// Whether numbers (days of the month, years, chapters and dafs) are written in Hebrew letters.
+    hebrew_numerals: bool,
+}
+
+impl Language {
+    fn catalog(self) -> Catalog {
+        let (messages, fallback, hebrew_numerals) = match self {
+            Language::English(Transliteration::Ashkenazi) => (&ENGLISH, None, false),
+            Language::English(Transliteration::Sephardi) => (&SEPHARDI, None, false),
+            Language::English(Transliteration::Academic) => (
+                &ACADEMIC,
+                Some(Language::English(Transliteration::Sephardi)),
+                false,
+            ),
+            Language::Hebrew => (&HEBREW, None, true),
+            Language::French => (&FRENCH, None, false),
+            Language::Russian => (&RUSSIAN, None, false),
+            Language::Spanish => (&SPANISH, None, false),
+            // Names of parshiyos, seforim and minor holidays are spelled in Yiddish the way
+            // they're spelled in Hebrew.
+            Language::Yiddish => (&YIDDISH, Some(Language::Hebrew), true),
+            Language::Portuguese => (&PORTUGUESE, None, false),
+        };
+        Catalog {
+            messages,
+            fallback,
+            hebrew_numerals,
+        }
+    }
+
+    /// `message` in this language.
+    pub fn tr(self, message: &str) -> &str {
+        self.lookup(message)
+            .unwrap_or_else(|| message.rsplit('|').next().unwrap())
+    }
+
+    /// The translation of `message` in this language's catalog or its fallback's, if it has one.
+    fn lookup(self, message: &str) -> Option<&'static str> {
+        let catalog = self.catalog();
+        match catalog.messages.get(message) {
+            Some(translation) => Some(translation),
+            None => catalog
+                .fallback
+                .and_then(|fallback| fallback.lookup(message)),
+        }
+    }
+
+    /// `message` in this language, with its arguments filled in.
+    pub fn tr_format(self, message: &str, args: &[&dyn Display]) -> String {
+        let translation = self.tr(message);
+        let mut ret = String::with_capacity(translation.len());
+        let mut next = 0;
+        let mut rest = translation;
+        while let Some(start) = rest.find('{') {
+            ret.push_str(&rest[..start]);
+            let end = start + rest[start..].find('}').unwrap();
+            let index = match &rest[start + 1..end] {
+                "" => {
+                    next += 1;
+                    next - 1
+                }
+                index => index.parse().unwrap(),
+            };
+            write!(ret, "{}", args[index]).unwrap();
+            rest = &rest[end + 1..];
+        }
+        ret.push_str(rest);
+        ret
+    }
+
+    /// Whether numbers are written in Hebrew letters.
+    pub fn hebrew_numerals(self) -> bool {
+        self.catalog().hebrew_numerals
+    }
+
+    /// `number` in Hebrew letters or in digits, as it's written in this language.
+    pub fn number(self, number: u64) -> String {
+        if self.hebrew_numerals() {
+            gematria(number)
+        } else {
+            number.to_string()
+        }
+    }
+}
+
+#[test]
+fn every_catalog_has_every_hebrew_message() {
+    let languages = [
+        ("fr", Language::French),
+        ("ru", Language::Russian),
+        ("es", Language::Spanish),
+        ("yi", Language::Yiddish),
+        ("pt", Language::Portuguese),
+    ];
+    for (name, language) in languages.iter() {
+        for (message, _) in he::MESSAGES {
+            assert!(
+                language.lookup(message).is_some(),
+                "{} has no translation of {:?}",
+                name,
+                message
+            );
+        }
+    }
+}
@@ -0,0 +1,541 @@
+//! The Portuguese catalog.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("Night of ", "Noite de "),
+    ("Candle lighting", "Acendimento das velas"),
+    ("Haftarah", "Haftará"),
+    ("Maftir", "Maftir"),
+    ("In Israel", "Em Israel"),
+    ("In Chutz La'aretz", "Fora de Israel"),
+    ("Triennial year", "Ano do ciclo trienal"),
+    // Gregorian dates
+    ("%A %B %-d %Y", "%A, %-d de %B de %Y"),
+    ("%A %B %-d %H:%M", "%A, %-d de %B %H:%M"),
+    ("Sunday", "domingo"),
+    ("Monday", "segunda-feira"),
+    ("Tuesday", "terça-feira"),
+    ("Wednesday", "quarta-feira"),
+    ("Thursday", "quinta-feira"),
+    ("Friday", "sexta-feira"),
+    ("Saturday", "sábado"),
+    ("January", "janeiro"),
+    ("February", "fevereiro"),
+    ("March", "março"),
+    ("April", "abril"),
+    ("May", "maio"),
+    ("June", "junho"),
+    ("July", "julho"),
+    ("August", "agosto"),
+    ("September", "setembro"),
+    ("October", "outubro"),
+    ("November", "novembro"),
+    ("December", "dezembro"),
+    // Days of the week in the Hebrew calendar
+    ("weekday|Sunday", "domingo"),
+    ("weekday|Monday", "segunda-feira"),
+    ("weekday|Tuesday", "terça-feira"),
+    ("weekday|Wednesday", "quarta-feira"),
+    ("weekday|Thursday", "quinta-feira"),
+    ("weekday|Friday", "sexta-feira"),
+    ("weekday|Shabbos", "Shabat"),
+    // Hebrew months
+    ("Tishrei", "Tishrê"),
+    ("Cheshvan", "Cheshvan"),
+    ("Kislev", "Kislev"),
+    ("Teves", "Tevet"),
+    ("Shvat", "Shevat"),
+    ("Adar", "Adar"),
+    ("Adar Rishon", "Adar Rishon"),
+    ("Adar Sheni", "Adar Sheni"),
+    ("Nissan", "Nissan"),
+    ("Iyar", "Iyar"),
+    ("Sivan", "Sivan"),
+    ("Tammuz", "Tamuz"),
+    ("Av", "Av"),
+    ("Elul", "Elul"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "1º dia de Rosh Hashaná"),
+    ("2nd day of Rosh Hashanah", "2º dia de Rosh Hashaná"),
+    ("Yom Kippur", "Yom Kipur"),
+    ("1st day of Sukkos", "1º dia de Sucot"),
+    ("2nd day of Sukkos", "2º dia de Sucot"),
+    ("3rd day of Sukkos", "3º dia de Sucot"),
+    ("4th day of Sukkos", "4º dia de Sucot"),
+    ("5th day of Sukkos", "5º dia de Sucot"),
+    ("6th day of Sukkos", "6º dia de Sucot"),
+    ("7th day of Sukkos", "7º dia de Sucot"),
+    ("Shmini Atzeres", "Shemini Atseret"),
+    ("Simchas Torah", "Simchat Torá"),
+    ("1st day of Pesach", "1º dia de Pessach"),
+    ("2nd day of Pesach", "2º dia de Pessach"),
+    ("3rd day of Pesach", "3º dia de Pessach"),
+    ("4th day of Pesach", "4º dia de Pessach"),
+    ("5th day of Pesach", "5º dia de Pessach"),
+    ("6th day of Pesach", "6º dia de Pessach"),
+    ("7th day of Pesach", "7º dia de Pessach"),
+    ("8th day of Pesach", "8º dia de Pessach"),
+    ("1st day of Shavuos", "1º dia de Shavuot"),
+    ("2nd day of Shavuos", "2º dia de Shavuot"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "1º dia de Rosh Chodesh Cheshvan"),
+    ("2nd day of Rosh Chodesh Cheshvan", "2º dia de Rosh Chodesh Cheshvan"),
+    ("Rosh Chodesh Kislev", "Rosh Chodesh Kislev"),
+    ("1st day of Rosh Chodesh Kislev", "1º dia de Rosh Chodesh Kislev"),
+    ("2nd day of Rosh Chodesh Kislev", "2º dia de Rosh Chodesh Kislev"),
+    ("Rosh Chodesh Teves", "Rosh Chodesh Tevet"),
+    ("1st day of Rosh Chodesh Teves", "1º dia de Rosh Chodesh Tevet"),
+    ("2nd day of Rosh Chodesh Teves", "2º dia de Rosh Chodesh Tevet"),
+    ("Rosh Chodesh Shvat", "Rosh Chodesh Shevat"),
+    ("1st day of Rosh Chodesh Adar", "1º dia de Rosh Chodesh Adar"),
+    ("2nd day of Rosh Chodesh Adar", "2º dia de Rosh Chodesh Adar"),
+    ("1st day of Rosh Chodesh Adar Rishon", "1º dia de Rosh Chodesh Adar Rishon"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "2º dia de Rosh Chodesh Adar Rishon"),
+    ("1st day of Rosh Chodesh Adar Sheni", "1º dia de Rosh Chodesh Adar Sheni"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "2º dia de Rosh Chodesh Adar Sheni"),
+    ("Rosh Chodesh Nissan", "Rosh Chodesh Nissan"),
+    ("1st day of Rosh Chodesh Iyar", "1º dia de Rosh Chodesh Iyar"),
+    ("2nd day of Rosh Chodesh Iyar", "2º dia de Rosh Chodesh Iyar"),
+    ("Rosh Chodesh Sivan", "Rosh Chodesh Sivan"),
+    ("1st day of Rosh Chodesh Tammuz", "1º dia de Rosh Chodesh Tamuz"),
+    ("2nd day of Rosh Chodesh Tammuz", "2º dia de Rosh Chodesh Tamuz"),
+    ("Rosh Chodesh Av", "Rosh Chodesh Av"),
+    ("1st day of Rosh Chodesh Elul", "1º dia de Rosh Chodesh Elul"),
+    ("2nd day of Rosh Chodesh Elul", "2º dia de Rosh Chodesh Elul"),
+    ("1st day of Chanukah", "1º dia de Chanucá"),
+    ("2nd day of Chanukah", "2º dia de Chanucá"),
+    ("3rd day of Chanukah", "3º dia de Chanucá"),
+    ("4rd day of Chanukah", "4º dia de Chanucá"),
+    ("5rd day of Chanukah", "5º dia de Chanucá"),
+    ("6rd day of Chanukah", "6º dia de Chanucá"),
+    ("7rd day of Chanukah", "7º dia de Chanucá"),
+    ("8rd day of Chanukah", "8º dia de Chanucá"),
+    ("Tzom Gedalia", "Jejum de Guedaliá"),
+    ("Taanis Esther", "Jejum de Ester"),
+    ("Tenth of Teves", "10 de Tevet"),
+    ("Purim", "Purim"),
+    ("Shushan Purim", "Shushan Purim"),
+    ("Seventeenth of Tammuz", "17 de Tamuz"),
+    ("Ninth of Av", "9 de Av"),
+    ("Haazinu", "Haazinu"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Vayelech", "Vayêlech"),
+    ("Bereishis", "Bereshit"),
+    ("Noach", "Nôach"),
+    ("Lech Lecha", "Lech Lechá"),
+    ("Vayeira", "Vayerá"),
+    ("Chayei Sarah", "Chayê Sará"),
+    ("Toldos", "Toledot"),
+    ("Vayetzei", "Vayetsê"),
+    ("Vayishlach", "Vayishlach"),
+    ("Vayeshev", "Vayêshev"),
+    ("Miketz", "Mikets"),
+    ("Vayigash", "Vayigash"),
+    ("Vayechi", "Vayechi"),
+    ("Shemos", "Shemot"),
+    ("Vaeira", "Vaerá"),
+    ("Bo", "Bô"),
+    ("Beshalach", "Beshalach"),
+    ("Yisro", "Yitrô"),
+    ("Mishpatim", "Mishpatim"),
+    ("Terumah", "Terumá"),
+    ("Tetzaveh", "Tetsavê"),
+    ("Ki Sisa", "Ki Tissá"),
+    ("Vayakhel/Pikudei", "Vayakhel/Pekudê"),
+    ("Vayakhel", "Vayakhel"),
+    ("Pikudei", "Pekudê"),
+    ("Vayikra", "Vayicrá"),
+    ("Tzav", "Tsav"),
+    ("Shemini", "Sheminí"),
+    ("Tazriya/Metzorah", "Tazria/Metsorá"),
+    ("Tazriya", "Tazria"),
+    ("Metzorah", "Metsorá"),
+    ("Acharei Mos/Kedoshim", "Acharê Mot/Kedoshim"),
+    ("Acharei Mos", "Acharê Mot"),
+    ("Kedoshim", "Kedoshim"),
+    ("Emor", "Emor"),
+    ("Behar/Bechukosai", "Behar/Bechucotai"),
+    ("Behar", "Behar"),
+    ("Bechukosai", "Bechucotai"),
+    ("Bamidbar", "Bamidbar"),
+    ("Naso", "Nassô"),
+    ("Behaaloscha", "Behaalotechá"),
+    ("Shlach", "Shelach"),
+    ("Korach", "Côrach"),
+    ("Chukas/Balak", "Chucat/Balac"),
+    ("Chukas", "Chucat"),
+    ("Balak", "Balac"),
+    ("Pinchas", "Pinechas"),
+    ("Matos/Maasei", "Matot/Massê"),
+    ("Matos", "Matot"),
+    ("Maasei", "Massê"),
+    ("Devarim", "Devarim"),
+    ("Vaeschanan", "Vaetchanan"),
+    ("Eikev", "Ekev"),
+    ("Re'eh", "Reê"),
+    ("Shoftim", "Shofetim"),
+    ("Ki Seitzei", "Ki Tetsê"),
+    ("Ki Savo", "Ki Tavô"),
+    ("Nitzavim/Vayelech", "Nitsavim/Vayêlech"),
+    ("Nitzavim", "Nitsavim"),
+    // Special parshiyos
+    ("Parshas Zachor", "Parashat Zachor"),
+    ("Parshas HaChodesh", "Parashat HaChodesh"),
+    ("Parshas Parah", "Parashat Pará"),
+    ("Parshas Shekalim", "Parashat Shekalim"),
+    ("Yehoshua", "Yehoshua"),
+    ("Shmuel I", "Shemuel I"),
+    ("Shmuel II", "Shemuel II"),
+    ("Melachim I", "Melachim I"),
+    ("Melachim II", "Melachim II"),
+    ("Yeshayahu", "Yeshayahu"),
+    // Books of Navi (Shoftim is with the parshiyos)
+    ("Yirmiyahu", "Yirmeyahu"),
+    ("Yechezkel", "Yechezkel"),
+    ("Hoshea", "Hoshêa"),
+    ("Yoel", "Yoel"),
+    ("Amos", "Amós"),
+    ("Ovadia", "Ovadiá"),
+    ("Micha", "Michá"),
+    ("Chavakuk", "Chavacuc"),
+    ("Zecharia", "Zecharyá"),
+    ("Malachi", "Malachi"),
+    // Minor holidays
+    ("1st day of the Omer", "1º dia do Ômer"),
+    ("2nd day of the Omer", "2º dia do Ômer"),
+    ("3rd day of the Omer", "3º dia do Ômer"),
+    ("4th day of the Omer", "4º dia do Ômer"),
+    ("5th day of the Omer", "5º dia do Ômer"),
+    ("6th day of the Omer", "6º dia do Ômer"),
+    ("7th day of the Omer", "7º dia do Ômer"),
+    ("8th day of the Omer", "8º dia do Ômer"),
+    ("9th day of the Omer", "9º dia do Ômer"),
+    ("10th day of the Omer", "10º dia do Ômer"),
+    ("11th day of the Omer", "11º dia do Ômer"),
+    ("12th day of the Omer", "12º dia do Ômer"),
+    ("13th day of the Omer", "13º dia do Ômer"),
+    ("14th day of the Omer", "14º dia do Ômer"),
+    ("15th day of the Omer", "15º dia do Ômer"),
+    ("16th day of the Omer", "16º dia do Ômer"),
+    ("17th day of the Omer", "17º dia do Ômer"),
+    ("18th day of the Omer", "18º dia do Ômer"),
+    ("19th day of the Omer", "19º dia do Ômer"),
+    ("20th day of the Omer", "20º dia do Ômer"),
+    ("21st day of the Omer", "21º dia do Ômer"),
+    ("22nd day of the Omer", "22º dia do Ômer"),
+    ("23rd day of the Omer", "23º dia do Ômer"),
+    ("24th day of the Omer", "24º dia do Ômer"),
+    ("25th day of the Omer", "25º dia do Ômer"),
+    ("26th day of the Omer", "26º dia do Ômer"),
+    ("27th day of the Omer", "27º dia do Ômer"),
+    ("28th day of the Omer", "28º dia do Ômer"),
+    ("29th day of the Omer", "29º dia do Ômer"),
+    ("30th day of the Omer", "30º dia do Ômer"),
+    ("31st day of the Omer", "31º dia do Ômer"),
+    ("32nd day of the Omer", "32º dia do Ômer"),
+    ("33rd day of the Omer", "33º dia do Ômer"),
+    ("34th day of the Omer", "34º dia do Ômer"),
+    ("35th day of the Omer", "35º dia do Ômer"),
+    ("36th day of the Omer", "36º dia do Ômer"),
+    ("37th day of the Omer", "37º dia do Ômer"),
+    ("38th day of the Omer", "38º dia do Ômer"),
+    ("39th day of the Omer", "39º dia do Ômer"),
+    ("40th day of the Omer", "40º dia do Ômer"),
+    ("41st day of the Omer", "41º dia do Ômer"),
+    ("42nd day of the Omer", "42º dia do Ômer"),
+    ("43rd day of the Omer", "43º dia do Ômer"),
+    ("44th day of the Omer", "44º dia do Ômer"),
+    ("45th day of the Omer", "45º dia do Ômer"),
+    ("46th day of the Omer", "46º dia do Ômer"),
+    ("47th day of the Omer", "47º dia do Ômer"),
+    ("48th day of the Omer", "48º dia do Ômer"),
+    ("49th day of the Omer", "49º dia do Ômer"),
+    ("Erev Pesach", "Véspera de Pessach"),
+    ("Erev Sukkos", "Véspera de Sucot"),
+    ("Erev Shavuos", "Véspera de Shavuot"),
+    ("Erev Yom Kippur", "Véspera de Yom Kipur"),
+    ("Erev Rosh Hashana", "Véspera de Rosh Hashaná"),
+    ("Pesach Sheni", "Pessach Sheni"),
+    ("Lag BaOmer", "Lag BaÔmer"),
+    ("15th of Av", "Tu BeAv"),
+    ("15th of Shevat", "Tu BiShvat"),
+    ("Purim Kattan", "Purim Catan"),
+    ("Shushan Purim Kattan", "Shushan Purim Catan"),
+    ("Purim Meshulash: Megillah reading and gifts to the poor", "Purim Meshulash: leitura da Meguilá e presentes aos pobres"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "Purim Meshulash: Al HaNissim e a leitura da Torá de Purim"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "Purim Meshulash: banquete de Purim e mishloach manot"),
+    ("Shabbos HaGadol", "Shabat HaGadol"),
+    ("Taanis Bechoros", "Jejum dos primogênitos"),
+    ("Shabbos Chazon", "Shabat Chazon"),
+    ("Shabbos Nachamu", "Shabat Nachamu"),
+    ("Leil Slichos", "Noite de Selichot"),
+    ("Shabbos Shuva", "Shabat Shuvá"),
+    ("Shabbos Rosh Chodesh", "Shabat Rosh Chodesh"),
+    ("Shabbos Machar Chodesh", "Shabat Machar Chodesh"),
+    ("Shabbos Shira", "Shabat Shirá"),
+    ("Shabbos Chanukah", "Shabat Chanucá"),
+    ("Second Shabbos Chanukah", "Segundo Shabat Chanucá"),
+    ("Shabbos Chol HaMoed Sukkos", "Shabat Chol HaMoed Sucot"),
+    ("Shabbos Chol HaMoed Pesach", "Shabat Chol HaMoed Pessach"),
+    ("Shabbos Shekalim", "Shabat Shekalim"),
+    ("Shabbos Zachor", "Shabat Zachor"),
+    ("Shabbos Parah", "Shabat Pará"),
+    ("Shabbos HaChodesh", "Shabat HaChodesh"),
+    ("Shabbos Mevarchim", "Shabat Mevarchim"),
+    // Modern Israeli holidays
+    ("Yom HaAtzmaut", "Yom HaAtsmaut"),
+    ("Yom HaZikaron", "Yom HaZikaron"),
+    ("Yom Yerushalayim", "Yom Yerushalayim"),
+    ("Yom HaShoah", "Yom HaShoá"),
+    ("Yom HaAliyah", "Yom HaAliyah"),
+    ("Yom HaAliyah (10 Nissan)", "Yom HaAliyah (10 de Nissan)"),
+    ("Sigd", "Sigd"),
+    ("Yitzhak Rabin Memorial Day", "Dia em memória de Yitzhak Rabin"),
+    ("Ben-Gurion Day", "Dia de Ben-Gurion"),
+    ("Hebrew Language Day", "Dia da Língua Hebraica"),
+    ("Family Day", "Dia da Família"),
+    ("Herzl Day", "Dia de Herzl"),
+    ("Jabotinsky Day", "Dia de Jabotinsky"),
+    // Chabad holidays
+    ("Vav Tishrei", "Vav Tishrê"),
+    ("Yud Kislev", "Yud Kislev"),
+    ("Yud Tes Kislev", "Yud Tet Kislev"),
+    ("Chof Kislev", "Chof Kislev"),
+    ("Hei Teves", "Hei Tevet"),
+    ("Chof Daled Teves", "Chof Dalet Tevet"),
+    ("Yud Shvat", "Yud Shevat"),
+    ("Chof Beis Shvat", "Chof Beit Shevat"),
+    ("Yud Aleph Nissan", "Yud Alef Nissan"),
+    ("Gimmel Tammuz", "Guimel Tamuz"),
+    ("Yud Beis Tammuz", "Yud Beit Tamuz"),
+    ("Yud Gimmel Tammuz", "Yud Guimel Tamuz"),
+    ("Chof Av", "Chof Av"),
+    ("Chai Elul", "Chai Elul"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "Shabat Mevarchim {0}: o molad é {1}, {3} de {2}, {4} e {5} chalakim ({6} horário padrão de Jerusalém). {7}"),
+    ("Rosh Chodesh {} will be on {}", "Rosh Chodesh {} será {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "Rosh Chodesh {} será {} e {}"),
+    ("Kiddush Levana {}: From {} until {}", "Kidush Levaná {}: de {} até {}"),
+    // Rare events
+    ("Tekufas {}: {}", "Tekufat {}: {}"),
+    ("Birkas HaChama", "Birkat HaChamá"),
+    ("Hakhel", "Hakhel"),
+    ("Erev Pesach on Shabbos", "Véspera de Pessach no Shabat"),
+    // Mourning periods
+    ("{}, through {}", "{}, até {} inclusive"),
+    ("{}, until the morning of {}", "{}, até a manhã de {}"),
+    ("Sefirah", "Sefirá"),
+    ("The Three Weeks", "As Três Semanas"),
+    ("The Nine Days", "Os Nove Dias"),
+    // convert
+    ("{}: From {} to {}.", "{}: de {} até {}."),
+    ("{} -> From sunset {} to sunset {}.", "{} -> do pôr do sol de {} até o pôr do sol de {}."),
+    ("{} at {} in {}: {}.", "{} às {} ({}): {}."),
+    ("{}: {}. This week's parsha: {}.", "{}: {}. Parashá da semana: {}."),
+    // day-info
+    ("{}: {} {} Said in the Amidah: {}.", "{}: {} {} Na Amidá se diz: {}."),
+    ("Tachanun is said at Shacharis and Mincha.", "Diz-se Tachanun em Shacharit e em Minchá."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "Diz-se Tachanun em Shacharit mas não em Minchá."),
+    ("Tachanun is not said.", "Não se diz Tachanun."),
+    ("Full Hallel is said.", "Diz-se o Halel completo."),
+    ("Half Hallel is said.", "Diz-se meio Halel."),
+    ("Hallel is not said.", "Não se diz Halel."),
+    ("Yaaleh VeYavo", "Yaalê VeYavô"),
+    ("Al HaNissim", "Al HaNissim"),
+    ("Mashiv HaRuach", "Mashiv HaRuach"),
+    ("Morid HaTal", "Morid HaTal"),
+    ("V'sen Tal uMatar", "VeTen Tal UMatar"),
+    // year-info
+    ("{}: A leap year, year {} of the Shmita cycle.", "{}: ano bissexto, ano {} do ciclo da Shemitá."),
+    ("{}: Year {} of the Shmita cycle.", "{}: ano {} do ciclo da Shemitá."),
+    (" Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.", " Keviá {0} - Rosh Hashaná cai {2}, {3}, Pessach cai {4}."),
+    ("a deficient year", "ano deficiente"),
+    ("a regular year", "ano regular"),
+    ("a full year", "ano completo"),
+    ("Shmita year", "Ano da Shemitá"),
+    ("Hakhel on Sukkos", "Hakhel em Sucot"),
+    ("Maaser Rishon and Maaser Sheni", "Maasser Rishon e Maasser Sheni"),
+    ("Maaser Rishon and Maaser Ani", "Maasser Rishon e Maasser Ani"),
+    ("Biur Maaser on Erev Pesach ({})", "Biur Maasser na véspera de Pessach ({})"),
+    // diff
+    ("Hebrew calendar: years: {}, months: {}, days: {}.", "Calendário hebraico: anos: {}, meses: {}, dias: {}."),
+    ("Gregorian calendar: years: {}, months: {}, days: {}.", "Calendário gregoriano: anos: {}, meses: {}, dias: {}."),
+    ("Days in all: {}.", "Dias no total: {}."),
+    // wedding-check and wedding-dates
+    ("A wedding may be held.", "Pode-se realizar um casamento."),
+    ("A wedding isn't held because of {}.", "Não se realizam casamentos por causa de: {}."),
+    ("Shabbos", "Shabat"),
+    ("Yom Tov", "Yom Tov"),
+    ("Chol HaMoed", "Chol HaMoed"),
+    ("a fast day", "dia de jejum"),
+    ("the Three Weeks", "as Três Semanas"),
+    ("the Nine Days", "os Nove Dias"),
+    ("the Ten Days of Repentance", "os Dez Dias de Arrependimento"),
+    ("Berakhot", "Berakhot"),
+    // Daf Yomi
+    ("Shabbat", "Shabat"),
+    ("Eruvin", "Eruvin"),
+    ("Pesachim", "Pessachim"),
+    ("Shekalim", "Shekalim"),
+    ("Yoma", "Yoma"),
+    ("Sukkah", "Sucá"),
+    ("Beitzah", "Beitsá"),
+    ("Rosh Hashanah", "Rosh Hashaná"),
+    ("Taanit", "Taanit"),
+    ("Megillah", "Meguilá"),
+    ("Moed Katan", "Moed Katan"),
+    ("Chagigah", "Chaguigá"),
+    ("Yevamot", "Yevamot"),
+    ("Ketubot", "Ketubot"),
+    ("Nedarim", "Nedarim"),
+    ("Nazir", "Nazir"),
+    ("Sotah", "Sotá"),
+    ("Gittin", "Gittin"),
+    ("Kiddushin", "Kiddushin"),
+    ("Bava Kamma", "Bava Kamma"),
+    ("Bava Metzia", "Bava Metsiá"),
+    ("Bava Batra", "Bava Batra"),
+    ("Sanhedrin", "Sanhedrin"),
+    ("Makkot", "Makkot"),
+    ("Shevuot", "Shevuot"),
+    ("Avodah Zarah", "Avodá Zará"),
+    ("Horayot", "Horayot"),
+    ("Zevachim", "Zevachim"),
+    ("Menachot", "Menachot"),
+    ("Chullin", "Chullin"),
+    ("Bekhorot", "Bekhorot"),
+    ("Arakhin", "Arakhin"),
+    ("Temurah", "Temurá"),
+    ("Keritot", "Keritot"),
+    ("Meilah", "Meilá"),
+    ("Niddah", "Nidá"),
+    // Yerushalmi Yomi
+    ("Jerusalem Talmud Berakhot", "Talmud de Jerusalém Berakhot"),
+    ("Jerusalem Talmud Peah", "Talmud de Jerusalém Peá"),
+    ("Jerusalem Talmud Demai", "Talmud de Jerusalém Demai"),
+    ("Jerusalem Talmud Kilayim", "Talmud de Jerusalém Kilayim"),
+    ("Jerusalem Talmud Shevi'it", "Talmud de Jerusalém Shevi'it"),
+    ("Jerusalem Talmud Terumot", "Talmud de Jerusalém Terumot"),
+    ("Jerusalem Talmud Ma'asrot", "Talmud de Jerusalém Ma'asrot"),
+    ("Jerusalem Talmud Ma'aser Sheni", "Talmud de Jerusalém Ma'aser Sheni"),
+    ("Jerusalem Talmud Hallah", "Talmud de Jerusalém Chalá"),
+    ("Jerusalem Talmud Orlah", "Talmud de Jerusalém Orlá"),
+    ("Jerusalem Talmud Bikkurim", "Talmud de Jerusalém Bikkurim"),
+    ("Jerusalem Talmud Shabbat", "Talmud de Jerusalém Shabat"),
+    ("Jerusalem Talmud Eruvin", "Talmud de Jerusalém Eruvin"),
+    ("Jerusalem Talmud Pesachim", "Talmud de Jerusalém Pessachim"),
+    ("Jerusalem Talmud Beitzah", "Talmud de Jerusalém Beitsá"),
+    ("Jerusalem Talmud Rosh Hashanah", "Talmud de Jerusalém Rosh Hashaná"),
+    ("Jerusalem Talmud Yoma", "Talmud de Jerusalém Yoma"),
+    ("Jerusalem Talmud Sukkah", "Talmud de Jerusalém Sucá"),
+    ("Jerusalem Talmud Ta'anit", "Talmud de Jerusalém Taanit"),
+    ("Jerusalem Talmud Shekalim", "Talmud de Jerusalém Shekalim"),
+    ("Jerusalem Talmud Megillah", "Talmud de Jerusalém Meguilá"),
+    ("Jerusalem Talmud Chagigah", "Talmud de Jerusalém Chaguigá"),
+    ("Jerusalem Talmud Moed Kattan", "Talmud de Jerusalém Moed Katan"),
+    ("Jerusalem Talmud Yevamot", "Talmud de Jerusalém Yevamot"),
+    ("Jerusalem Talmud Ketubot", "Talmud de Jerusalém Ketubot"),
+    ("Jerusalem Talmud Sotah", "Talmud de Jerusalém Sotá"),
+    ("Jerusalem Talmud Nedarim", "Talmud de Jerusalém Nedarim"),
+    ("Jerusalem Talmud Nazir", "Talmud de Jerusalém Nazir"),
+    ("Jerusalem Talmud Gittin", "Talmud de Jerusalém Gittin"),
+    ("Jerusalem Talmud Kiddushin", "Talmud de Jerusalém Kiddushin"),
+    ("Jerusalem Talmud Bava Kamma", "Talmud de Jerusalém Bava Kamma"),
+    ("Jerusalem Talmud Bava Metsia", "Talmud de Jerusalém Bava Metsiá"),
+    ("Jerusalem Talmud Bava Batra", "Talmud de Jerusalém Bava Batra"),
+    ("Jerusalem Talmud Shevuot", "Talmud de Jerusalém Shevuot"),
+    ("Jerusalem Talmud Makkot", "Talmud de Jerusalém Makkot"),
+    ("Jerusalem Talmud Sanhedrin", "Talmud de Jerusalém Sanhedrin"),
+    ("Jerusalem Talmud Avodah Zarah", "Talmud de Jerusalém Avodá Zará"),
+    ("Jerusalem Talmud Horayot", "Talmud de Jerusalém Horayot"),
+    ("Jerusalem Talmud Niddah", "Talmud de Jerusalém Nidá"),
+    // Rambam
+    ("Rambam|Transmission of the Oral Law", "Transmissão da Lei Oral"),
+    ("Rambam|Positive Mitzvot", "Preceitos positivos"),
+    ("Rambam|Negative Mitzvot", "Preceitos negativos"),
+    ("Rambam|Overview of Mishneh Torah Contents", "Índice da Mishnê Torá"),
+    ("Rambam|Foundations of the Torah", "Fundamentos da Torá"),
+    ("Rambam|Human Dispositions", "Traços de caráter"),
+    ("Rambam|Torah Study", "Estudo da Torá"),
+    ("Rambam|Foreign Worship and Customs of the Nations", "Idolatria e costumes das nações"),
+    ("Rambam|Repentance", "Arrependimento"),
+    ("Rambam|Reading the Shema", "Leitura do Shemá"),
+    ("Rambam|Prayer and the Priestly Blessing", "Oração e bênção sacerdotal"),
+    ("Rambam|Tefillin, Mezuzah and the Torah Scroll", "Tefilin, mezuzá e rolo da Torá"),
+    ("Rambam|Fringes", "Tsitsit"),
+    ("Rambam|Blessings", "Bênçãos"),
+    ("Rambam|Circumcision", "Circuncisão"),
+    ("Rambam|The Order of Prayer", "Ordem das orações"),
+    ("Rambam|Sabbath", "Shabat"),
+    ("Rambam|Eruvin", "Eruvin"),
+    ("Rambam|Rest on the Tenth of Tishrei", "Descanso do dez de Tishrê"),
+    ("Rambam|Rest on a Holiday", "Descanso nos dias festivos"),
+    ("Rambam|Leavened and Unleavened Bread", "Chamets e matsá"),
+    ("Rambam|Shofar, Sukkah and Lulav", "Shofar, sucá e lulav"),
+    ("Rambam|Sheqel Dues", "Meio siclo"),
+    ("Rambam|Sanctification of the New Month", "Santificação da lua nova"),
+    ("Rambam|Fasts", "Jejuns"),
+    ("Rambam|Scroll of Esther and Hanukkah", "Meguilá e Chanucá"),
+    ("Rambam|Marriage", "Casamento"),
+    ("Rambam|Divorce", "Divórcio"),
+    ("Rambam|Levirate Marriage and Release", "Levirato e chalitsá"),
+    ("Rambam|Virgin Maiden", "Donzela virgem"),
+    ("Rambam|Woman Suspected of Infidelity", "Mulher suspeita de infidelidade"),
+    ("Rambam|Forbidden Intercourse", "Relações proibidas"),
+    ("Rambam|Forbidden Foods", "Alimentos proibidos"),
+    ("Rambam|Ritual Slaughter", "Abate ritual"),
+    ("Rambam|Oaths", "Juramentos"),
+    ("Rambam|Vows", "Votos"),
+    ("Rambam|Nazariteship", "Nazireado"),
+    ("Rambam|Appraisals and Devoted Property", "Avaliações e consagrações"),
+    ("Rambam|Diverse Species", "Misturas proibidas"),
+    ("Rambam|Gifts to the Poor", "Dádivas aos pobres"),
+    ("Rambam|Heave Offerings", "Ofertas elevadas"),
+    ("Rambam|Tithes", "Dízimos"),
+    ("Rambam|Second Tithes and Fourth Year's Fruit", "Segundo dízimo e frutos do quarto ano"),
+    ("Rambam|First Fruits and other Gifts to Priests Outside the Sanctuary", "Primícias e outras dádivas aos sacerdotes"),
+    ("Rambam|Sabbatical Year and the Jubilee", "Ano sabático e jubileu"),
+    ("Rambam|The Chosen Temple", "O Templo escolhido"),
+    ("Rambam|Vessels of the Sanctuary and Those who Serve Therein", "Utensílios do Templo e seus servidores"),
+    ("Rambam|Admission into the Sanctuary", "Entrada no Templo"),
+    ("Rambam|Things Forbidden on the Altar", "O que é proibido no altar"),
+    ("Rambam|Sacrificial Procedure", "Procedimento dos sacrifícios"),
+    ("Rambam|Daily Offerings and Additional Offerings", "Ofertas diárias e adicionais"),
+    ("Rambam|Sacrifices Rendered Unfit", "Sacrifícios invalidados"),
+    ("Rambam|Service on the Day of Atonement", "Serviço de Yom Kipur"),
+    ("Rambam|Trespass", "Apropriação do sagrado"),
+    ("Rambam|Paschal Offering", "Oferta pascal"),
+    ("Rambam|Festival Offering", "Oferta festiva"),
+    ("Rambam|Firstlings", "Primogênitos"),
+    ("Rambam|Offerings for Unintentional Transgressions", "Ofertas por transgressões involuntárias"),
+    ("Rambam|Offerings for Those with Incomplete Atonement", "Ofertas por expiação incompleta"),
+    ("Rambam|Substitution", "Substituição"),
+    ("Rambam|Defilement by a Corpse", "Impureza do cadáver"),
+    ("Rambam|Red Heifer", "Vaca vermelha"),
+    ("Rambam|Defilement by Leprosy", "Impureza da lepra"),
+    ("Rambam|Those Who Defile Bed or Seat", "Impureza do leito e do assento"),
+    ("Rambam|Other Sources of Defilement", "Outras fontes de impureza"),
+    ("Rambam|Defilement of Foods", "Impureza dos alimentos"),
+    ("Rambam|Vessels", "Utensílios"),
+    ("Rambam|Immersion Pools", "Banhos rituais"),
+    ("Rambam|Damages to Property", "Danos à propriedade"),
+    ("Rambam|Theft", "Furto"),
+    ("Rambam|Robbery and Lost Property", "Roubo e objetos perdidos"),
+    ("Rambam|One Who Injures a Person or Property", "Lesões e danos"),
+    ("Rambam|Murderer and the Preservation of Life", "Homicida e preservação da vida"),
+    ("Rambam|Sales", "Compra e venda"),
+    ("Rambam|Ownerless Property and Gifts", "Bens sem dono e doações"),
+    ("Rambam|Neighbors", "Vizinhos"),
+    ("Rambam|Agents and Partners", "Agentes e sócios"),
+    ("Rambam|Slaves", "Escravos"),
+    ("Rambam|Hiring", "Locação"),
+    ("Rambam|Borrowing and Deposit", "Empréstimo e depósito"),
+    ("Rambam|Creditor and Debtor", "Credor e devedor"),
+    ("Rambam|Plaintiff and Defendant", "Autor e réu"),
+    ("Rambam|Inheritances", "Heranças"),
+    ("Rambam|The Sanhedrin and the Penalties within their Jurisdiction", "O Sinédrio e as penas de sua jurisdição"),
+    ("Rambam|Testimony", "Testemunho"),
+    ("Rambam|Rebels", "Rebeldes"),
+    ("Rambam|Mourning", "Luto"),
+    ("Rambam|Kings and Wars", "Reis e guerras"),
+];
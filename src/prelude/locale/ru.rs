@@ -0,0 +1,542 @@
+//! The Russian catalog.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("Night of ", "Вечер "),
+    ("Candle lighting", "Зажигание свечей"),
+    ("Haftarah", "Афтара"),
+    ("Maftir", "Мафтир"),
+    ("In Israel", "В Израиле"),
+    ("In Chutz La'aretz", "За пределами Израиля"),
+    ("Triennial year", "Год трёхлетнего цикла"),
+    // Gregorian dates
+    // Months are only printed in dates, so they are in the genitive.
+    ("%A %B %-d %Y", "%A %-d %B %Y"),
+    ("%A %B %-d %H:%M", "%A %-d %B %H:%M"),
+    ("Sunday", "воскресенье"),
+    ("Monday", "понедельник"),
+    ("Tuesday", "вторник"),
+    ("Wednesday", "среда"),
+    ("Thursday", "четверг"),
+    ("Friday", "пятница"),
+    ("Saturday", "суббота"),
+    ("January", "января"),
+    ("February", "февраля"),
+    ("March", "марта"),
+    ("April", "апреля"),
+    ("May", "мая"),
+    ("June", "июня"),
+    ("July", "июля"),
+    ("August", "августа"),
+    ("September", "сентября"),
+    ("October", "октября"),
+    ("November", "ноября"),
+    ("December", "декабря"),
+    // Days of the week in the Hebrew calendar
+    ("weekday|Sunday", "воскресенье"),
+    ("weekday|Monday", "понедельник"),
+    ("weekday|Tuesday", "вторник"),
+    ("weekday|Wednesday", "среда"),
+    ("weekday|Thursday", "четверг"),
+    ("weekday|Friday", "пятница"),
+    ("weekday|Shabbos", "Шабат"),
+    // Hebrew months
+    ("Tishrei", "Тишрей"),
+    ("Cheshvan", "Хешван"),
+    ("Kislev", "Кислев"),
+    ("Teves", "Тевет"),
+    ("Shvat", "Шват"),
+    ("Adar", "Адар"),
+    ("Adar Rishon", "Адар Ришон"),
+    ("Adar Sheni", "Адар Шени"),
+    ("Nissan", "Нисан"),
+    ("Iyar", "Ияр"),
+    ("Sivan", "Сиван"),
+    ("Tammuz", "Тамуз"),
+    ("Av", "Ав"),
+    ("Elul", "Элул"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "1-й день Рош а-Шана"),
+    ("2nd day of Rosh Hashanah", "2-й день Рош а-Шана"),
+    ("Yom Kippur", "Йом Кипур"),
+    ("1st day of Sukkos", "1-й день Суккот"),
+    ("2nd day of Sukkos", "2-й день Суккот"),
+    ("3rd day of Sukkos", "3-й день Суккот"),
+    ("4th day of Sukkos", "4-й день Суккот"),
+    ("5th day of Sukkos", "5-й день Суккот"),
+    ("6th day of Sukkos", "6-й день Суккот"),
+    ("7th day of Sukkos", "7-й день Суккот"),
+    ("Shmini Atzeres", "Шмини Ацерет"),
+    ("Simchas Torah", "Симхат Тора"),
+    ("1st day of Pesach", "1-й день Песаха"),
+    ("2nd day of Pesach", "2-й день Песаха"),
+    ("3rd day of Pesach", "3-й день Песаха"),
+    ("4th day of Pesach", "4-й день Песаха"),
+    ("5th day of Pesach", "5-й день Песаха"),
+    ("6th day of Pesach", "6-й день Песаха"),
+    ("7th day of Pesach", "7-й день Песаха"),
+    ("8th day of Pesach", "8-й день Песаха"),
+    ("1st day of Shavuos", "1-й день Шавуот"),
+    ("2nd day of Shavuos", "2-й день Шавуот"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "1-й день Рош Ходеш Хешван"),
+    ("2nd day of Rosh Chodesh Cheshvan", "2-й день Рош Ходеш Хешван"),
+    ("Rosh Chodesh Kislev", "Рош Ходеш Кислев"),
+    ("1st day of Rosh Chodesh Kislev", "1-й день Рош Ходеш Кислев"),
+    ("2nd day of Rosh Chodesh Kislev", "2-й день Рош Ходеш Кислев"),
+    ("Rosh Chodesh Teves", "Рош Ходеш Тевет"),
+    ("1st day of Rosh Chodesh Teves", "1-й день Рош Ходеш Тевет"),
+    ("2nd day of Rosh Chodesh Teves", "2-й день Рош Ходеш Тевет"),
+    ("Rosh Chodesh Shvat", "Рош Ходеш Шват"),
+    ("1st day of Rosh Chodesh Adar", "1-й день Рош Ходеш Адар"),
+    ("2nd day of Rosh Chodesh Adar", "2-й день Рош Ходеш Адар"),
+    ("1st day of Rosh Chodesh Adar Rishon", "1-й день Рош Ходеш Адар Ришон"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "2-й день Рош Ходеш Адар Ришон"),
+    ("1st day of Rosh Chodesh Adar Sheni", "1-й день Рош Ходеш Адар Шени"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "2-й день Рош Ходеш Адар Шени"),
+    ("Rosh Chodesh Nissan", "Рош Ходеш Нисан"),
+    ("1st day of Rosh Chodesh Iyar", "1-й день Рош Ходеш Ияр"),
+    ("2nd day of Rosh Chodesh Iyar", "2-й день Рош Ходеш Ияр"),
+    ("Rosh Chodesh Sivan", "Рош Ходеш Сиван"),
+    ("1st day of Rosh Chodesh Tammuz", "1-й день Рош Ходеш Тамуз"),
+    ("2nd day of Rosh Chodesh Tammuz", "2-й день Рош Ходеш Тамуз"),
+    ("Rosh Chodesh Av", "Рош Ходеш Ав"),
+    ("1st day of Rosh Chodesh Elul", "1-й день Рош Ходеш Элул"),
+    ("2nd day of Rosh Chodesh Elul", "2-й день Рош Ходеш Элул"),
+    ("1st day of Chanukah", "1-й день Хануки"),
+    ("2nd day of Chanukah", "2-й день Хануки"),
+    ("3rd day of Chanukah", "3-й день Хануки"),
+    ("4rd day of Chanukah", "4-й день Хануки"),
+    ("5rd day of Chanukah", "5-й день Хануки"),
+    ("6rd day of Chanukah", "6-й день Хануки"),
+    ("7rd day of Chanukah", "7-й день Хануки"),
+    ("8rd day of Chanukah", "8-й день Хануки"),
+    ("Tzom Gedalia", "Пост Гедальи"),
+    ("Taanis Esther", "Пост Эстер"),
+    ("Tenth of Teves", "10 Тевета"),
+    ("Purim", "Пурим"),
+    ("Shushan Purim", "Шушан Пурим"),
+    ("Seventeenth of Tammuz", "17 Тамуза"),
+    ("Ninth of Av", "9 Ава"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Haazinu", "Аазину"),
+    ("Vayelech", "Вайелех"),
+    ("Bereishis", "Берешит"),
+    ("Noach", "Ноах"),
+    ("Lech Lecha", "Лех-Леха"),
+    ("Vayeira", "Вайера"),
+    ("Chayei Sarah", "Хайей Сара"),
+    ("Toldos", "Толдот"),
+    ("Vayetzei", "Вайеце"),
+    ("Vayishlach", "Вайишлах"),
+    ("Vayeshev", "Вайешев"),
+    ("Miketz", "Микец"),
+    ("Vayigash", "Вайигаш"),
+    ("Vayechi", "Вайехи"),
+    ("Shemos", "Шмот"),
+    ("Vaeira", "Ваэра"),
+    ("Bo", "Бо"),
+    ("Beshalach", "Бешалах"),
+    ("Yisro", "Итро"),
+    ("Mishpatim", "Мишпатим"),
+    ("Terumah", "Трума"),
+    ("Tetzaveh", "Тецаве"),
+    ("Ki Sisa", "Ки Тиса"),
+    ("Vayakhel/Pikudei", "Ваякхель/Пкудей"),
+    ("Vayakhel", "Ваякхель"),
+    ("Pikudei", "Пкудей"),
+    ("Vayikra", "Ваикра"),
+    ("Tzav", "Цав"),
+    ("Shemini", "Шмини"),
+    ("Tazriya/Metzorah", "Тазриа/Мецора"),
+    ("Tazriya", "Тазриа"),
+    ("Metzorah", "Мецора"),
+    ("Acharei Mos/Kedoshim", "Ахарей Мот/Кдошим"),
+    ("Acharei Mos", "Ахарей Мот"),
+    ("Kedoshim", "Кдошим"),
+    ("Emor", "Эмор"),
+    ("Behar/Bechukosai", "Бегар/Бехукотай"),
+    ("Behar", "Бегар"),
+    ("Bechukosai", "Бехукотай"),
+    ("Bamidbar", "Бемидбар"),
+    ("Naso", "Насо"),
+    ("Behaaloscha", "Беаалотха"),
+    ("Shlach", "Шлах"),
+    ("Korach", "Корах"),
+    ("Chukas/Balak", "Хукат/Балак"),
+    ("Chukas", "Хукат"),
+    ("Balak", "Балак"),
+    ("Pinchas", "Пинхас"),
+    ("Matos/Maasei", "Матот/Масей"),
+    ("Matos", "Матот"),
+    ("Maasei", "Масей"),
+    ("Devarim", "Дварим"),
+    ("Vaeschanan", "Ваэтханан"),
+    ("Eikev", "Экев"),
+    ("Re'eh", "Рээ"),
+    ("Shoftim", "Шофтим"),
+    ("Ki Seitzei", "Ки Теце"),
+    ("Ki Savo", "Ки Таво"),
+    ("Nitzavim/Vayelech", "Ницавим/Вайелех"),
+    ("Nitzavim", "Ницавим"),
+    // Special parshiyos
+    ("Parshas Zachor", "Паршат Захор"),
+    ("Parshas HaChodesh", "Паршат а-Ходеш"),
+    ("Parshas Parah", "Паршат Пара"),
+    ("Parshas Shekalim", "Паршат Шкалим"),
+    // Books of Navi (Shoftim is with the parshiyos)
+    ("Yehoshua", "Йеошуа"),
+    ("Shmuel I", "Шмуэль I"),
+    ("Shmuel II", "Шмуэль II"),
+    ("Melachim I", "Млахим I"),
+    ("Melachim II", "Млахим II"),
+    ("Yeshayahu", "Йешаяу"),
+    ("Yirmiyahu", "Ирмеяу"),
+    ("Yechezkel", "Йехезкель"),
+    ("Hoshea", "Ошеа"),
+    ("Yoel", "Йоэль"),
+    ("Amos", "Амос"),
+    ("Ovadia", "Овадья"),
+    ("Micha", "Миха"),
+    ("Chavakuk", "Хавакук"),
+    ("Zecharia", "Зхарья"),
+    ("Malachi", "Малахи"),
+    // Minor holidays
+    ("1st day of the Omer", "1-й день Омера"),
+    ("2nd day of the Omer", "2-й день Омера"),
+    ("3rd day of the Omer", "3-й день Омера"),
+    ("4th day of the Omer", "4-й день Омера"),
+    ("5th day of the Omer", "5-й день Омера"),
+    ("6th day of the Omer", "6-й день Омера"),
+    ("7th day of the Omer", "7-й день Омера"),
+    ("8th day of the Omer", "8-й день Омера"),
+    ("9th day of the Omer", "9-й день Омера"),
+    ("10th day of the Omer", "10-й день Омера"),
+    ("11th day of the Omer", "11-й день Омера"),
+    ("12th day of the Omer", "12-й день Омера"),
+    ("13th day of the Omer", "13-й день Омера"),
+    ("14th day of the Omer", "14-й день Омера"),
+    ("15th day of the Omer", "15-й день Омера"),
+    ("16th day of the Omer", "16-й день Омера"),
+    ("17th day of the Omer", "17-й день Омера"),
+    ("18th day of the Omer", "18-й день Омера"),
+    ("19th day of the Omer", "19-й день Омера"),
+    ("20th day of the Omer", "20-й день Омера"),
+    ("21st day of the Omer", "21-й день Омера"),
+    ("22nd day of the Omer", "22-й день Омера"),
+    ("23rd day of the Omer", "23-й день Омера"),
+    ("24th day of the Omer", "24-й день Омера"),
+    ("25th day of the Omer", "25-й день Омера"),
+    ("26th day of the Omer", "26-й день Омера"),
+    ("27th day of the Omer", "27-й день Омера"),
+    ("28th day of the Omer", "28-й день Омера"),
+    ("29th day of the Omer", "29-й день Омера"),
+    ("30th day of the Omer", "30-й день Омера"),
+    ("31st day of the Omer", "31-й день Омера"),
+    ("32nd day of the Omer", "32-й день Омера"),
+    ("33rd day of the Omer", "33-й день Омера"),
+    ("34th day of the Omer", "34-й день Омера"),
+    ("35th day of the Omer", "35-й день Омера"),
+    ("36th day of the Omer", "36-й день Омера"),
+    ("37th day of the Omer", "37-й день Омера"),
+    ("38th day of the Omer", "38-й день Омера"),
+    ("39th day of the Omer", "39-й день Омера"),
+    ("40th day of the Omer", "40-й день Омера"),
+    ("41st day of the Omer", "41-й день Омера"),
+    ("42nd day of the Omer", "42-й день Омера"),
+    ("43rd day of the Omer", "43-й день Омера"),
+    ("44th day of the Omer", "44-й день Омера"),
+    ("45th day of the Omer", "45-й день Омера"),
+    ("46th day of the Omer", "46-й день Омера"),
+    ("47th day of the Omer", "47-й день Омера"),
+    ("48th day of the Omer", "48-й день Омера"),
+    ("49th day of the Omer", "49-й день Омера"),
+    ("Erev Pesach", "Канун Песаха"),
+    ("Erev Sukkos", "Канун Суккот"),
+    ("Erev Shavuos", "Канун Шавуот"),
+    ("Erev Yom Kippur", "Канун Йом Кипура"),
+    ("Erev Rosh Hashana", "Канун Рош а-Шана"),
+    ("Pesach Sheni", "Песах Шени"),
+    ("Lag BaOmer", "Лаг ба-Омер"),
+    ("15th of Av", "Ту бе-Ав"),
+    ("15th of Shevat", "Ту би-Шват"),
+    ("Purim Kattan", "Пурим Катан"),
+    ("Shushan Purim Kattan", "Шушан Пурим Катан"),
+    ("Purim Meshulash: Megillah reading and gifts to the poor", "Пурим Мешулаш: чтение Мегилы и подарки бедным"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "Пурим Мешулаш: Аль а-Нисим и чтение Торы Пурима"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "Пурим Мешулаш: пуримская трапеза и мишлоах манот"),
+    ("Shabbos HaGadol", "Шабат а-Гадоль"),
+    ("Taanis Bechoros", "Пост первенцев"),
+    ("Shabbos Chazon", "Шабат Хазон"),
+    ("Shabbos Nachamu", "Шабат Нахаму"),
+    ("Leil Slichos", "Ночь Слихот"),
+    ("Shabbos Shuva", "Шабат Шува"),
+    ("Shabbos Rosh Chodesh", "Шабат Рош Ходеш"),
+    ("Shabbos Machar Chodesh", "Шабат Махар Ходеш"),
+    ("Shabbos Shira", "Шабат Шира"),
+    ("Shabbos Chanukah", "Шабат Хануки"),
+    ("Second Shabbos Chanukah", "Второй Шабат Хануки"),
+    ("Shabbos Chol HaMoed Sukkos", "Шабат Холь а-Моэд Суккот"),
+    ("Shabbos Chol HaMoed Pesach", "Шабат Холь а-Моэд Песах"),
+    ("Shabbos Shekalim", "Шабат Шкалим"),
+    ("Shabbos Zachor", "Шабат Захор"),
+    ("Shabbos Parah", "Шабат Пара"),
+    ("Shabbos HaChodesh", "Шабат а-Ходеш"),
+    ("Shabbos Mevarchim", "Шабат Мевархим"),
+    // Modern Israeli holidays
+    ("Yom HaAtzmaut", "Йом а-Ацмаут"),
+    ("Yom HaZikaron", "Йом а-Зикарон"),
+    ("Yom Yerushalayim", "Йом Йерушалаим"),
+    ("Yom HaShoah", "Йом а-Шоа"),
+    ("Yom HaAliyah", "Йом а-Алия"),
+    ("Yom HaAliyah (10 Nissan)", "Йом а-Алия (10 Нисана)"),
+    ("Sigd", "Сигд"),
+    ("Yitzhak Rabin Memorial Day", "День памяти Ицхака Рабина"),
+    ("Ben-Gurion Day", "День Бен-Гуриона"),
+    ("Hebrew Language Day", "День иврита"),
+    ("Family Day", "День семьи"),
+    ("Herzl Day", "День Герцля"),
+    ("Jabotinsky Day", "День Жаботинского"),
+    // Chabad holidays
+    ("Vav Tishrei", "Вав Тишрей"),
+    ("Yud Kislev", "Юд Кислев"),
+    ("Yud Tes Kislev", "Юд-Тет Кислев"),
+    ("Chof Kislev", "Хаф Кислев"),
+    ("Hei Teves", "Хей Тевет"),
+    ("Chof Daled Teves", "Хаф-Далет Тевет"),
+    ("Yud Shvat", "Юд Шват"),
+    ("Chof Beis Shvat", "Хаф-Бет Шват"),
+    ("Yud Aleph Nissan", "Юд-Алеф Нисан"),
+    ("Gimmel Tammuz", "Гимел Тамуз"),
+    ("Yud Beis Tammuz", "Юд-Бет Тамуз"),
+    ("Yud Gimmel Tammuz", "Юд-Гимел Тамуз"),
+    ("Chof Av", "Хаф Ав"),
+    ("Chai Elul", "Хай Элул"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "Шабат Мевархим {0}: молад в {1} {3} {2}, {4} и {5} халаким ({6} по иерусалимскому поясному времени). {7}"),
+    ("Rosh Chodesh {} will be on {}", "Рош Ходеш {}: {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "Рош Ходеш {}: {} и {}"),
+    ("Kiddush Levana {}: From {} until {}", "Кидуш Левана {}: с {} до {}"),
+    // Rare events
+    ("Tekufas {}: {}", "Ткуфат {}: {}"),
+    ("Birkas HaChama", "Биркат а-Хама"),
+    ("Hakhel", "Акхель"),
+    ("Erev Pesach on Shabbos", "Канун Песаха в Шабат"),
+    // Mourning periods
+    ("{}, through {}", "{}, по {} включительно"),
+    ("{}, until the morning of {}", "{}, до утра {}"),
+    ("Sefirah", "Сфира"),
+    ("The Three Weeks", "Три недели"),
+    ("The Nine Days", "Девять дней"),
+    // convert
+    ("{}: From {} to {}.", "{}: с {} до {}."),
+    ("{} -> From sunset {} to sunset {}.", "{} -> с заката {} до заката {}."),
+    ("{} at {} in {}: {}.", "{} в {} ({}): {}."),
+    ("{}: {}. This week's parsha: {}.", "{}: {}. Недельная глава: {}."),
+    // day-info
+    ("{}: {} {} Said in the Amidah: {}.", "{}: {} {} В Амиде говорят: {}."),
+    ("Tachanun is said at Shacharis and Mincha.", "Таханун говорят в Шахарит и в Минху."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "Таханун говорят в Шахарит, но не в Минху."),
+    ("Tachanun is not said.", "Таханун не говорят."),
+    ("Full Hallel is said.", "Говорят полный Алель."),
+    ("Half Hallel is said.", "Говорят неполный Алель."),
+    ("Hallel is not said.", "Алель не говорят."),
+    ("Yaaleh VeYavo", "Яале ве-Яво"),
+    ("Al HaNissim", "Аль а-Нисим"),
+    ("Mashiv HaRuach", "Машив а-Руах"),
+    ("Morid HaTal", "Морид а-Таль"),
+    ("V'sen Tal uMatar", "Ве-тен таль у-матар"),
+    // year-info
+    ("{}: A leap year, year {} of the Shmita cycle.", "{}: високосный год, {}-й год цикла шмиты."),
+    ("{}: Year {} of the Shmita cycle.", "{}: {}-й год цикла шмиты."),
+    (" Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.", " Квиа {0} - Рош а-Шана: {2}, {3}, Песах: {4}."),
+    ("a deficient year", "недостаточный год"),
+    ("a regular year", "правильный год"),
+    ("a full year", "полный год"),
+    ("Shmita year", "Год шмиты"),
+    ("Hakhel on Sukkos", "Акхель в Суккот"),
+    ("Maaser Rishon and Maaser Sheni", "Маасер ришон и маасер шени"),
+    ("Maaser Rishon and Maaser Ani", "Маасер ришон и маасер ани"),
+    ("Biur Maaser on Erev Pesach ({})", "Биур маасер в канун Песаха ({})"),
+    // diff
+    ("Hebrew calendar: years: {}, months: {}, days: {}.", "Еврейский календарь: лет: {}, месяцев: {}, дней: {}."),
+    ("Gregorian calendar: years: {}, months: {}, days: {}.", "Григорианский календарь: лет: {}, месяцев: {}, дней: {}."),
+    ("Days in all: {}.", "Всего дней: {}."),
+    // wedding-check and wedding-dates
+    ("A wedding may be held.", "Свадьбу можно проводить."),
+    ("A wedding isn't held because of {}.", "Свадьбу не проводят: {}."),
+    ("Shabbos", "Шабат"),
+    ("Yom Tov", "Йом Тов"),
+    ("Chol HaMoed", "Холь а-Моэд"),
+    ("a fast day", "пост"),
+    ("the Three Weeks", "Три недели"),
+    ("the Nine Days", "Девять дней"),
+    ("the Ten Days of Repentance", "Десять дней раскаяния"),
+    // Daf Yomi
+    ("Berakhot", "Брахот"),
+    ("Shabbat", "Шабат"),
+    ("Eruvin", "Эрувин"),
+    ("Pesachim", "Песахим"),
+    ("Shekalim", "Шкалим"),
+    ("Yoma", "Йома"),
+    ("Sukkah", "Сука"),
+    ("Beitzah", "Бейца"),
+    ("Rosh Hashanah", "Рош а-Шана"),
+    ("Taanit", "Таанит"),
+    ("Megillah", "Мегила"),
+    ("Moed Katan", "Моэд Катан"),
+    ("Chagigah", "Хагига"),
+    ("Yevamot", "Йевамот"),
+    ("Ketubot", "Ктубот"),
+    ("Nedarim", "Недарим"),
+    ("Nazir", "Назир"),
+    ("Sotah", "Сота"),
+    ("Gittin", "Гитин"),
+    ("Kiddushin", "Кидушин"),
+    ("Bava Kamma", "Бава Кама"),
+    ("Bava Metzia", "Бава Мециа"),
+    ("Bava Batra", "Бава Батра"),
+    ("Sanhedrin", "Санедрин"),
+    ("Makkot", "Макот"),
+    ("Shevuot", "Швуот"),
+    ("Avodah Zarah", "Авода Зара"),
+    ("Horayot", "Орайот"),
+    ("Zevachim", "Звахим"),
+    ("Menachot", "Менахот"),
+    ("Chullin", "Хулин"),
+    ("Bekhorot", "Бехорот"),
+    ("Arakhin", "Арахин"),
+    ("Temurah", "Тмура"),
+    ("Keritot", "Критот"),
+    ("Meilah", "Меила"),
+    ("Niddah", "Нида"),
+    // Yerushalmi Yomi
+    ("Jerusalem Talmud Berakhot", "Иерусалимский Талмуд, Брахот"),
+    ("Jerusalem Talmud Peah", "Иерусалимский Талмуд, Пеа"),
+    ("Jerusalem Talmud Demai", "Иерусалимский Талмуд, Демай"),
+    ("Jerusalem Talmud Kilayim", "Иерусалимский Талмуд, Килаим"),
+    ("Jerusalem Talmud Shevi'it", "Иерусалимский Талмуд, Швиит"),
+    ("Jerusalem Talmud Terumot", "Иерусалимский Талмуд, Трумот"),
+    ("Jerusalem Talmud Ma'asrot", "Иерусалимский Талмуд, Маасрот"),
+    ("Jerusalem Talmud Ma'aser Sheni", "Иерусалимский Талмуд, Маасер Шени"),
+    ("Jerusalem Talmud Hallah", "Иерусалимский Талмуд, Хала"),
+    ("Jerusalem Talmud Orlah", "Иерусалимский Талмуд, Орла"),
+    ("Jerusalem Talmud Bikkurim", "Иерусалимский Талмуд, Бикурим"),
+    ("Jerusalem Talmud Shabbat", "Иерусалимский Талмуд, Шабат"),
+    ("Jerusalem Talmud Eruvin", "Иерусалимский Талмуд, Эрувин"),
+    ("Jerusalem Talmud Pesachim", "Иерусалимский Талмуд, Песахим"),
+    ("Jerusalem Talmud Beitzah", "Иерусалимский Талмуд, Бейца"),
+    ("Jerusalem Talmud Rosh Hashanah", "Иерусалимский Талмуд, Рош а-Шана"),
+    ("Jerusalem Talmud Yoma", "Иерусалимский Талмуд, Йома"),
+    ("Jerusalem Talmud Sukkah", "Иерусалимский Талмуд, Сука"),
+    ("Jerusalem Talmud Ta'anit", "Иерусалимский Талмуд, Таанит"),
+    ("Jerusalem Talmud Shekalim", "Иерусалимский Талмуд, Шкалим"),
+    ("Jerusalem Talmud Megillah", "Иерусалимский Талмуд, Мегила"),
+    ("Jerusalem Talmud Chagigah", "Иерусалимский Талмуд, Хагига"),
+    ("Jerusalem Talmud Moed Kattan", "Иерусалимский Талмуд, Моэд Катан"),
+    ("Jerusalem Talmud Yevamot", "Иерусалимский Талмуд, Йевамот"),
+    ("Jerusalem Talmud Ketubot", "Иерусалимский Талмуд, Ктубот"),
+    ("Jerusalem Talmud Sotah", "Иерусалимский Талмуд, Сота"),
+    ("Jerusalem Talmud Nedarim", "Иерусалимский Талмуд, Недарим"),
+    ("Jerusalem Talmud Nazir", "Иерусалимский Талмуд, Назир"),
+    ("Jerusalem Talmud Gittin", "Иерусалимский Талмуд, Гитин"),
+    ("Jerusalem Talmud Kiddushin", "Иерусалимский Талмуд, Кидушин"),
+    ("Jerusalem Talmud Bava Kamma", "Иерусалимский Талмуд, Бава Кама"),
+    ("Jerusalem Talmud Bava Metsia", "Иерусалимский Талмуд, Бава Мециа"),
+    ("Jerusalem Talmud Bava Batra", "Иерусалимский Талмуд, Бава Батра"),
+    ("Jerusalem Talmud Shevuot", "Иерусалимский Талмуд, Швуот"),
+    ("Jerusalem Talmud Makkot", "Иерусалимский Талмуд, Макот"),
+    ("Jerusalem Talmud Sanhedrin", "Иерусалимский Талмуд, Санедрин"),
+    ("Jerusalem Talmud Avodah Zarah", "Иерусалимский Талмуд, Авода Зара"),
+    ("Jerusalem Talmud Horayot", "Иерусалимский Талмуд, Орайот"),
+    ("Jerusalem Talmud Niddah", "Иерусалимский Талмуд, Нида"),
+    // Rambam
+    ("Rambam|Transmission of the Oral Law", "Передача Устного Закона"),
+    ("Rambam|Positive Mitzvot", "Повелевающие заповеди"),
+    ("Rambam|Negative Mitzvot", "Запрещающие заповеди"),
+    ("Rambam|Overview of Mishneh Torah Contents", "Оглавление Мишне Тора"),
+    ("Rambam|Foundations of the Torah", "Основы Торы"),
+    ("Rambam|Human Dispositions", "Свойства характера"),
+    ("Rambam|Torah Study", "Изучение Торы"),
+    ("Rambam|Foreign Worship and Customs of the Nations", "Идолопоклонство и обычаи народов"),
+    ("Rambam|Repentance", "Раскаяние"),
+    ("Rambam|Reading the Shema", "Чтение Шма"),
+    ("Rambam|Prayer and the Priestly Blessing", "Молитва и благословение коэнов"),
+    ("Rambam|Tefillin, Mezuzah and the Torah Scroll", "Тфилин, мезуза и свиток Торы"),
+    ("Rambam|Fringes", "Цицит"),
+    ("Rambam|Blessings", "Благословения"),
+    ("Rambam|Circumcision", "Обрезание"),
+    ("Rambam|The Order of Prayer", "Порядок молитв"),
+    ("Rambam|Sabbath", "Шабат"),
+    ("Rambam|Eruvin", "Эрувин"),
+    ("Rambam|Rest on the Tenth of Tishrei", "Покой десятого Тишрея"),
+    ("Rambam|Rest on a Holiday", "Покой в праздник"),
+    ("Rambam|Leavened and Unleavened Bread", "Квасное и маца"),
+    ("Rambam|Shofar, Sukkah and Lulav", "Шофар, сука и лулав"),
+    ("Rambam|Sheqel Dues", "Шекели"),
+    ("Rambam|Sanctification of the New Month", "Освящение новомесячья"),
+    ("Rambam|Fasts", "Посты"),
+    ("Rambam|Scroll of Esther and Hanukkah", "Мегила и Ханука"),
+    ("Rambam|Marriage", "Брак"),
+    ("Rambam|Divorce", "Развод"),
+    ("Rambam|Levirate Marriage and Release", "Левиратный брак и халица"),
+    ("Rambam|Virgin Maiden", "Девица"),
+    ("Rambam|Woman Suspected of Infidelity", "Жена, заподозренная в измене"),
+    ("Rambam|Forbidden Intercourse", "Запретные связи"),
+    ("Rambam|Forbidden Foods", "Запрещённая пища"),
+    ("Rambam|Ritual Slaughter", "Шхита"),
+    ("Rambam|Oaths", "Клятвы"),
+    ("Rambam|Vows", "Обеты"),
+    ("Rambam|Nazariteship", "Назорейство"),
+    ("Rambam|Appraisals and Devoted Property", "Оценки и посвящения"),
+    ("Rambam|Diverse Species", "Смешения"),
+    ("Rambam|Gifts to the Poor", "Дары бедным"),
+    ("Rambam|Heave Offerings", "Трумот"),
+    ("Rambam|Tithes", "Десятины"),
+    ("Rambam|Second Tithes and Fourth Year's Fruit", "Вторая десятина и плоды четвёртого года"),
+    ("Rambam|First Fruits and other Gifts to Priests Outside the Sanctuary", "Первые плоды и другие дары коэнам"),
+    ("Rambam|Sabbatical Year and the Jubilee", "Субботний и юбилейный год"),
+    ("Rambam|The Chosen Temple", "Избранный Храм"),
+    ("Rambam|Vessels of the Sanctuary and Those who Serve Therein", "Храмовая утварь и служители"),
+    ("Rambam|Admission into the Sanctuary", "Вход в Храм"),
+    ("Rambam|Things Forbidden on the Altar", "Запрещённое для жертвенника"),
+    ("Rambam|Sacrificial Procedure", "Порядок жертвоприношений"),
+    ("Rambam|Daily Offerings and Additional Offerings", "Постоянные и дополнительные жертвы"),
+    ("Rambam|Sacrifices Rendered Unfit", "Непригодные жертвы"),
+    ("Rambam|Service on the Day of Atonement", "Служба Йом Кипура"),
+    ("Rambam|Trespass", "Присвоение священного"),
+    ("Rambam|Paschal Offering", "Пасхальная жертва"),
+    ("Rambam|Festival Offering", "Праздничная жертва"),
+    ("Rambam|Firstlings", "Первенцы"),
+    ("Rambam|Offerings for Unintentional Transgressions", "Жертвы за неумышленные грехи"),
+    ("Rambam|Offerings for Those with Incomplete Atonement", "Жертвы неполного искупления"),
+    ("Rambam|Substitution", "Замена"),
+    ("Rambam|Defilement by a Corpse", "Нечистота мёртвого"),
+    ("Rambam|Red Heifer", "Рыжая корова"),
+    ("Rambam|Defilement by Leprosy", "Нечистота цараат"),
+    ("Rambam|Those Who Defile Bed or Seat", "Оскверняющие ложе и сиденье"),
+    ("Rambam|Other Sources of Defilement", "Прочие источники нечистоты"),
+    ("Rambam|Defilement of Foods", "Нечистота пищи"),
+    ("Rambam|Vessels", "Сосуды"),
+    ("Rambam|Immersion Pools", "Миквы"),
+    ("Rambam|Damages to Property", "Ущерб имуществу"),
+    ("Rambam|Theft", "Кража"),
+    ("Rambam|Robbery and Lost Property", "Грабёж и потерянное имущество"),
+    ("Rambam|One Who Injures a Person or Property", "Причиняющий ущерб человеку"),
+    ("Rambam|Murderer and the Preservation of Life", "Убийца и сохранение жизни"),
+    ("Rambam|Sales", "Купля-продажа"),
+    ("Rambam|Ownerless Property and Gifts", "Бесхозное имущество и подарки"),
+    ("Rambam|Neighbors", "Соседи"),
+    ("Rambam|Agents and Partners", "Посланники и компаньоны"),
+    ("Rambam|Slaves", "Рабы"),
+    ("Rambam|Hiring", "Наём"),
+    ("Rambam|Borrowing and Deposit", "Ссуда и залог"),
+    ("Rambam|Creditor and Debtor", "Заимодавец и должник"),
+    ("Rambam|Plaintiff and Defendant", "Истец и ответчик"),
+    ("Rambam|Inheritances", "Наследство"),
+    ("Rambam|The Sanhedrin and the Penalties within their Jurisdiction", "Санедрин и наказания в его ведении"),
+    ("Rambam|Testimony", "Свидетельство"),
+    ("Rambam|Rebels", "Непокорные"),
+    ("Rambam|Mourning", "Траур"),
+    ("Rambam|Kings and Wars", "Цари и войны"),
+];
@@ -0,0 +1,105 @@
+//! Sephardi and modern spellings of the English names (Shabbat, Sukkot, Tevet).
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Days of the week in the Hebrew calendar
+    ("weekday|Shabbos", "Shabbat"),
+    // Hebrew months
+    ("Teves", "Tevet"),
+    ("Shvat", "Shevat"),
+    // Yom Tov
+    ("1st day of Sukkos", "1st day of Sukkot"),
+    ("2nd day of Sukkos", "2nd day of Sukkot"),
+    ("3rd day of Sukkos", "3rd day of Sukkot"),
+    ("4th day of Sukkos", "4th day of Sukkot"),
+    ("5th day of Sukkos", "5th day of Sukkot"),
+    ("6th day of Sukkos", "6th day of Sukkot"),
+    ("7th day of Sukkos", "7th day of Sukkot"),
+    ("Shmini Atzeres", "Shemini Atzeret"),
+    ("Simchas Torah", "Simchat Torah"),
+    ("1st day of Shavuos", "1st day of Shavuot"),
+    ("2nd day of Shavuos", "2nd day of Shavuot"),
+    // Chol
+    ("Rosh Chodesh Teves", "Rosh Chodesh Tevet"),
+    ("1st day of Rosh Chodesh Teves", "1st day of Rosh Chodesh Tevet"),
+    ("2nd day of Rosh Chodesh Teves", "2nd day of Rosh Chodesh Tevet"),
+    ("Rosh Chodesh Shvat", "Rosh Chodesh Shevat"),
+    ("Taanis Esther", "Taanit Esther"),
+    ("Tenth of Teves", "Tenth of Tevet"),
+    // Parshiyos (the books of the Torah have the names of their first parshiyos)
+    ("Bereishis", "Bereshit"),
+    ("Vayeira", "Vayera"),
+    ("Toldos", "Toldot"),
+    ("Shemos", "Shemot"),
+    ("Vaeira", "Vaera"),
+    ("Yisro", "Yitro"),
+    ("Ki Sisa", "Ki Tisa"),
+    ("Vayakhel/Pikudei", "Vayakhel/Pekudei"),
+    ("Pikudei", "Pekudei"),
+    ("Tazriya/Metzorah", "Tazria/Metzora"),
+    ("Tazriya", "Tazria"),
+    ("Metzorah", "Metzora"),
+    ("Acharei Mos/Kedoshim", "Acharei Mot/Kedoshim"),
+    ("Acharei Mos", "Acharei Mot"),
+    ("Behar/Bechukosai", "Behar/Bechukotai"),
+    ("Bechukosai", "Bechukotai"),
+    ("Behaaloscha", "Behaalotecha"),
+    ("Shlach", "Shelach"),
+    ("Chukas/Balak", "Chukat/Balak"),
+    ("Chukas", "Chukat"),
+    ("Matos/Maasei", "Matot/Maasei"),
+    ("Matos", "Matot"),
+    ("Vaeschanan", "Vaetchanan"),
+    ("Eikev", "Ekev"),
+    ("Ki Seitzei", "Ki Teitzei"),
+    ("Ki Savo", "Ki Tavo"),
+    // Special parshiyos
+    ("Parshas Zachor", "Parashat Zachor"),
+    ("Parshas HaChodesh", "Parashat HaChodesh"),
+    ("Parshas Parah", "Parashat Parah"),
+    ("Parshas Shekalim", "Parashat Shekalim"),
+    // Minor holidays
+    ("Erev Sukkos", "Erev Sukkot"),
+    ("Erev Shavuos", "Erev Shavuot"),
+    ("Purim Kattan", "Purim Katan"),
+    ("Shushan Purim Kattan", "Shushan Purim Katan"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "Purim Meshulash: Purim feast and mishloach manot"),
+    ("Shabbos HaGadol", "Shabbat HaGadol"),
+    ("Taanis Bechoros", "Taanit Bechorot"),
+    ("Shabbos Chazon", "Shabbat Chazon"),
+    ("Shabbos Nachamu", "Shabbat Nachamu"),
+    ("Leil Slichos", "Leil Selichot"),
+    ("Shabbos Shuva", "Shabbat Shuva"),
+    ("Shabbos Rosh Chodesh", "Shabbat Rosh Chodesh"),
+    ("Shabbos Machar Chodesh", "Shabbat Machar Chodesh"),
+    ("Shabbos Shira", "Shabbat Shira"),
+    ("Shabbos Chanukah", "Shabbat Chanukah"),
+    ("Second Shabbos Chanukah", "Second Shabbat Chanukah"),
+    ("Shabbos Chol HaMoed Sukkos", "Shabbat Chol HaMoed Sukkot"),
+    ("Shabbos Chol HaMoed Pesach", "Shabbat Chol HaMoed Pesach"),
+    ("Shabbos Shekalim", "Shabbat Shekalim"),
+    ("Shabbos Zachor", "Shabbat Zachor"),
+    ("Shabbos Parah", "Shabbat Parah"),
+    ("Shabbos HaChodesh", "Shabbat HaChodesh"),
+    ("Shabbos Mevarchim", "Shabbat Mevarchim"),
+    // Chabad holidays
+    ("Yud Tes Kislev", "Yud Tet Kislev"),
+    ("Hei Teves", "Hei Tevet"),
+    ("Chof Daled Teves", "Chof Daled Tevet"),
+    ("Yud Shvat", "Yud Shevat"),
+    ("Chof Beis Shvat", "Chof Bet Shevat"),
+    ("Yud Beis Tammuz", "Yud Bet Tammuz"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "Shabbat Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}"),
+    // Rare events
+    ("Tekufas {}: {}", "Tekufat {}: {}"),
+    ("Birkas HaChama", "Birkat HaChama"),
+    ("Erev Pesach on Shabbos", "Erev Pesach on Shabbat"),
+    // day-info
+    ("Tachanun is said at Shacharis and Mincha.", "Tachanun is said at Shacharit and Mincha."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "Tachanun is said at Shacharit but not at Mincha."),
+    ("V'sen Tal uMatar", "V'ten Tal uMatar"),
+    // year-info
+    ("Hakhel on Sukkos", "Hakhel on Sukkot"),
+    // wedding-check and wedding-dates
+    ("Shabbos", "Shabbat"),
+];
@@ -0,0 +1,147 @@
+//! The Yiddish catalog. Everything it doesn't have is in the Hebrew one.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Listing
+    ("Night of ", "די נאַכט פֿון "),
+    ("Candle lighting", "ליכט בענטשן"),
+    ("Haftarah", "הפֿטורה"),
+    ("Maftir", "מפֿטיר"),
+    ("In Israel", "אין ארץ ישׂראל"),
+    ("In Chutz La'aretz", "אין חוץ לארץ"),
+    ("Triennial year", "יאָר פֿונעם דרײַ-יאָריקן ציקל"),
+    // Gregorian dates
+    ("%A %B %-d %Y", "%A %-d %B %Y"),
+    ("%A %B %-d %H:%M", "%A %-d %B %H:%M"),
+    ("Sunday", "זונטיק"),
+    ("Monday", "מאָנטיק"),
+    ("Tuesday", "דינסטיק"),
+    ("Wednesday", "מיטוואָך"),
+    ("Thursday", "דאָנערשטיק"),
+    ("Friday", "פֿרײַטיק"),
+    ("Saturday", "שבת"),
+    ("January", "יאַנואַר"),
+    ("February", "פֿעברואַר"),
+    ("March", "מערץ"),
+    ("April", "אַפּריל"),
+    ("May", "מײַ"),
+    ("June", "יוני"),
+    ("July", "יולי"),
+    ("August", "אויגוסט"),
+    ("September", "סעפּטעמבער"),
+    ("October", "אָקטאָבער"),
+    ("November", "נאָוועמבער"),
+    ("December", "דעצעמבער"),
+    // Days of the week in the Hebrew calendar
+    ("weekday|Sunday", "זונטיק"),
+    ("weekday|Monday", "מאָנטיק"),
+    ("weekday|Tuesday", "דינסטיק"),
+    ("weekday|Wednesday", "מיטוואָך"),
+    ("weekday|Thursday", "דאָנערשטיק"),
+    ("weekday|Friday", "פֿרײַטיק"),
+    // Yom Tov
+    ("1st day of Rosh Hashanah", "ערשטער טאָג ראש השנה"),
+    ("2nd day of Rosh Hashanah", "צווייטער טאָג ראש השנה"),
+    ("Yom Kippur", "יום כּיפּור"),
+    ("1st day of Sukkos", "ערשטער טאָג סוכּות"),
+    ("2nd day of Sukkos", "צווייטער טאָג סוכּות"),
+    ("3rd day of Sukkos", "דריטער טאָג סוכּות"),
+    ("4th day of Sukkos", "פֿערטער טאָג סוכּות"),
+    ("5th day of Sukkos", "פֿינפֿטער טאָג סוכּות"),
+    ("6th day of Sukkos", "זעקסטער טאָג סוכּות"),
+    ("7th day of Sukkos", "זיבעטער טאָג סוכּות"),
+    ("Shmini Atzeres", "שמיני עצרת"),
+    ("Simchas Torah", "שׂמחת תּורה"),
+    ("1st day of Pesach", "ערשטער טאָג פּסח"),
+    ("2nd day of Pesach", "צווייטער טאָג פּסח"),
+    ("3rd day of Pesach", "דריטער טאָג פּסח"),
+    ("4th day of Pesach", "פֿערטער טאָג פּסח"),
+    ("5th day of Pesach", "פֿינפֿטער טאָג פּסח"),
+    ("6th day of Pesach", "זעקסטער טאָג פּסח"),
+    ("7th day of Pesach", "זיבעטער טאָג פּסח"),
+    ("8th day of Pesach", "אַכטער טאָג פּסח"),
+    ("1st day of Shavuos", "ערשטער טאָג שבֿועות"),
+    ("2nd day of Shavuos", "צווייטער טאָג שבֿועות"),
+    // Chol
+    ("1st day of Rosh Chodesh Cheshvan", "ערשטער טאָג ראש חודש חשוון"),
+    ("2nd day of Rosh Chodesh Cheshvan", "צווייטער טאָג ראש חודש חשוון"),
+    ("Rosh Chodesh Kislev", "ראש חודש כּסלו"),
+    ("1st day of Rosh Chodesh Kislev", "ערשטער טאָג ראש חודש כּסלו"),
+    ("2nd day of Rosh Chodesh Kislev", "צווייטער טאָג ראש חודש כּסלו"),
+    ("Rosh Chodesh Teves", "ראש חודש טבֿת"),
+    ("1st day of Rosh Chodesh Teves", "ערשטער טאָג ראש חודש טבֿת"),
+    ("2nd day of Rosh Chodesh Teves", "צווייטער טאָג ראש חודש טבֿת"),
+    ("Rosh Chodesh Shvat", "ראש חודש שבֿט"),
+    ("1st day of Rosh Chodesh Adar", "ערשטער טאָג ראש חודש אַדר"),
+    ("2nd day of Rosh Chodesh Adar", "צווייטער טאָג ראש חודש אַדר"),
+    ("1st day of Rosh Chodesh Adar Rishon", "ערשטער טאָג ראש חודש אַדר ראשון"),
+    ("2nd day of Rosh Chodesh Adar Rishon", "צווייטער טאָג ראש חודש אַדר ראשון"),
+    ("1st day of Rosh Chodesh Adar Sheni", "ערשטער טאָג ראש חודש אַדר שני"),
+    ("2nd day of Rosh Chodesh Adar Sheni", "צווייטער טאָג ראש חודש אַדר שני"),
+    ("Rosh Chodesh Nissan", "ראש חודש ניסן"),
+    ("1st day of Rosh Chodesh Iyar", "ערשטער טאָג ראש חודש אייר"),
+    ("2nd day of Rosh Chodesh Iyar", "צווייטער טאָג ראש חודש אייר"),
+    ("Rosh Chodesh Sivan", "ראש חודש סיוון"),
+    ("1st day of Rosh Chodesh Tammuz", "ערשטער טאָג ראש חודש תּמוז"),
+    ("2nd day of Rosh Chodesh Tammuz", "צווייטער טאָג ראש חודש תּמוז"),
+    ("Rosh Chodesh Av", "ראש חודש אָבֿ"),
+    ("1st day of Rosh Chodesh Elul", "ערשטער טאָג ראש חודש אלול"),
+    ("2nd day of Rosh Chodesh Elul", "צווייטער טאָג ראש חודש אלול"),
+    ("1st day of Chanukah", "ערשטער טאָג חנוכּה"),
+    ("2nd day of Chanukah", "צווייטער טאָג חנוכּה"),
+    ("3rd day of Chanukah", "דריטער טאָג חנוכּה"),
+    ("4rd day of Chanukah", "פֿערטער טאָג חנוכּה"),
+    ("5rd day of Chanukah", "פֿינפֿטער טאָג חנוכּה"),
+    ("6rd day of Chanukah", "זעקסטער טאָג חנוכּה"),
+    ("7rd day of Chanukah", "זיבעטער טאָג חנוכּה"),
+    ("8rd day of Chanukah", "אַכטער טאָג חנוכּה"),
+    ("Tzom Gedalia", "צום גדליה"),
+    ("Taanis Esther", "תּענית אסתּר"),
+    ("Tenth of Teves", "עשׂרה בטבֿת"),
+    ("Purim", "פּורים"),
+    ("Shushan Purim", "שושן פּורים"),
+    ("Seventeenth of Tammuz", "שבֿעה־עשׂר בתּמוז"),
+    ("Ninth of Av", "תּשעה באָבֿ"),
+    // Minor holidays
+    ("Pesach Sheni", "פּסח שני"),
+    ("Purim Meshulash: Megillah reading and gifts to the poor", "פּורים משולש: מגילה לייענען און מתּנות לאבֿיונים"),
+    ("Purim Meshulash: Al HaNissim and the Torah reading of Purim", "פּורים משולש: על הנסים און קריאת התּורה פֿון פּורים"),
+    ("Purim Meshulash: Purim feast and mishloach manos", "פּורים משולש: פּורים־סעודה און שלח־מנות"),
+    // Shabbos Mevarchim and Kiddush Levana
+    ("Shabbos Mevarchim {0}: Molad is on {1} {2} {3}, {4} and {5} Chalakim ({6} Jerusalem standard time). {7}", "שבת מברכים {0}: דער מולד איז {1} {3} {2}, {4} און {5} חלקים ({6} ירושלימער ווינטער־צײַט). {7}"),
+    ("Rosh Chodesh {} will be on {}", "ראש חודש {} וועט זײַן {}"),
+    ("Rosh Chodesh {} will be on {} and {}", "ראש חודש {} וועט זײַן {} און {}"),
+    ("Kiddush Levana {}: From {} until {}", "קידוש לבנה {}: פֿון {} ביז {}"),
+    // Mourning periods
+    ("{}, through {}", "{}, ביז {} (אַרײַנגערעכנט)"),
+    ("{}, until the morning of {}", "{}, ביז אין דער פֿרי פֿון {}"),
+    ("Sefirah", "ספֿירה"),
+    ("The Three Weeks", "די דרײַ וואָכן"),
+    ("The Nine Days", "די נײַן טעג"),
+    // convert
+    ("{}: From {} to {}.", "{}: פֿון {} ביז {}."),
+    ("{} -> From sunset {} to sunset {}.", "{}: פֿון שקיעה {} ביז שקיעה {}."),
+    ("{} at {} in {}: {}.", "{} אום {} ({}): {}."),
+    ("{}: {}. This week's parsha: {}.", "{}: {}. די סדרה פֿון דער וואָך: {}."),
+    // day-info
+    ("{}: {} {} Said in the Amidah: {}.", "{}: {} {} מען זאָגט אין שמונה עשׂרה: {}."),
+    ("Tachanun is said at Shacharis and Mincha.", "מען זאָגט תּחנון שחרית און מנחה."),
+    ("Tachanun is said at Shacharis but not at Mincha.", "מען זאָגט תּחנון שחרית אָבער נישט מנחה."),
+    ("Tachanun is not said.", "מען זאָגט נישט קיין תּחנון."),
+    ("Full Hallel is said.", "מען זאָגט גאַנץ הלל."),
+    ("Half Hallel is said.", "מען זאָגט האַלב הלל."),
+    ("Hallel is not said.", "מען זאָגט נישט קיין הלל."),
+    // year-info
+    ("{}: A leap year, year {} of the Shmita cycle.", "{}: אַ עיבור־יאָר, יאָר {} פֿון דער שמיטה."),
+    ("{}: Year {} of the Shmita cycle.", "{}: יאָר {} פֿון דער שמיטה."),
+    // diff
+    ("Hebrew calendar: years: {}, months: {}, days: {}.", "ייִדישער לוח: יאָרן: {}, חדשים: {}, טעג: {}."),
+    ("Gregorian calendar: years: {}, months: {}, days: {}.", "גויִישער לוח: יאָרן: {}, חדשים: {}, טעג: {}."),
+    ("Days in all: {}.", "טעג אין גאַנצן: {}."),
+    // wedding-check and wedding-dates
+    ("A wedding may be held.", "מע מעג מאַכן אַ חתונה."),
+    ("A wedding isn't held because of {}.", "מע מאַכט נישט קיין חתונה צוליב {}."),
+    ("a fast day", "אַ תּענית"),
+    ("the Three Weeks", "די דרײַ וואָכן"),
+    ("the Nine Days", "די נײַן טעג"),
+    ("the Ten Days of Repentance", "עשׂרת ימי תּשובֿה"),
+];
@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod get_omer;
+mod locale;
 pub mod print;
 use crate::args::types::{AppError, MainArgs};
 pub use get_omer::get_omer;
@@ -0,0 +1,91 @@
+use crate::algorithms::wedding::{self, Reason};
+use crate::args::types::{AppError, MainArgs, OutputType, WeddingCheckArgs, WeddingDatesArgs};
+use crate::prelude::print;
+use crate::Runnable;
+use chrono::{DateTime, Datelike, Duration, Utc};
+use heca_lib::HebrewDate;
+use serde::Serialize;
+use std::convert::TryInto;
+
+#[derive(Debug, Serialize)]
+pub struct Return {
+    pub day: HebrewDate,
+    pub permitted: bool,
+    pub reasons: Vec<Reason>,
+}
+
+impl Return {
+    fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
+        let verdict = if self.permitted {
+            args.language.tr("A wedding may be held.").to_string()
+        } else {
+            args.language.tr_format(
+                "A wedding isn't held because of {}.",
+                &[&self
+                    .reasons
+                    .iter()
+                    .map(|x| x.pretty(args.language))
+                    .collect::<Vec<_>>()
+                    .join(", ")],
+            )
+        };
+        println!(
+            "{}: {}",
+            print::hebrew_date(self.day, args.language, args.thousands),
+            verdict
+        );
+        Ok(())
+    }
+
+    fn json_print(&self) -> Result<(), AppError> {
+        println!("{}", serde_json::to_string(&self).unwrap());
+        Ok(())
+    }
+
+    fn print(&self, args: &MainArgs) -> Result<(), AppError> {
+        match args.output_type {
+            OutputType::JSON => self.json_print(),
+            OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
+        }
+    }
+}
+
+impl Runnable for WeddingCheckArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let reasons = wedding::get(self.date, self.location, self.minhag);
+        let ret = Return {
+            day: self.date,
+            permitted: reasons.is_empty(),
+            reasons,
+        };
+        ret.print(args)
+    }
+}
+
+impl Runnable for WeddingDatesArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let first: DateTime<Utc> = self.from.into();
+        let last: DateTime<Utc> = self.to.into();
+        let days = (0..)
+            .map(|x| first + Duration::days(x))
+            .take_while(|x| *x <= last)
+            .map(|x| (x + Duration::hours(1)).try_into().unwrap())
+            .filter(|x| wedding::get(*x, self.location, self.minhag).is_empty())
+            .collect::<Vec<HebrewDate>>();
+        match args.output_type {
+            OutputType::JSON => println!("{}", serde_json::to_string(&days).unwrap()),
+            OutputType::Pretty | OutputType::Regular => days.iter().for_each(|x| {
+                let day = DateTime::<Utc>::from(*x) + Duration::days(1);
+                let hebrew_date = print::hebrew_date(*x, args.language, args.thousands);
+                println!(
+                    "{}/{}/{}: {}",
+                    day.year(),
+                    day.month(),
+                    day.day(),
+                    hebrew_date
+                )
+            }),
+        };
+        Ok(())
+    }
+}
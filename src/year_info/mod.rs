@@ -0,0 +1,218 @@
+use crate::args::types::{AppError, MainArgs, OutputType, YearInfoArgs};
+use crate::prelude::print;
+use crate::Runnable;
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use heca_lib::prelude::{HebrewMonth, MonthSchedule};
+use heca_lib::HebrewYear;
+use serde::Serialize;
+use std::num::NonZeroI8;
+
+/// Which tithe is separated besides Maaser Rishon.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Maaser {
+    Sheni,
+    Ani,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Return {
+    pub year: u64,
+    pub leap_year: bool,
+    pub keviah: MonthSchedule,
+    /// The year's place in the Shmita cycle, from 1 to 7.
+    pub shmita_cycle_year: u8,
+    pub shmita: bool,
+    /// Hakhel is on Sukkos of the year after Shmita.
+    pub hakhel: bool,
+    /// No tithes are separated in a Shmita year.
+    pub maaser: Option<Maaser>,
+    /// Tithes are removed from the house on Erev Pesach of the fourth and seventh years.
+    pub biur_maaser: Option<DateTime<Utc>>,
+}
+
+impl Return {
+    fn pretty_print(&self, args: &MainArgs) -> Result<(), AppError> {
+        let (rosh_hashana, length, pesach) = keviah(self.keviah);
+        let language = args.language;
+        let year = print::hebrew_year(self.year, language, args.thousands);
+        let cycle_year = language.number(u64::from(self.shmita_cycle_year));
+        let mut ret = if self.leap_year {
+            language.tr_format(
+                "{}: A leap year, year {} of the Shmita cycle.",
+                &[&year, &cycle_year],
+            )
+        } else {
+            language.tr_format("{}: Year {} of the Shmita cycle.", &[&year, &cycle_year])
+        };
+        // The keviah is written with the Hebrew letters of the weekdays of Rosh Hashana and
+        // Pesach, and of the length of the year.
+        let letters = format!(
+            "{}{}״{}",
+            rosh_hashana.hebrew_letter(),
+            length.hebrew_letter(),
+            pesach.hebrew_letter()
+        );
+        ret.push_str(&language.tr_format(
+            " Keviah {0} - Rosh Hashana on {2}, {3}, Pesach on {4}.",
+            &[
+                &format!("{:?}", self.keviah),
+                &letters,
+                &print::weekday(rosh_hashana.weekday(), language),
+                &language.tr(length.name()),
+                &print::weekday(pesach.weekday(), language),
+            ],
+        ));
+        let mut facts = vec![];
+        if self.shmita {
+            facts.push(language.tr("Shmita year").to_string());
+        }
+        if self.hakhel {
+            facts.push(language.tr("Hakhel on Sukkos").to_string());
+        }
+        match self.maaser {
+            Some(Maaser::Sheni) => {
+                facts.push(language.tr("Maaser Rishon and Maaser Sheni").to_string())
+            }
+            Some(Maaser::Ani) => {
+                facts.push(language.tr("Maaser Rishon and Maaser Ani").to_string())
+            }
+            None => {}
+        };
+        if let Some(biur_maaser) = self.biur_maaser {
+            let day = biur_maaser + Duration::days(1);
+            facts.push(language.tr_format(
+                "Biur Maaser on Erev Pesach ({})",
+                &[&format!("{}/{}/{}", day.year(), day.month(), day.day())],
+            ));
+        }
+        if !facts.is_empty() {
+            ret.push(' ');
+            ret.push_str(&facts.join(". "));
+            ret.push('.');
+        }
+        println!("{}", ret);
+        Ok(())
+    }
+
+    fn json_print(&self) -> Result<(), AppError> {
+        println!("{}", serde_json::to_string(&self).unwrap());
+        Ok(())
+    }
+
+    fn print(&self, args: &MainArgs) -> Result<(), AppError> {
+        match args.output_type {
+            OutputType::JSON => self.json_print(),
+            OutputType::Pretty | OutputType::Regular => self.pretty_print(args),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Day {
+    Sunday,
+    Monday,
+    Tuesday,
+    Thursday,
+    Shabbos,
+}
+
+impl Day {
+    fn hebrew_letter(self) -> &'static str {
+        match self {
+            Day::Sunday => "א",
+            Day::Monday => "ב",
+            Day::Tuesday => "ג",
+            Day::Thursday => "ה",
+            Day::Shabbos => "ז",
+        }
+    }
+
+    fn weekday(self) -> Weekday {
+        match self {
+            Day::Sunday => Weekday::Sun,
+            Day::Monday => Weekday::Mon,
+            Day::Tuesday => Weekday::Tue,
+            Day::Thursday => Weekday::Thu,
+            Day::Shabbos => Weekday::Sat,
+        }
+    }
+}
+
+/// Whether Cheshvan and Kislev are both short, one is short, or both are long.
+#[derive(Clone, Copy)]
+enum Length {
+    Chaseira,
+    Kesidra,
+    Shleima,
+}
+
+impl Length {
+    fn hebrew_letter(self) -> &'static str {
+        match self {
+            Length::Chaseira => "ח",
+            Length::Kesidra => "כ",
+            Length::Shleima => "ש",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Length::Chaseira => "a deficient year",
+            Length::Kesidra => "a regular year",
+            Length::Shleima => "a full year",
+        }
+    }
+}
+
+fn keviah(schedule: MonthSchedule) -> (Day, Length, Day) {
+    use Day::*;
+    use Length::*;
+    match schedule {
+        MonthSchedule::BaChaG => (Monday, Chaseira, Tuesday),
+        MonthSchedule::BaShaH => (Monday, Shleima, Thursday),
+        MonthSchedule::GaChaH => (Tuesday, Kesidra, Thursday),
+        MonthSchedule::HaKaZ => (Thursday, Kesidra, Shabbos),
+        MonthSchedule::HaShA => (Thursday, Shleima, Sunday),
+        MonthSchedule::ZaChA => (Shabbos, Chaseira, Sunday),
+        MonthSchedule::ZaShaG => (Shabbos, Shleima, Tuesday),
+        MonthSchedule::BaChaH => (Monday, Chaseira, Thursday),
+        MonthSchedule::BaShaZ => (Monday, Shleima, Shabbos),
+        MonthSchedule::GaKaZ => (Tuesday, Kesidra, Shabbos),
+        MonthSchedule::HaChA => (Thursday, Chaseira, Sunday),
+        MonthSchedule::HaShaG => (Thursday, Shleima, Tuesday),
+        MonthSchedule::ZaChaG => (Shabbos, Chaseira, Tuesday),
+        MonthSchedule::ZaShaH => (Shabbos, Shleima, Thursday),
+    }
+}
+
+impl Runnable for YearInfoArgs {
+    fn run(&self, args: &MainArgs) -> Result<(), AppError> {
+        let year = HebrewYear::new(self.year)?;
+        // 5782 was a Shmita year.
+        let shmita_cycle_year = ((self.year - 1) % 7 + 1) as u8;
+        let biur_maaser = if shmita_cycle_year == 4 || shmita_cycle_year == 7 {
+            Some(
+                year.get_hebrew_date(HebrewMonth::Nissan, NonZeroI8::new(14).unwrap())?
+                    .into(),
+            )
+        } else {
+            None
+        };
+        let ret = Return {
+            year: self.year,
+            leap_year: year.is_leap_year(),
+            keviah: year.year_type(),
+            shmita_cycle_year,
+            shmita: shmita_cycle_year == 7,
+            hakhel: shmita_cycle_year == 1,
+            maaser: match shmita_cycle_year {
+                3 | 6 => Some(Maaser::Ani),
+                7 => None,
+                _ => Some(Maaser::Sheni),
+            },
+            biur_maaser,
+        };
+        ret.print(args)
+    }
+}
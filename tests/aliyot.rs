@@ -0,0 +1,162 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, location: &str, show: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--aliyot")
+        .arg(format!("--location={}", location))
+        .arg(format!("--show={}", show));
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn shabbos_aliyot() {
+    for location in &["Chul", "Israel"] {
+        let res = list("5780", location, "shabbos");
+        for shabbos in &res {
+            let reading = shabbos.reading.as_ref().unwrap();
+            assert_eq!(reading.aliyot.len(), 7, "{}", shabbos.name);
+            assert!(reading.maftir.is_some());
+            assert_eq!(reading.range.start, reading.aliyot[0].start);
+            assert_eq!(reading.range.end, reading.aliyot[6].end);
+        }
+        let bereishis = res.iter().find(|x| x.name == "Bereishis").unwrap();
+        let reading = bereishis.reading.as_ref().unwrap();
+        assert_eq!(reading.range.book, "Bereishis");
+        assert_eq!(reading.range.start, "1:1");
+        assert_eq!(reading.range.end, "6:8");
+        assert_eq!(reading.aliyot[0].end, "2:3");
+    }
+    // In 5782, the last day of Pesach was on Shabbos, so Israel was a week ahead of Chutz
+    // La'aretz until Matos/Maasei.
+    let chul = list("5782", "Chul", "shabbos");
+    let israel = list("5782", "Israel", "shabbos");
+    let chul = chul
+        .iter()
+        .find(|x| x.day == "2022-04-29T18:00:00Z")
+        .unwrap();
+    let israel = israel
+        .iter()
+        .find(|x| x.day == "2022-04-29T18:00:00Z")
+        .unwrap();
+    assert_eq!(chul.name, "AchareiMos");
+    assert_eq!(chul.reading.as_ref().unwrap().range.start, "16:1");
+    assert_eq!(israel.name, "Kedoshim");
+    assert_eq!(israel.reading.as_ref().unwrap().range.start, "19:1");
+}
+
+#[test]
+fn yom_tov_aliyot() {
+    // The last day of Pesach 5782 was on Shabbos.
+    let res = list("5782", "Chul", "yom-tov");
+    let pesach8 = res.iter().find(|x| x.name == "Pesach8").unwrap();
+    let reading = pesach8.reading.as_ref().unwrap();
+    assert_eq!(reading.range.start, "14:22");
+    assert_eq!(reading.aliyot.len(), 7);
+    let shavuos2 = res.iter().find(|x| x.name == "Shavuos2").unwrap();
+    let reading = shavuos2.reading.as_ref().unwrap();
+    assert_eq!(reading.range.start, "15:19");
+    assert_eq!(reading.aliyot.len(), 5);
+
+    // In Israel, Simchas Torah's reading is read on Shmini Atzeres.
+    let res = list("5780", "Israel", "yom-tov");
+    let shmini_atzeres = res.iter().find(|x| x.name == "ShminiAtzeres").unwrap();
+    let reading = shmini_atzeres.reading.as_ref().unwrap();
+    assert_eq!(reading.range.book, "Devarim");
+    assert_eq!(reading.range.start, "33:1");
+    assert_eq!(reading.aliyot.last().unwrap().book, "Bereishis");
+
+    // Rosh Chodesh Teves 5780 was on Shabbos and Sunday, the sixth and seventh days of Chanukah.
+    let res = list("5780", "Chul", "chol");
+    let teves2 = res.iter().find(|x| x.name == "RoshChodeshTeves2").unwrap();
+    let reading = teves2.reading.as_ref().unwrap();
+    assert_eq!(reading.aliyot.len(), 4);
+    assert_eq!(reading.aliyot[0].start, "28:1");
+    assert_eq!(reading.aliyot[3].start, "7:48");
+    assert_eq!(reading.aliyot[3].end, "7:53");
+
+    // Shabbos Chol HaMoed Sukkos 5780 was the sixth day. Chutz La'aretz reads the sacrifices of
+    // the fifth day as well.
+    let res = list("5780", "Chul", "yom-tov");
+    let sukkos6 = res.iter().find(|x| x.name == "Sukkos6").unwrap();
+    let maftir = sukkos6.reading.as_ref().unwrap().maftir.as_ref().unwrap();
+    assert_eq!(
+        (maftir.start.as_str(), maftir.end.as_str()),
+        ("29:26", "29:31")
+    );
+    let res = list("5780", "Israel", "yom-tov");
+    let sukkos6 = res.iter().find(|x| x.name == "Sukkos6").unwrap();
+    let maftir = sukkos6.reading.as_ref().unwrap().maftir.as_ref().unwrap();
+    assert_eq!(
+        (maftir.start.as_str(), maftir.end.as_str()),
+        ("29:29", "29:31")
+    );
+
+    let res = list("5780", "Chul", "special-parshas");
+    assert_eq!(res.len(), 4);
+    for special_parsha in res {
+        assert_eq!(special_parsha.reading.unwrap().aliyot.len(), 1);
+    }
+}
+
+#[test]
+fn chanukah_aliyot() {
+    let third_aliyah = |minhag: &str| {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        cmd.arg("--language")
+            .arg("en_US")
+            .arg("--print")
+            .arg("json")
+            .arg("list")
+            .arg("5780")
+            .arg("--aliyot")
+            .arg("--show=chol")
+            .arg(format!("--minhag={}", minhag));
+        let out = cmd.output().unwrap();
+        if !out.status.success() {
+            panic!("{}", String::from_utf8(out.stderr).unwrap());
+        }
+        let res: Vec<Res> = serde_json::from_slice(&out.stdout).unwrap();
+        let chanukah3 = res.into_iter().find(|x| x.name == "Chanukah3").unwrap();
+        let aliyah = &chanukah3.reading.unwrap().aliyot[2];
+        (aliyah.start.clone(), aliyah.end.clone())
+    };
+    // Ashkenazim read about the fourth day's Nasi, and Sephardim repeat the third day's.
+    assert_eq!(third_aliyah("ashkenaz"), ("7:30".into(), "7:35".into()));
+    assert_eq!(
+        third_aliyah("edot-hamizrach"),
+        ("7:24".into(), "7:29".into())
+    );
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: String,
+    name: String,
+    reading: Option<Reading>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Reading {
+    range: Aliyah,
+    aliyot: Vec<Aliyah>,
+    maftir: Option<Aliyah>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Aliyah {
+    book: String,
+    start: String,
+    end: String,
+}
@@ -0,0 +1,54 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg("--show=chabad-holidays");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn chabad_holidays() {
+    let res = list("5785");
+    assert_eq!(res.len(), 14);
+    let yud_shvat = res.iter().find(|x| x.name == "YudShvat").unwrap();
+    assert_eq!(yud_shvat.day, "2025-02-07T18:00:00Z");
+    assert_eq!(yud_shvat.kind.as_deref(), Some("Hilula"));
+    let chai_elul = res.iter().find(|x| x.name == "ChaiElul").unwrap();
+    assert_eq!(chai_elul.kind.as_deref(), Some("Birthday"));
+    let hei_teves = res.iter().find(|x| x.name == "HeiTeves").unwrap();
+    assert_eq!(hei_teves.kind, None);
+}
+
+#[test]
+fn first_year() {
+    let names = |year| {
+        list(year)
+            .into_iter()
+            .map(|x| x.name)
+            .collect::<Vec<String>>()
+    };
+    assert!(!names("5754").contains(&"GimmelTammuz".to_string()));
+    assert!(names("5755").contains(&"GimmelTammuz".to_string()));
+    assert!(!names("5710").contains(&"YudShvat".to_string()));
+    assert!(names("5711").contains(&"YudShvat".to_string()));
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: String,
+    name: String,
+    kind: Option<String>,
+}
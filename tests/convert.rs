@@ -217,3 +217,485 @@ fn convert_day_too_large_no_json() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn convert_hebrew_numerals() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("he_IL")
+        .arg("convert")
+        .arg("2019/02/20");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "ט״ו אדר א׳ תשע״ט - ט״ז אדר א׳ תשע״ט",
+    ));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("he_IL")
+        .arg("--thousands")
+        .arg("convert")
+        .arg("2020/08/20");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("ל׳ אב ה׳תש״פ - א׳ אלול ה׳תש״פ"));
+
+    // 6000 has no hundreds, tens or ones, so it's written with its thousands.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("he_IL")
+        .arg("convert")
+        .arg("2239/10/01");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("ב׳ תשרי ו׳ - ג׳ תשרי ו׳."));
+
+    Ok(())
+}
+
+#[test]
+fn convert_hebrew_numeral_input() -> Result<(), Box<dyn std::error::Error>> {
+    for (date, expected) in &[
+        (
+            "כ״ה כסלו תשפ״ה",
+            r#"["2024-12-25T18:00:00Z","2024-12-26T18:00:00Z"]"#,
+        ),
+        (
+            "ה' אדר ב' התשע\"ט",
+            r#"["2019-03-11T18:00:00Z","2019-03-12T18:00:00Z"]"#,
+        ),
+        (
+            "ה׳ באדר ב׳ ה׳תשע״ט",
+            r#"["2019-03-11T18:00:00Z","2019-03-12T18:00:00Z"]"#,
+        ),
+        (
+            "15 בניסן 5780",
+            r#"["2020-04-08T18:00:00Z","2020-04-09T18:00:00Z"]"#,
+        ),
+        (
+            "ל חשון תשפה",
+            r#"["2024-11-30T18:00:00Z","2024-12-01T18:00:00Z"]"#,
+        ),
+        (
+            "א׳ ניסן ד׳תתקפ״ה",
+            r#"["1225-03-17T18:00:00Z","1225-03-18T18:00:00Z"]"#,
+        ),
+        (
+            "ט״ז שבט תשפ״ה",
+            r#"["2025-02-13T18:00:00Z","2025-02-14T18:00:00Z"]"#,
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("--print").arg("json").arg("convert").arg(date);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(*expected));
+    }
+
+    // The letters are out of order, or 15 and 16 are written with the Name.
+    for date in &[
+        "כ״ה כסלו פשת",
+        "י״ה כסלו תשפ״ה",
+        "יה כסלו תשפ״ה",
+        "י״ו כסלו תשפ״ה",
+        "ט״ו כסלו התשי״ה",
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("convert").arg(date);
+        cmd.assert().failure();
+    }
+
+    Ok(())
+}
+
+#[test]
+fn convert_other_languages() -> Result<(), Box<dyn std::error::Error>> {
+    for (language, expected) in &[
+        (
+            "fr_FR",
+            "dimanche 10 mars 2019: du 3 Adar Chéni 5779 au 4 Adar Chéni 5779.",
+        ),
+        (
+            "ru_RU",
+            "воскресенье 10 марта 2019: с 3 Адар Шени 5779 до 4 Адар Шени 5779.",
+        ),
+        (
+            "yi_US",
+            "זונטיק 10 מערץ 2019: פֿון ג׳ אדר ב׳ תשע״ט ביז ד׳ אדר ב׳ תשע״ט.",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("--language")
+            .arg(language)
+            .arg("convert")
+            .arg("2019/3/10");
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(*expected));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn convert_language_from_lang() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.env("LANG", "es_MX.UTF-8")
+        .arg("convert")
+        .arg("2019/3/10");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "domingo 10 de marzo de 2019: desde el 3 Adar Sheni 5779 hasta el 4 Adar Sheni 5779.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn convert_transliteration() -> Result<(), Box<dyn std::error::Error>> {
+    for (transliteration, date, expected) in &[
+        ("ashkenazi", "1/Teves/5780", "1 Teves 5780 -> From sunset"),
+        ("sephardi", "1/Teves/5780", "1 Tevet 5780 -> From sunset"),
+        (
+            "academic",
+            "1/Cheshvan/5780",
+            "1 Ḥeshvan 5780 -> From sunset",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("--language")
+            .arg("en_US")
+            .arg("--transliteration")
+            .arg(transliteration)
+            .arg("convert")
+            .arg(date);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(*expected));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn convert_batch() -> Result<(), Box<dyn std::error::Error>> {
+    // Blank lines are skipped, and a line that can't be converted fails the command at the end.
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .write_stdin("2019/3/10\nfoo\n\n5 Adar2 5779\n");
+    cmd.assert()
+        .failure()
+        .stdout(
+            "Sunday March 10 2019: From 3 Adar Sheni 5779 to 4 Adar Sheni 5779.\n\
+             5 Adar Sheni 5779 -> From sunset Monday March 11 2019 to sunset Tuesday March 12 2019.\n",
+        )
+        .stderr(
+            "Line 2 (foo): Cannot split the date. Deliminators are: '-', '/', '_', '\\', '.', ',', '=']\n\
+             1 line couldn't be converted\n",
+        );
+
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .write_stdin("2019/3/10\n\n");
+    cmd.assert()
+        .success()
+        .stdout("Sunday March 10 2019: From 3 Adar Sheni 5779 to 4 Adar Sheni 5779.\n");
+
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--print")
+        .arg("json")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .arg("--column")
+        .arg("2")
+        .write_stdin("name,born\nMoshe,\"1990/1/1\"\nAharon\n");
+    cmd.assert().failure().stdout(
+        "{\"line\":1,\"input\":\"born\",\"error\":{\"type\":\"SplitDateError\"}}\n\
+         {\"line\":2,\"input\":\"1990/1/1\",\"converted\":[{\"day\":4,\"month\":\"Teves\",\"year\":5750},{\"day\":5,\"month\":\"Teves\",\"year\":5750}]}\n\
+         {\"line\":3,\"input\":\"Aharon\",\"error\":{\"type\":\"NoSuchColumn\",\"error\":2}}\n",
+    );
+
+    // With --header, the header isn't read as a date.
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--print")
+        .arg("json")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .arg("--column")
+        .arg("2")
+        .arg("--header")
+        .write_stdin("name,born\nMoshe,\"1990/1/1\"\n");
+    cmd.assert().success().stdout(
+        "{\"line\":2,\"input\":\"1990/1/1\",\"converted\":[{\"day\":4,\"month\":\"Teves\",\"year\":5750},{\"day\":5,\"month\":\"Teves\",\"year\":5750}]}\n",
+    );
+
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .arg("--column")
+        .arg("3")
+        .write_stdin("a,b\n");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Line 1 (a,b): The line has no column 3",
+    ));
+
+    // A quoted field can hold a comma.
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .arg("--column")
+        .arg("3")
+        .write_stdin("a,\"Jan 5, 2020\",2020/1/1\n");
+    cmd.assert()
+        .success()
+        .stdout("Wednesday January 1 2020: From 4 Teves 5780 to 5 Teves 5780.\n");
+
+    // A line that isn't UTF-8 is reported like any other bad line.
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .write_stdin(&b"\xff\n2020/1/1\n"[..]);
+    cmd.assert()
+        .failure()
+        .stdout("Wednesday January 1 2020: From 4 Teves 5780 to 5 Teves 5780.\n")
+        .stderr(predicate::str::contains("Line 1"));
+
+    let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("convert")
+        .arg("--batch")
+        .arg("-")
+        .arg("--column")
+        .arg("0");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a valid column"));
+
+    Ok(())
+}
+
+#[test]
+fn convert_time() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("2024-03-10T12:00")
+        .arg("--city")
+        .arg("Jerusalem");
+    cmd.assert()
+        .success()
+        .stdout("Sunday March 10 2024 at 12:00 in Jerusalem: 30 Adar Rishon 5784.\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--print")
+        .arg("json")
+        .arg("convert")
+        .arg("2024-03-10T19:30")
+        .arg("--city")
+        .arg("Jerusalem");
+    cmd.assert()
+        .success()
+        .stdout("{\"day\":1,\"month\":\"Adar2\",\"year\":5784}\n");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.env_remove("HECA_CITY")
+        .arg("--config")
+        .arg("/dev/null")
+        .arg("convert")
+        .arg("2024-03-10T19:30");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("A time needs a city"));
+    Ok(())
+}
+
+#[test]
+fn convert_natural() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("erev pesach 5785");
+    cmd.assert().success().stdout(
+        "14 Nissan 5785 -> From sunset Friday April 11 2025 to sunset Saturday April 12 2025.\n",
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("Rosh Hashanah 2031");
+    cmd.assert().success().stdout(
+        "1 Tishrei 5792 -> From sunset Wednesday September 17 2031 to sunset Thursday September 18 2031.\n",
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--print")
+        .arg("json")
+        .arg("convert")
+        .arg("tisha b'av 2025");
+    cmd.assert()
+        .success()
+        .stdout("[\"2025-08-02T18:00:00Z\",\"2025-08-03T18:00:00Z\"]\n");
+
+    // A small number is the day of the holiday, not the year.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("pesach 8");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("22 Nissan"));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("pesach 8 5785");
+    cmd.assert().success().stdout(
+        "22 Nissan 5785 -> From sunset Saturday April 19 2025 to sunset Sunday April 20 2025.\n",
+    );
+
+    // A day of a Hebrew month can have a Gregorian year.
+    for (date, expected) in &[
+        (
+            "9 av 2025",
+            "9 Av 5785 -> From sunset Saturday August 2 2025",
+        ),
+        (
+            "17 tammuz 2025",
+            "17 Tammuz 5785 -> From sunset Saturday July 12 2025",
+        ),
+        (
+            "1 tishrei 2025",
+            "1 Tishrei 5786 -> From sunset Monday September 22 2025",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("--language").arg("en_US").arg("convert").arg(date);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::starts_with(*expected));
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("convert").arg("30 cheshvan 2025");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Cannot find the date 30 cheshvan 2025",
+    ));
+
+    for date in &[
+        "+100000000d",
+        "+200000000000000d",
+        "+99999999999999999999999d",
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("convert").arg(date);
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("out of the range of dates"));
+    }
+
+    // A holiday of one day is also its first day.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("purim 1 2025");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("14 Adar 5785"));
+
+    // 5785 and 5786 aren't leap years.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("convert").arg("purim kattan 2025");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Cannot find the date purim kattan 2025",
+    ));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("today");
+    cmd.assert().success();
+    Ok(())
+}
+
+#[test]
+fn convert_details() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("convert")
+        .arg("--details")
+        .arg("--location")
+        .arg("Chul")
+        .arg("2025/3/13");
+    cmd.assert().success().stdout(
+        "Thursday March 13 2025: From 13 Adar 5785 to 14 Adar 5785.\n\
+         13 Adar 5785: Thursday. This week's parsha: Ki Sisa.\n  \
+         Taanis Esther\n  \
+         Sanhedrin 86\n  \
+         Jerusalem Talmud Chagigah 21\n  \
+         Foreign Worship and Customs of the Nations 4 - Foreign Worship and Customs of the Nations 6\n  \
+         Vessels 7\n",
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.arg("--print")
+        .arg("json")
+        .arg("convert")
+        .arg("--details")
+        .arg("--location")
+        .arg("Chul")
+        .arg("2025/3/15");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""day":{"day":15,"month":"Adar","year":5785},"weekday":"Shabbos","events":[{"day":"2025-03-14T18:00:00Z","type":"Chol","name":"ShushanPurim"}],"parsha":{"day":"2025-03-14T18:00:00Z","type":"Shabbos","name":"KiSisa"}"#,
+        ));
+
+    // A Yom Tov or Chol HaMoed on Shabbos is read in place of the parsha.
+    for (date, reading) in [
+        ("2021/3/28", "7th day of Pesach"),
+        ("2024/4/22", "5th day of Pesach"),
+        ("2023/10/1", "Shmini Atzeres"),
+    ]
+    .iter()
+    {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.arg("--language")
+            .arg("en_US")
+            .arg("convert")
+            .arg("--details")
+            .arg("--location")
+            .arg("Chul")
+            .arg(date);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(format!(
+                "This week's parsha: {}.",
+                reading
+            )));
+    }
+    Ok(())
+}
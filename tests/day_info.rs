@@ -0,0 +1,165 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn day_info(date: &str, extra: &[&str]) -> Res {
+    let mut args = vec!["day-info", date];
+    args.extend_from_slice(extra);
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .args(&args);
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn tachanun() {
+    // A regular Tuesday.
+    let res = day_info("2020/05/05", &["--location=Chul"]);
+    assert_eq!(res.day.month, "Iyar");
+    assert!(res.tachanun.shacharis && res.tachanun.mincha);
+    // Erev Shabbos.
+    let res = day_info("2020/05/15", &["--location=Chul"]);
+    assert!(res.tachanun.shacharis && !res.tachanun.mincha);
+    // The day before Pesach Sheni.
+    let res = day_info("2020/05/07", &["--location=Chul"]);
+    assert!(res.tachanun.shacharis && !res.tachanun.mincha);
+    // 8 Av 5780 and the fast of 9 Av.
+    let res = day_info("2020/07/29", &["--location=Chul"]);
+    assert!(res.tachanun.shacharis && !res.tachanun.mincha);
+    let res = day_info("2020/07/30", &["--location=Chul"]);
+    assert!(!res.tachanun.shacharis && !res.tachanun.mincha);
+    // Yud Tes Kislev.
+    let res = day_info("19/Kislev/5780", &["--location=Chul"]);
+    assert!(res.tachanun.shacharis);
+    let res = day_info("19/Kislev/5780", &["--location=Chul", "--minhag=chabad"]);
+    assert!(!res.tachanun.shacharis);
+    let res = day_info("18/Kislev/5780", &["--location=Chul", "--minhag=chabad"]);
+    assert!(res.tachanun.shacharis && !res.tachanun.mincha);
+    // Yud Shvat isn't a day of Geulah.
+    let res = day_info("10/Shvat/5780", &["--location=Chul", "--minhag=chabad"]);
+    assert!(res.tachanun.shacharis && res.tachanun.mincha);
+}
+
+#[test]
+fn hallel() {
+    assert_eq!(
+        day_info("16/Nissan/5780", &["--location=Chul"]).hallel,
+        "Full"
+    );
+    assert_eq!(
+        day_info("16/Nissan/5780", &["--location=Israel"]).hallel,
+        "Half"
+    );
+    assert_eq!(
+        day_info("22/Nissan/5780", &["--location=Israel"]).hallel,
+        "None"
+    );
+    assert_eq!(
+        day_info("1/Teves/5780", &["--location=Chul"]).hallel,
+        "Full"
+    );
+    assert_eq!(day_info("1/Iyar/5780", &["--location=Chul"]).hallel, "Half");
+    assert_eq!(
+        day_info("1/Tishrei/5780", &["--location=Chul"]).hallel,
+        "None"
+    );
+    assert_eq!(
+        day_info("23/Tishrei/5780", &["--location=Chul"]).hallel,
+        "Full"
+    );
+    assert_eq!(
+        day_info("23/Tishrei/5780", &["--location=Israel"]).hallel,
+        "None"
+    );
+    // Yom HaAtzmaut 5780 was on a Wednesday.
+    let res = day_info("2020/04/29", &["--location=Israel"]);
+    assert_eq!(res.hallel, "None");
+    assert!(res.tachanun.shacharis);
+    let res = day_info(
+        "2020/04/29",
+        &["--location=Israel", "--minhag=edot-hamizrach"],
+    );
+    assert_eq!(res.hallel, "Full");
+    assert!(!res.tachanun.shacharis);
+}
+
+#[test]
+fn insertions() {
+    // In Chutz La'aretz, V'sen Tal uMatar starts on the evening of December 5th when the next
+    // year is a leap year, and December 4th otherwise.
+    let res = day_info("2019/12/05", &["--location=Chul"]);
+    assert!(!res.insertions.tal_umatar);
+    let res = day_info("2019/12/06", &["--location=Chul"]);
+    assert!(res.insertions.tal_umatar);
+    let res = day_info("2021/12/05", &["--location=Chul"]);
+    assert!(res.insertions.tal_umatar);
+    let res = day_info("2021/12/04", &["--location=Chul"]);
+    assert!(!res.insertions.tal_umatar);
+    // In 5774, it started on the evening of 1 Teves.
+    let res = day_info("1/Teves/5774", &["--location=Chul"]);
+    assert!(!res.insertions.tal_umatar);
+    let res = day_info("2/Teves/5774", &["--location=Chul"]);
+    assert!(res.insertions.tal_umatar);
+    let res = day_info("7/Cheshvan/5780", &["--location=Israel"]);
+    assert!(res.insertions.tal_umatar);
+    let res = day_info("7/Cheshvan/5780", &["--location=Chul"]);
+    assert!(!res.insertions.tal_umatar);
+    assert_eq!(res.insertions.mashiv_haruach, "MashivHaRuach");
+
+    let res = day_info("30/Kislev/5780", &["--location=Chul"]);
+    assert!(res.insertions.yaaleh_veyavo && res.insertions.al_hanissim);
+    let res = day_info("14/Adar2/5779", &["--location=Chul"]);
+    assert!(!res.insertions.yaaleh_veyavo && res.insertions.al_hanissim);
+    let res = day_info("14/Adar1/5779", &["--location=Chul"]);
+    assert!(!res.insertions.al_hanissim);
+    let res = day_info("14/Adar/5781", &["--location=Jerusalem"]);
+    assert!(!res.insertions.al_hanissim);
+    let res = day_info("15/Adar/5781", &["--location=Jerusalem"]);
+    assert!(res.insertions.al_hanissim);
+    let res = day_info("22/Nissan/5780", &["--location=Chul"]);
+    assert!(res.insertions.yaaleh_veyavo);
+    let res = day_info("22/Nissan/5780", &["--location=Israel"]);
+    assert!(!res.insertions.yaaleh_veyavo && !res.insertions.tal_umatar);
+
+    let res = day_info("1/Sivan/5780", &["--location=Chul"]);
+    assert_eq!(res.insertions.mashiv_haruach, "None");
+    let res = day_info("1/Sivan/5780", &["--location=Chul", "--minhag=sefard"]);
+    assert_eq!(res.insertions.mashiv_haruach, "MoridHaTal");
+    let res = day_info("1/Sivan/5780", &["--location=Israel"]);
+    assert_eq!(res.insertions.mashiv_haruach, "MoridHaTal");
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: Day,
+    tachanun: Tachanun,
+    hallel: String,
+    insertions: Insertions,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Insertions {
+    yaaleh_veyavo: bool,
+    al_hanissim: bool,
+    mashiv_haruach: String,
+    tal_umatar: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct Day {
+    month: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct Tachanun {
+    shacharis: bool,
+    mincha: bool,
+}
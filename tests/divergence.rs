@@ -0,0 +1,117 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, location: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg(format!("--location={}", location))
+        .arg("--show=shabbos");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn israel_and_chul() {
+    // The eighth day of Pesach 5782 was on Shabbos, and Chutz La'aretz only caught up with
+    // Matos/Maasei.
+    let chul = list("5782", "Chul");
+    let diverging: Vec<_> = chul.iter().filter(|x| x.other_location.is_some()).collect();
+    assert_eq!(diverging.len(), 14);
+    assert_eq!(diverging[0].day, "2022-04-29T18:00:00Z");
+    assert_eq!(diverging[0].name, "AchareiMos");
+    let other = diverging[0].other_location.as_ref().unwrap();
+    assert_eq!(other.location, "Israel");
+    assert_eq!(other.name, "Kedoshim");
+
+    let israel = list("5782", "Israel");
+    let acharei_mos = israel.iter().find(|x| x.name == "AchareiMos").unwrap();
+    let other = acharei_mos.other_location.as_ref().unwrap();
+    assert_eq!(other.location, "Chul");
+    assert_eq!(other.r#type, "YomTov");
+    assert_eq!(other.name, "Pesach8");
+
+    // 5781 had no divergence.
+    assert!(list("5781", "Chul")
+        .iter()
+        .all(|x| x.other_location.is_none()));
+}
+
+#[test]
+fn triennial() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("5786")
+        .arg("--type=hebrew")
+        .arg("--show=shabbos")
+        .arg("--triennial");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<Res> = serde_json::from_slice(&out.stdout).unwrap();
+    let portion = |name: &str| {
+        let triennial = res
+            .iter()
+            .find(|x| x.name == name)
+            .unwrap()
+            .triennial
+            .as_ref()
+            .unwrap();
+        (
+            triennial.year,
+            triennial.portion.book.as_str(),
+            triennial.portion.start.as_str(),
+            triennial.portion.end.as_str(),
+        )
+    };
+    // Haazinu in Tishrei finishes the third year, and Bereishis starts the cycle over.
+    assert_eq!(portion("Haazinu"), (3, "Devarim", "32:40", "32:52"));
+    assert_eq!(portion("Bereishis"), (1, "Bereishis", "1:1", "3:21"));
+    assert_eq!(portion("Noach"), (1, "Bereishis", "6:9", "8:14"));
+
+    // Without the flag, there's no triennial portion.
+    assert!(list("5786", "Chul").iter().all(|x| x.triennial.is_none()));
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Res {
+    day: String,
+    name: String,
+    other_location: Option<OtherLocation>,
+    triennial: Option<Triennial>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Triennial {
+    year: u8,
+    portion: Portion,
+}
+
+#[derive(Deserialize, Debug)]
+struct Portion {
+    book: String,
+    start: String,
+    end: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct OtherLocation {
+    location: String,
+    r#type: String,
+    name: String,
+}
@@ -0,0 +1,151 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, minhag: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg("--haftarah")
+        .arg("--location=Chul")
+        .arg(format!("--minhag={}", minhag))
+        .arg("--show=shabbos,yom-tov");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+fn first_section<'a>(res: &'a [Res], name: &str) -> &'a Section {
+    &res.iter()
+        .find(|x| x.name == name)
+        .unwrap()
+        .haftarah
+        .as_ref()
+        .unwrap()[0]
+}
+
+fn sections<'a>(res: &'a [Res], name: &str) -> Vec<(&'a str, &'a str)> {
+    res.iter()
+        .find(|x| x.name == name)
+        .unwrap()
+        .haftarah
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|x| (x.book.as_ref(), x.start.as_ref()))
+        .collect()
+}
+
+#[test]
+fn haftarah_overrides() {
+    let res = list("5780", "ashkenaz");
+    // Shabbos Shuva.
+    let vayelech = first_section(&res, "Vayelech");
+    assert_eq!(
+        (vayelech.book.as_ref(), vayelech.start.as_ref()),
+        ("Hoshea", "14:2")
+    );
+    // Shabbos Chanukah (and Rosh Chodesh).
+    assert_eq!(first_section(&res, "Miketz").book, "Zecharia");
+    // Shabbos Rosh Chodesh and Machar Chodesh.
+    assert_eq!(first_section(&res, "TazriyaMetzorah").start, "66:1");
+    assert_eq!(first_section(&res, "Bamidbar").start, "20:18");
+    // Shabbos HaGadol, but not the Shabbos before it.
+    assert_eq!(first_section(&res, "Tzav").book, "Malachi");
+    assert_eq!(first_section(&res, "Vayikra").book, "Yeshayahu");
+    // The Three Weeks and the Seven of Consolation.
+    assert_eq!(first_section(&res, "Pinchas").start, "1:1");
+    assert_eq!(first_section(&res, "MatosMaasei").start, "2:4");
+    assert_eq!(first_section(&res, "KiSeitzei").start, "54:1");
+    // Shabbos Chol HaMoed.
+    assert_eq!(first_section(&res, "Sukkos6").book, "Yechezkel");
+    // Weekday Chol HaMoed has no Haftarah.
+    assert!(res
+        .iter()
+        .find(|x| x.name == "Sukkos5")
+        .unwrap()
+        .haftarah
+        .is_none());
+}
+
+#[test]
+fn haftarah_minhag() {
+    let ashkenaz = list("5780", "ashkenaz");
+    let sefardi = list("5780", "edot-hamizrach");
+    let chabad = list("5780", "chabad");
+    assert_eq!(first_section(&ashkenaz, "Vayetzei").start, "12:13");
+    assert_eq!(first_section(&sefardi, "Vayetzei").start, "11:7");
+    assert_eq!(first_section(&chabad, "Vayetzei").start, "11:7");
+    assert_eq!(first_section(&ashkenaz, "Shemos").book, "Yeshayahu");
+    assert_eq!(first_section(&sefardi, "Shemos").book, "Yirmiyahu");
+    assert_eq!(first_section(&chabad, "Shemos").book, "Yeshayahu");
+
+    let yemenite = list("5780", "yemenite");
+    assert_eq!(first_section(&yemenite, "Bereishis").start, "42:1");
+    assert_eq!(first_section(&yemenite, "Vayetzei").start, "11:7");
+    assert_eq!(first_section(&yemenite, "Shemos").book, "Yechezkel");
+    assert_eq!(
+        sections(&yemenite, "MatosMaasei"),
+        [("Yirmiyahu", "2:4"), ("Yirmiyahu", "4:1")]
+    );
+    // Shabbos Rosh Chodesh and Machar Chodesh.
+    assert_eq!(
+        sections(&yemenite, "TazriyaMetzorah"),
+        [
+            ("MelachimBeis", "7:3"),
+            ("Yeshayahu", "66:1"),
+            ("Yeshayahu", "66:23")
+        ]
+    );
+    assert_eq!(
+        sections(&yemenite, "Bamidbar"),
+        [
+            ("Hoshea", "2:1"),
+            ("ShmuelAleph", "20:18"),
+            ("ShmuelAleph", "20:42")
+        ]
+    );
+
+    assert_eq!(
+        sections(&chabad, "Vayelech"),
+        [("Hoshea", "14:2"), ("Yoel", "2:15"), ("Micha", "7:18")]
+    );
+    assert_eq!(
+        sections(&chabad, "MatosMaasei"),
+        [
+            ("Yirmiyahu", "2:4"),
+            ("Yirmiyahu", "3:4"),
+            ("Yirmiyahu", "4:1")
+        ]
+    );
+    // Shabbos Rosh Chodesh Elul, the day before the second day of Rosh Chodesh.
+    assert_eq!(
+        sections(&list("5782", "chabad"), "Reeh"),
+        [
+            ("Yeshayahu", "54:11"),
+            ("Yeshayahu", "66:1"),
+            ("Yeshayahu", "66:23"),
+            ("ShmuelAleph", "20:18"),
+            ("ShmuelAleph", "20:42")
+        ]
+    );
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    name: String,
+    haftarah: Option<Vec<Section>>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Section {
+    book: String,
+    start: String,
+}
@@ -0,0 +1,234 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde::Deserialize;
+
+fn add(date: &str, amount: &str, unit: &str) -> Added {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("add")
+        .arg("--type")
+        .arg("hebrew")
+        .arg(date)
+        .arg("--")
+        .arg(amount)
+        .arg(unit);
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn add_hebrew_months() {
+    // 5784 is a leap year, so 12 months after Adar Rishon is only Shvat.
+    let res = add("10/adar1/5784", "12", "hebrew-months");
+    assert_eq!(res.hebrew, day(10, "Shvat", 5785));
+    assert_eq!(res.gregorian, "2025-02-08");
+    assert_eq!(
+        add("10/nissan/5785", "-13", "hebrew-months").hebrew,
+        day(10, "Adar2", 5784)
+    );
+    // 19 years have 235 months.
+    assert_eq!(
+        add("1/tishrei/5780", "235", "hebrew-months").hebrew,
+        day(1, "Tishrei", 5799)
+    );
+    assert_eq!(
+        add("1/tishrei/5780", "-235", "hebrew-months").hebrew,
+        day(1, "Tishrei", 5761)
+    );
+}
+
+#[test]
+fn add_too_much() {
+    for amount in &["100000000", "9223372036854775807"] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .args(&["add", "--type", "hebrew", "1/tishrei/5780", amount])
+            .arg("hebrew-months")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("too far in the future"));
+    }
+    for (amount, unit) in &[
+        ("100000000", "days"),
+        ("9223372036854775807", "weeks"),
+        ("1000000000", "months"),
+        ("9223372036854775807", "years"),
+    ] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .args(&["add", "2024/1/1", amount, unit])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("too far in the future"));
+    }
+}
+
+#[test]
+fn add_hebrew_years() {
+    assert_eq!(
+        add("15/adar/5783", "1", "hebrew-year").hebrew,
+        day(15, "Adar2", 5784)
+    );
+    assert_eq!(
+        add("15/adar1/5784", "1", "hebrew-years").hebrew,
+        day(15, "Adar", 5785)
+    );
+    // Kislev 5785 has 30 days, Kislev 5784 has 29.
+    assert_eq!(
+        add("30/kislev/5785", "-1", "hebrew-year").hebrew,
+        day(29, "Kislev", 5784)
+    );
+}
+
+#[test]
+fn add_gregorian() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("add")
+        .arg("2024-01-31")
+        .arg("1")
+        .arg("month");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Added = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.gregorian, "2024-02-29");
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("add")
+        .arg("2024-01-05")
+        .arg("1")
+        .arg("months");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Added = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.gregorian, "2024-02-05");
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("add")
+        .arg("2024-01-31")
+        .arg("--")
+        .arg("-3")
+        .arg("weeks");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Added = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.gregorian, "2024-01-10");
+    assert_eq!(res.hebrew, day(29, "Teves", 5784));
+}
+
+#[test]
+fn diff() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("diff")
+        .arg("2020-01-30")
+        .arg("2024-03-15");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Diff = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.hebrew, Span::new(4, 2, 1));
+    assert_eq!(res.gregorian, Span::new(4, 1, 15));
+    assert_eq!(res.days, 1506);
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("diff")
+        .arg("2024-01-05")
+        .arg("2025-03-07");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Diff = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.gregorian, Span::new(1, 2, 2));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("diff")
+        .arg("2024-03-15")
+        .arg("2020-01-30");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Diff = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.hebrew, Span::new(-4, -2, -1));
+    assert_eq!(res.days, -1506);
+}
+
+fn day(day: u8, month: &str, year: u64) -> Day {
+    Day {
+        day,
+        month: month.into(),
+        year,
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Day {
+    day: u8,
+    month: String,
+    year: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct Added {
+    hebrew: Day,
+    gregorian: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Span {
+    years: i64,
+    months: i64,
+    days: i64,
+}
+
+impl Span {
+    fn new(years: i64, months: i64, days: i64) -> Self {
+        Span {
+            years,
+            months,
+            days,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Diff {
+    hebrew: Span,
+    gregorian: Span,
+    days: i64,
+}
@@ -125,6 +125,42 @@ fn hebcal_to_cmd_check_yom_hazikaron() {
     find_holiday(yom_hazikarons, "YomHaZikaron", &res);
 }
 
+#[test]
+fn memorial_days() {
+    let list = |year: &str, exact_days: bool| -> Vec<Res> {
+        let mut args = vec!["list", year, "--type=hebrew", "--show=israeli-holidays"];
+        if exact_days {
+            args.push("--exact-days");
+        }
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        cmd.arg("--language")
+            .arg("en_US")
+            .arg("--print")
+            .arg("json")
+            .args(&args);
+        let out = cmd.output().unwrap();
+        if !out.status.success() {
+            panic!("{}", String::from_utf8(out.stderr).unwrap());
+        }
+        serde_json::from_slice(&out.stdout).unwrap()
+    };
+    let day = |res: &[Res], name: &str| res.iter().find(|x| x.name == name).unwrap().day.clone();
+
+    let res = list("5785", false);
+    assert_eq!(day(&res, "YomHaAliyahNissan"), "2025-04-07T18:00:00Z");
+    assert_eq!(day(&res, "HerzlDay"), "2025-05-07T18:00:00Z");
+    assert_eq!(day(&res, "JabotinskyDay"), "2025-07-24T18:00:00Z");
+    assert_eq!(day(&res, "FamilyDay"), "2025-02-27T18:00:00Z");
+    assert_eq!(day(&res, "HebrewLanguageDay"), "2025-01-20T18:00:00Z");
+    // 6 Kislev 5785 was on Shabbos.
+    assert_eq!(day(&res, "BenGurionDay"), "2024-12-07T18:00:00Z");
+    let res = list("5785", true);
+    assert_eq!(day(&res, "BenGurionDay"), "2024-12-06T18:00:00Z");
+    // 12 Cheshvan 5784 was on Friday.
+    let res = list("5784", false);
+    assert_eq!(day(&res, "YomRabin"), "2023-10-25T18:00:00Z");
+}
+
 fn find_holiday(yom_haatzmauts: Vec<&str>, json_match: &str, res: &[Res]) {
     for yom_haatzmaut in yom_haatzmauts {
         if yom_haatzmaut != "" {
@@ -155,6 +191,51 @@ fn find_holiday(yom_haatzmauts: Vec<&str>, json_match: &str, res: &[Res]) {
     }
 }
 
+#[test]
+fn strings_from_config() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--config")
+        .arg("./tests/strings_config.toml")
+        .arg("list")
+        .arg("5780")
+        .arg("--show=israeli-holidays,yom-tov");
+    let out = String::from_utf8(cmd.output().unwrap().stdout).unwrap();
+    assert!(out.contains("Night of 2020/4/28: Israel Independence Day\n"));
+    assert!(out.contains("Night of 2019/10/13: First day of Tabernacles. Candle lighting\n"));
+    assert!(out.contains("Night of 2019/10/14: 2nd day of Sukkos. Candle lighting\n"));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("--config")
+        .arg("./tests/strings_config.toml")
+        .arg("list")
+        .arg("5780")
+        .arg("--show=israeli-holidays");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<TitledRes> = serde_json::from_slice(&out.stdout).unwrap();
+    let yom_haatzmaut = res.iter().find(|x| x.name == "YomHaAtzmaut").unwrap();
+    assert_eq!(
+        yom_haatzmaut.title.as_deref(),
+        Some("Israel Independence Day")
+    );
+    let sigd = res.iter().find(|x| x.name == "Sigd").unwrap();
+    assert_eq!(sigd.title, None);
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TitledRes {
+    name: String,
+    title: Option<String>,
+}
+
 #[derive(Deserialize, Debug, Eq, PartialEq)]
 pub struct Res {
     day: String,
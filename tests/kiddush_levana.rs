@@ -0,0 +1,75 @@
+use assert_cmd::prelude::CommandCargoExt;
+use chrono::{Duration, NaiveDateTime};
+use std::process::Command;
+
+use serde::Deserialize;
+
+#[test]
+fn kiddush_levana_half_month_window() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("5700")
+        .arg("--years")
+        .arg("200")
+        .arg("--show=kiddush-levana");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<Res> = serde_json::from_slice(&out.stdout).unwrap();
+    assert!(!res.is_empty());
+    for i in res {
+        assert_eq!(i.r#type, "KiddushLevana");
+        let window = i.window.latest - i.window.earliest;
+        assert!(window > Duration::days(11) + Duration::hours(18));
+        assert!(window < Duration::days(11) + Duration::hours(19));
+    }
+}
+
+#[test]
+fn kiddush_levana_seven_to_fifteen_days() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("5780")
+        .arg("--show=kiddush-levana")
+        .arg("--kiddush-levana-start=7")
+        .arg("--kiddush-levana-end=15");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<Res> = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(res.len(), 12);
+    // The molad of Cheshvan 5780 was Monday night, 18:34 and 6 chalakim.
+    assert_eq!(res[1].month, "Cheshvan");
+    assert_eq!(
+        res[1].window.earliest,
+        NaiveDateTime::parse_from_str("2019-11-04T18:34:20", "%Y-%m-%dT%H:%M:%S").unwrap()
+    );
+    assert_eq!(res[1].day, "2019-11-04T18:00:00Z");
+    for i in res {
+        assert_eq!(i.window.latest - i.window.earliest, Duration::days(8));
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: String,
+    month: String,
+    r#type: String,
+    window: Window,
+}
+
+#[derive(Deserialize, Debug)]
+struct Window {
+    earliest: NaiveDateTime,
+    latest: NaiveDateTime,
+}
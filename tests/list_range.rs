@@ -0,0 +1,68 @@
+use assert_cmd::prelude::*;
+use serde::Deserialize;
+use std::process::Command;
+
+#[derive(Deserialize)]
+struct Res {
+    day: String,
+    name: String,
+}
+
+#[test]
+fn list_from_to() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("--from")
+        .arg("erev pesach 5785")
+        .arg("--to")
+        .arg("9 av 2025")
+        .arg("--show")
+        .arg("yom-tov,chol,minor-holidays");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<Res> = serde_json::from_slice(&out.stdout).unwrap();
+    let first = res.first().unwrap();
+    assert_eq!(first.day, "2025-04-11T18:00:00Z");
+    assert_eq!(first.name, "ErevPesach");
+    let last = res.last().unwrap();
+    assert_eq!(last.day, "2025-08-02T18:00:00Z");
+    assert_eq!(last.name, "NineAv");
+    assert!(res.iter().any(|x| x.name == "Shavuos1"));
+
+    // The range can cross into the next Hebrew year.
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("--from")
+        .arg("2025/9/1")
+        .arg("--to")
+        .arg("2025/9/30");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<Res> = serde_json::from_slice(&out.stdout).unwrap();
+    let names: Vec<&str> = res.iter().map(|x| x.name.as_ref()).collect();
+    assert_eq!(names, ["RoshHashanah1", "RoshHashanah2"]);
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("list")
+        .arg("--from")
+        .arg("2025/9/30")
+        .arg("--to")
+        .arg("2025/9/1");
+    cmd.assert().failure();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("list").arg("--from").arg("2025/9/1");
+    cmd.assert().failure();
+}
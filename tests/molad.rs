@@ -110,6 +110,69 @@ fn test_molad() {
     }
 }
 
+#[test]
+fn test_rosh_chodesh_announcement() {
+    let mut cmd =
+        Command::cargo_bin(env!("CARGO_PKG_NAME")).expect(&format!("{} {}", file!(), line!()));
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("5785")
+        .arg("--show=shabbos-mevarchim");
+    let out = cmd.output().expect(&format!("{} {}", file!(), line!()));
+    let res: Vec<Announcement> =
+        serde_json::from_slice(&out.stdout).expect(&format!("{} {}", file!(), line!()));
+    assert!(res.iter().all(|x| x.month != "Tishrei"));
+    let cheshvan =
+        res.iter()
+            .find(|x| x.month == "Cheshvan")
+            .expect(&format!("{} {}", file!(), line!()));
+    assert_eq!(cheshvan.rosh_chodesh, vec!["Friday", "Shabbos"]);
+    assert_eq!(
+        cheshvan.announcement,
+        "Rosh Chodesh Cheshvan will be on Friday and Shabbos"
+    );
+    assert_eq!(cheshvan.molad_standard_time, "2024-11-01T15:44:55");
+    let shvat = res
+        .iter()
+        .find(|x| x.month == "Shvat")
+        .expect(&format!("{} {}", file!(), line!()));
+    assert_eq!(shvat.rosh_chodesh, vec!["Thursday"]);
+
+    // The announcement is in the chosen language.
+    let mut cmd =
+        Command::cargo_bin(env!("CARGO_PKG_NAME")).expect(&format!("{} {}", file!(), line!()));
+    cmd.arg("--language")
+        .arg("he_IL")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg("5785")
+        .arg("--show=shabbos-mevarchim");
+    let out = cmd.output().expect(&format!("{} {}", file!(), line!()));
+    let res: Vec<Announcement> =
+        serde_json::from_slice(&out.stdout).expect(&format!("{} {}", file!(), line!()));
+    let cheshvan =
+        res.iter()
+            .find(|x| x.month == "Cheshvan")
+            .expect(&format!("{} {}", file!(), line!()));
+    assert_eq!(
+        cheshvan.announcement,
+        "ראש חודש חשוון יהיה ביום שישי ולמחרתו ביום שבת קודש"
+    );
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Announcement {
+    month: String,
+    rosh_chodesh: Vec<String>,
+    announcement: String,
+    molad_standard_time: String,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 struct Res {
     day: String,
@@ -0,0 +1,65 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, minhag: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg(format!("--minhag={}", minhag))
+        .arg("--show=mourning-periods");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+fn period<'a>(res: &'a [Res], name: &str) -> (&'a str, &'a str) {
+    let x = res.iter().find(|x| x.name == name).unwrap();
+    (&x.start, &x.end)
+}
+
+#[test]
+fn sefirah() {
+    assert_eq!(
+        period(&list("5780", "ashkenaz"), "Sefirah"),
+        ("2020-04-09T18:00:00Z", "2020-05-11T18:00:00Z")
+    );
+    assert_eq!(
+        period(&list("5780", "edot-hamizrach"), "Sefirah"),
+        ("2020-04-09T18:00:00Z", "2020-05-13T06:00:00Z")
+    );
+    assert_eq!(
+        period(&list("5780", "sefard"), "Sefirah"),
+        ("2020-04-09T18:00:00Z", "2020-05-25T18:00:00Z")
+    );
+}
+
+#[test]
+fn three_weeks() {
+    let res = list("5780", "ashkenaz");
+    assert_eq!(
+        period(&res, "ThreeWeeks"),
+        ("2020-07-08T18:00:00Z", "2020-07-30T18:00:00Z")
+    );
+    assert_eq!(
+        period(&res, "NineDays"),
+        ("2020-07-21T18:00:00Z", "2020-07-30T18:00:00Z")
+    );
+    // Tisha B'Av 5779 was on Shabbos, so the fast was on Sunday.
+    let res = list("5779", "ashkenaz");
+    assert_eq!(period(&res, "NineDays").1, "2019-08-11T18:00:00Z");
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    name: String,
+    start: String,
+    end: String,
+}
@@ -0,0 +1,61 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, location: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg(format!("--location={}", location))
+        .arg("--show=chol");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+fn purim(res: &[Res]) -> Vec<(&str, &str)> {
+    res.iter()
+        .filter(|x| x.name.contains("Purim"))
+        .map(|x| (x.day.as_ref(), x.name.as_ref()))
+        .collect()
+}
+
+#[test]
+fn walled_city() {
+    assert_eq!(
+        purim(&list("5782", "Israel")),
+        vec![
+            ("2022-03-16T18:00:00Z", "Purim"),
+            ("2022-03-17T18:00:00Z", "ShushanPurim")
+        ]
+    );
+    assert_eq!(
+        purim(&list("5782", "Jerusalem")),
+        vec![("2022-03-17T18:00:00Z", "ShushanPurim")]
+    );
+}
+
+#[test]
+fn purim_meshulash() {
+    assert_eq!(
+        purim(&list("5781", "Jerusalem")),
+        vec![
+            ("2021-02-25T18:00:00Z", "PurimMeshulashMegillah"),
+            ("2021-02-26T18:00:00Z", "PurimMeshulashAlHaNissim"),
+            ("2021-02-27T18:00:00Z", "PurimMeshulashSeudah")
+        ]
+    );
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: String,
+    name: String,
+}
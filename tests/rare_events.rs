@@ -0,0 +1,78 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, years: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg(format!("--years={}", years))
+        .arg("--show=rare-events");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+fn days<'a>(res: &'a [Res], name: &str) -> Vec<&'a str> {
+    res.iter()
+        .filter(|x| x.name == name)
+        .map(|x| x.day.as_ref())
+        .collect()
+}
+
+#[test]
+fn birkas_hachama() {
+    let res = list("5740", "60");
+    assert_eq!(
+        days(&res, "BirkasHaChama"),
+        vec![
+            "1981-04-07T18:00:00Z",
+            "2009-04-07T18:00:00Z",
+            "2037-04-07T18:00:00Z"
+        ]
+    );
+}
+
+#[test]
+fn tekufos() {
+    let res = list("5785", "1");
+    let tekufos = res
+        .iter()
+        .filter(|x| x.name == "Tekufa")
+        .map(|x| (x.season.as_deref().unwrap(), x.time.as_deref().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tekufos,
+        vec![
+            ("Tishrei", "2024-10-07T03:00:00"),
+            ("Teves", "2025-01-06T10:30:00"),
+            ("Nissan", "2025-04-07T18:00:00"),
+            ("Tammuz", "2025-07-08T01:30:00"),
+        ]
+    );
+}
+
+#[test]
+fn hakhel_and_erev_pesach_on_shabbos() {
+    let res = list("5781", "5");
+    assert_eq!(days(&res, "Hakhel"), vec!["2022-10-10T18:00:00Z"]);
+    assert_eq!(
+        days(&res, "ErevPesachOnShabbos"),
+        vec!["2021-03-26T18:00:00Z", "2025-04-11T18:00:00Z"]
+    );
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: String,
+    name: String,
+    season: Option<String>,
+    time: Option<String>,
+}
@@ -0,0 +1,75 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn list(year: &str, location: &str) -> Vec<Res> {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("list")
+        .arg(year)
+        .arg("--type=hebrew")
+        .arg(format!("--location={}", location))
+        .arg("--show=special-shabbosos");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+fn days<'a>(res: &'a [Res], name: &str) -> Vec<&'a str> {
+    res.iter()
+        .filter(|x| x.name == name)
+        .map(|x| x.day.as_ref())
+        .collect()
+}
+
+#[test]
+fn special_shabbosos() {
+    let res = list("5780", "Chul");
+    assert_eq!(
+        days(&res, "ShabbosRoshChodesh"),
+        vec!["2019-12-27T18:00:00Z", "2020-04-24T18:00:00Z"]
+    );
+    assert_eq!(
+        days(&res, "ShabbosMacharChodesh"),
+        vec!["2020-05-22T18:00:00Z"]
+    );
+    assert_eq!(days(&res, "ShabbosChanukah"), vec!["2019-12-27T18:00:00Z"]);
+    assert!(days(&res, "ShabbosChanukahSheni").is_empty());
+    assert_eq!(days(&res, "ShabbosShira"), vec!["2020-02-07T18:00:00Z"]);
+    assert_eq!(days(&res, "ShabbosZachor"), vec!["2020-03-06T18:00:00Z"]);
+    assert_eq!(days(&res, "ShabbosMevarchim").len(), 11);
+
+    // Chanukah 5763 started on Shabbos, so it had two Shabbosos.
+    let res = list("5763", "Chul");
+    assert_eq!(days(&res, "ShabbosChanukah"), vec!["2002-11-29T18:00:00Z"]);
+    assert_eq!(
+        days(&res, "ShabbosChanukahSheni"),
+        vec!["2002-12-06T18:00:00Z"]
+    );
+}
+
+#[test]
+fn shabbos_chol_hamoed() {
+    for location in &["Chul", "Israel"] {
+        let res = list("5780", location);
+        assert_eq!(
+            days(&res, "ShabbosCholHaMoedSukkos"),
+            vec!["2019-10-18T18:00:00Z"]
+        );
+        assert_eq!(
+            days(&res, "ShabbosCholHaMoedPesach"),
+            vec!["2020-04-10T18:00:00Z"]
+        );
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    day: String,
+    name: String,
+}
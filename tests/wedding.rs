@@ -0,0 +1,98 @@
+use assert_cmd::prelude::CommandCargoExt;
+use serde::Deserialize;
+use std::process::Command;
+
+fn wedding_check(date: &str, minhag: &str) -> Res {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("wedding-check")
+        .arg(date)
+        .arg("--location=Chul")
+        .arg(format!("--minhag={}", minhag));
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn wedding_check_reasons() {
+    // Lag BaOmer 5780.
+    let res = wedding_check("2020/05/12", "ashkenaz");
+    assert!(res.permitted && res.reasons.is_empty());
+    assert_eq!(
+        wedding_check("2020/05/05", "ashkenaz").reasons,
+        vec!["Sefirah"]
+    );
+    assert_eq!(
+        wedding_check("2020/05/12", "edot-hamizrach").reasons,
+        vec!["Sefirah"]
+    );
+    assert_eq!(
+        wedding_check("2020/05/16", "ashkenaz").reasons,
+        vec!["Shabbos"]
+    );
+    assert_eq!(
+        wedding_check("2020/04/12", "ashkenaz").reasons,
+        vec!["CholHaMoed", "Sefirah"]
+    );
+    assert_eq!(
+        wedding_check("2020/07/20", "ashkenaz").reasons,
+        vec!["ThreeWeeks"]
+    );
+    // Sephardim only hold back from the Nine Days.
+    assert!(wedding_check("2020/07/20", "edot-hamizrach").permitted);
+    assert_eq!(
+        wedding_check("2020/07/23", "edot-hamizrach").reasons,
+        vec!["NineDays"]
+    );
+    assert_eq!(
+        wedding_check("2019/10/02", "ashkenaz").reasons,
+        vec!["Fast", "AseresYemeiTeshuva"]
+    );
+    assert_eq!(
+        wedding_check("2019/10/02", "edot-hamizrach").reasons,
+        vec!["Fast"]
+    );
+    assert_eq!(
+        wedding_check("2020/06/10", "ashkenaz").reasons,
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn wedding_dates() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("wedding-dates")
+        .arg("2020/05/10")
+        .arg("2020/05/17")
+        .arg("--location=Chul");
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    let res: Vec<Day> = serde_json::from_slice(&out.stdout).unwrap();
+    assert_eq!(
+        res.iter().map(|x| x.day).collect::<Vec<_>>(),
+        vec![18, 19, 20, 21, 23]
+    );
+}
+
+#[derive(Deserialize, Debug)]
+struct Res {
+    permitted: bool,
+    reasons: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Day {
+    day: u8,
+}
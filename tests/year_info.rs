@@ -0,0 +1,67 @@
+use assert_cmd::prelude::*;
+use serde::Deserialize;
+use std::process::Command;
+
+fn year_info(year: &str) -> Res {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("--language")
+        .arg("en_US")
+        .arg("--print")
+        .arg("json")
+        .arg("year-info")
+        .arg(year);
+    let out = cmd.output().unwrap();
+    if !out.status.success() {
+        panic!("{}", String::from_utf8(out.stderr).unwrap());
+    }
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+#[test]
+fn shmita_cycle() {
+    let res = year_info("5782");
+    assert!(res.shmita && res.leap_year);
+    assert_eq!(res.shmita_cycle_year, 7);
+    assert_eq!(res.maaser, None);
+    assert_eq!(res.biur_maaser.as_deref(), Some("2022-04-14T18:00:00Z"));
+
+    let res = year_info("5783");
+    assert!(!res.shmita && res.hakhel);
+    assert_eq!(res.maaser.as_deref(), Some("Sheni"));
+    assert_eq!(res.biur_maaser, None);
+
+    let res = year_info("5785");
+    assert_eq!(res.shmita_cycle_year, 3);
+    assert_eq!(res.maaser.as_deref(), Some("Ani"));
+
+    let res = year_info("5786");
+    assert_eq!(res.maaser.as_deref(), Some("Sheni"));
+    assert_eq!(res.biur_maaser.as_deref(), Some("2026-03-31T18:00:00Z"));
+}
+
+#[test]
+fn keviah() {
+    assert_eq!(year_info("5782").keviah, "GaKaZ");
+    assert_eq!(year_info("5783").keviah, "BaShaH");
+    assert_eq!(year_info("5784").keviah, "ZaChaG");
+    assert_eq!(year_info("5785").keviah, "HaShA");
+}
+
+#[test]
+fn bad_year() {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    cmd.arg("year-info").arg("abc");
+    cmd.assert().failure();
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Res {
+    leap_year: bool,
+    keviah: String,
+    shmita_cycle_year: u8,
+    shmita: bool,
+    hakhel: bool,
+    maaser: Option<String>,
+    biur_maaser: Option<String>,
+}